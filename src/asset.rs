@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use crate::avm::parser::signed_tx_parser::SignedTx;
+use crate::network::Network;
+use crate::utils::amount::Amount;
+
+/// AVAX is not created by a `CreateAssetTx` , its metadata are known upfront .
+pub const AVAX_DENOMINATION: u8 = 9;
+
+/// The metadata of an asset , as set by the `CreateAssetTx` creating it .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AssetMetadata {
+    /// The id of the tx creating the asset
+    pub asset_id: String,
+    pub name: String,
+    pub symbol: String,
+    /// The number of decimals of the amounts
+    pub denomination: u8,
+}
+
+/// Resolve asset ids to their metadata , fed with the parsed txs .
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct AssetRegistry {
+    pub assets: HashMap<String, AssetMetadata>,
+}
+
+impl AssetRegistry {
+    /// A registry already knowing the AVAX asset of `_network` .
+    pub fn with_network(_network: &Network) -> AssetRegistry {
+        let mut registry = AssetRegistry::default();
+        registry.add_network(_network);
+
+        registry
+    }
+
+    pub fn add_network(&mut self, _network: &Network) {
+        self.add_asset(AssetMetadata {
+            asset_id: _network.avax_asset_id.clone(),
+            name: "Avalanche".to_string(),
+            symbol: "AVAX".to_string(),
+            denomination: AVAX_DENOMINATION,
+        });
+    }
+
+    pub fn add_asset(&mut self, _asset: AssetMetadata) {
+        self.assets.insert(_asset.asset_id.clone(), _asset);
+    }
+
+    /// Register the asset created by the tx , return false if the tx is not a `CreateAssetTx` .
+    pub fn add_tx(&mut self, _tx: &SignedTx) -> bool {
+        match &_tx.create_asset_tx {
+            Some(create_asset_tx) => {
+                self.add_asset(AssetMetadata {
+                    asset_id: _tx.tx_id.clone(),
+                    name: create_asset_tx.name.clone(),
+                    symbol: create_asset_tx.symbol.clone(),
+                    denomination: create_asset_tx.denomination as u8,
+                });
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn get(&self, _asset_id: &str) -> Option<&AssetMetadata> {
+        self.assets.get(_asset_id)
+    }
+
+    /// Format a raw amount with the decimals and symbol of its asset , e.g. `1.5 AVAX` .
+    /// Amounts of unknown assets are left raw and followed by the asset id .
+    pub fn format_amount(&self, _asset_id: &str, _amount: Amount) -> String {
+        match self.get(_asset_id) {
+            Some(asset) => format!("{} {}", _amount.format(asset.denomination), asset.symbol),
+            None => format!("{} {}", _amount, _asset_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm::parser::fixtures::{base_tx, create_asset_tx};
+
+    #[test]
+    fn format_amount_01() {
+        let network = Network::mainnet();
+        let mut registry = AssetRegistry::with_network(&network);
+        registry.add_asset(AssetMetadata {
+            asset_id: "asset".to_string(),
+            name: "Test Collection".to_string(),
+            symbol: "TEST".to_string(),
+            denomination: 2,
+        });
+
+        assert_eq!(
+            registry.format_amount(&network.avax_asset_id, Amount(1_000_000_000)),
+            "1 AVAX",
+            "Checking if AVAX has 9 decimals"
+        );
+        assert_eq!(
+            registry.format_amount(&network.avax_asset_id, Amount(1_050_000)),
+            "0.00105 AVAX",
+            "Checking if trailing zeros are trimmed"
+        );
+        assert_eq!(
+            registry.format_amount("asset", Amount(1_234)),
+            "12.34 TEST",
+            "Checking if the denomination of custom assets is used"
+        );
+        assert_eq!(
+            registry.format_amount("unknown", Amount(1_234)),
+            "1234 unknown",
+            "Checking if amounts of unknown assets are left raw"
+        );
+    }
+
+    #[test]
+    fn add_tx_01() {
+        let tx = create_asset_tx();
+        let mut registry = AssetRegistry::default();
+        assert!(
+            registry.add_tx(&tx),
+            "Checking if the created asset is registered"
+        );
+        assert!(
+            !registry.add_tx(&base_tx()),
+            "Checking if a tx creating no asset is skipped"
+        );
+        assert_eq!(
+            registry.get(&tx.tx_id).unwrap().name,
+            "Test Collection",
+            "Checking if the name of the asset is correctly set"
+        );
+        assert_eq!(
+            registry.format_amount(&tx.tx_id, Amount(10)),
+            "10 TEST",
+            "Checking if amounts are formatted with the symbol of the asset"
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use std::error::Error;
+
+use crate::avm::parser::base_tx_parser::BaseTx;
+use crate::avm::parser::signed_tx_parser::SignedTx;
+use crate::avm::parser::transferable_input_parser::TransferableInput;
+use crate::avm::parser::transferable_output_parser::TransferableOutput;
+use crate::network::Network;
+use crate::utils::balance::BalanceSummary;
+
+impl SignedTx {
+    /// Per asset totals of the inputs and outputs of the tx , exported outputs included .
+    /// Assets minted by an operation or a create asset tx are not part of the outputs .
+    pub fn balance_summary(&self, _network: &Network) -> Result<BalanceSummary, Box<dyn Error>> {
+        let mut summary = BalanceSummary::default();
+
+        if let Some(base_tx) = &self.base_tx {
+            add_base_tx(&mut summary, base_tx)?;
+        }
+        if let Some(create_asset_tx) = &self.create_asset_tx {
+            add_base_tx(&mut summary, &create_asset_tx.base_tx)?;
+        }
+        if let Some(operation_tx) = &self.operation_tx {
+            add_base_tx(&mut summary, &operation_tx.base_tx)?;
+        }
+        if let Some(import_tx) = &self.import_tx {
+            add_base_tx(&mut summary, &import_tx.base_tx)?;
+            add_inputs(&mut summary, &import_tx.transferable_inputs)?;
+        }
+        if let Some(export_tx) = &self.export_tx {
+            add_base_tx(&mut summary, &export_tx.base_tx)?;
+            add_outputs(&mut summary, &export_tx.transferable_outputs)?;
+        }
+
+        summary.finish(_network)
+    }
+}
+
+fn add_base_tx(_summary: &mut BalanceSummary, _base_tx: &BaseTx) -> Result<(), Box<dyn Error>> {
+    add_inputs(_summary, &_base_tx.transferable_inputs)?;
+    add_outputs(_summary, &_base_tx.transferable_outputs)
+}
+
+fn add_inputs(
+    _summary: &mut BalanceSummary,
+    _inputs: &[TransferableInput],
+) -> Result<(), Box<dyn Error>> {
+    for transferable_input in _inputs {
+        _summary.add_input(
+            &transferable_input.asset_id,
+            transferable_input.input.amount,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn add_outputs(
+    _summary: &mut BalanceSummary,
+    _outputs: &[TransferableOutput],
+) -> Result<(), Box<dyn Error>> {
+    for transferable_output in _outputs {
+        if let Some(amount) = transferable_output.output.amount {
+            _summary.add_output(&transferable_output.asset_id, amount)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm::parser::fixtures::{base_tx, create_asset_tx};
+    use std::collections::BTreeMap;
+
+    const AVAX_ASSET_ID: &str = "FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z";
+
+    #[test]
+    fn balance_summary_01() {
+        assert_eq!(
+            base_tx().balance_summary(&Network::mainnet()).unwrap(),
+            BalanceSummary {
+                inputs: BTreeMap::from([(AVAX_ASSET_ID.to_string(), 220_000_000_000)]),
+                outputs: BTreeMap::from([(AVAX_ASSET_ID.to_string(), 219_999_000_000)]),
+                burned: BTreeMap::from([(AVAX_ASSET_ID.to_string(), 1_000_000)]),
+                fee: 1_000_000,
+            },
+            "Checking if the inputs , outputs and burned fee are summed per asset"
+        );
+        assert_eq!(
+            create_asset_tx()
+                .balance_summary(&Network::mainnet())
+                .unwrap()
+                .fee,
+            10_000_000,
+            "Checking if the fee of a create asset tx is burned"
+        );
+    }
+}
@@ -0,0 +1,338 @@
+use crate::avm::parser::base_tx_parser::BaseTx;
+use crate::avm::parser::create_asset_tx_parser::CreateAssetTx;
+use crate::avm::parser::credential_parser::Credential;
+use crate::avm::parser::export_tx_parser::ExportTx;
+use crate::avm::parser::import_tx_parser::ImportTx;
+use crate::avm::parser::operation_tx_parser::OperationTx;
+use crate::avm::parser::output_owner_parser::OutputOwner;
+use crate::avm::parser::output_parser::Output;
+use crate::avm::parser::signed_tx_parser::SignedTx;
+use crate::avm::parser::transfer_op_parser::TransferableOperation;
+use crate::avm::parser::transferable_input_parser::TransferableInput;
+use crate::avm::parser::transferable_output_parser::TransferableOutput;
+use crate::avm::syntax::output_bytes;
+use crate::utils::canonical::{
+    sort_addresses, sort_signature_indices, sort_with_credentials, split_credentials, utxo_key,
+};
+use crate::utils::cb58::decode;
+use crate::utils::syntax::owners_bytes;
+
+impl Output {
+    pub fn sort_canonical(&mut self) {
+        sort_addresses(&mut self.addresses);
+    }
+}
+
+impl OutputOwner {
+    pub fn sort_canonical(&mut self) {
+        sort_addresses(&mut self.addresses);
+    }
+}
+
+impl BaseTx {
+    /// Sort outputs , inputs and their addresses and signature indices the way AvalancheGo expects .
+    /// `_credentials` are the credentials of the inputs , in the same order .
+    pub fn sort_canonical(&mut self, _credentials: &mut [Credential]) {
+        sort_outputs(&mut self.transferable_outputs);
+        sort_inputs(&mut self.transferable_inputs, _credentials);
+    }
+}
+
+impl CreateAssetTx {
+    pub fn sort_canonical(&mut self, _credentials: &mut [Credential]) {
+        self.base_tx.sort_canonical(_credentials);
+
+        for initial_state in self.initial_states.iter_mut() {
+            for output in initial_state.outputs.iter_mut() {
+                output.sort_canonical();
+            }
+            initial_state.outputs.sort_by_key(output_bytes);
+        }
+    }
+}
+
+impl OperationTx {
+    /// `_credentials` are the credentials of the inputs followed by the ones of the operations .
+    pub fn sort_canonical(&mut self, _credentials: &mut [Credential]) {
+        let (input_credentials, operation_credentials) =
+            split_credentials(_credentials, self.base_tx.transferable_inputs.len());
+        self.base_tx.sort_canonical(input_credentials);
+
+        for (index, operation) in self.transferable_ops.iter_mut().enumerate() {
+            sort_operation(operation, operation_credentials.get_mut(index));
+        }
+
+        let keys: Vec<Vec<u8>> = self.transferable_ops.iter().map(operation_bytes).collect();
+        sort_with_credentials(&mut self.transferable_ops, &keys, operation_credentials);
+    }
+}
+
+impl ImportTx {
+    /// `_credentials` are the credentials of the inputs followed by the ones of the imported inputs .
+    pub fn sort_canonical(&mut self, _credentials: &mut [Credential]) {
+        let (input_credentials, imported_credentials) =
+            split_credentials(_credentials, self.base_tx.transferable_inputs.len());
+        self.base_tx.sort_canonical(input_credentials);
+        sort_inputs(&mut self.transferable_inputs, imported_credentials);
+    }
+}
+
+impl ExportTx {
+    pub fn sort_canonical(&mut self, _credentials: &mut [Credential]) {
+        self.base_tx.sort_canonical(_credentials);
+        sort_outputs(&mut self.transferable_outputs);
+    }
+}
+
+impl SignedTx {
+    /// Sort the tx and its credentials , a tx built in any order comes out valid .
+    /// The tx id is not updated .
+    pub fn sort_canonical(&mut self) {
+        let credentials = &mut self.credentials;
+
+        if let Some(base_tx) = self.base_tx.as_mut() {
+            base_tx.sort_canonical(credentials);
+        }
+        if let Some(create_asset_tx) = self.create_asset_tx.as_mut() {
+            create_asset_tx.sort_canonical(credentials);
+        }
+        if let Some(operation_tx) = self.operation_tx.as_mut() {
+            operation_tx.sort_canonical(credentials);
+        }
+        if let Some(import_tx) = self.import_tx.as_mut() {
+            import_tx.sort_canonical(credentials);
+        }
+        if let Some(export_tx) = self.export_tx.as_mut() {
+            export_tx.sort_canonical(credentials);
+        }
+    }
+}
+
+fn sort_outputs(_outputs: &mut [TransferableOutput]) {
+    for transferable_output in _outputs.iter_mut() {
+        transferable_output.output.sort_canonical();
+    }
+
+    let keys: Vec<Vec<u8>> = _outputs
+        .iter()
+        .map(|transferable_output| {
+            let mut key = decode(&transferable_output.asset_id).unwrap_or_default();
+            key.extend(output_bytes(&transferable_output.output));
+            key
+        })
+        .collect();
+    sort_with_credentials(_outputs, &keys, &mut []);
+}
+
+fn sort_inputs(_inputs: &mut [TransferableInput], _credentials: &mut [Credential]) {
+    for (index, transferable_input) in _inputs.iter_mut().enumerate() {
+        sort_signature_indices(
+            &mut transferable_input.input.address_indices,
+            _credentials.get_mut(index),
+        );
+    }
+
+    let keys: Vec<Vec<u8>> = _inputs
+        .iter()
+        .map(|input| utxo_key(&input.tx_id, input.utxo_index))
+        .collect();
+    sort_with_credentials(_inputs, &keys, _credentials);
+}
+
+fn sort_operation(_operation: &mut TransferableOperation, _credential: Option<&mut Credential>) {
+    let keys: Vec<Vec<u8>> = _operation
+        .utxo_ids
+        .iter()
+        .map(|utxo_id| utxo_key(&utxo_id.tx_id, utxo_id.utxo_index))
+        .collect();
+    sort_with_credentials(&mut _operation.utxo_ids, &keys, &mut []);
+
+    if let Some(mint_op) = _operation.secp256k1_mint_op.as_mut() {
+        sort_signature_indices(&mut mint_op.address_indices, _credential);
+        mint_op.secp256k1_mint_output.sort_canonical();
+        mint_op.secp256k1_transfer_output.sort_canonical();
+    } else if let Some(nft_mint_op) = _operation.nft_mint_op.as_mut() {
+        sort_signature_indices(&mut nft_mint_op.address_indices, _credential);
+        for output in nft_mint_op.outputs.iter_mut() {
+            output.sort_canonical();
+        }
+        nft_mint_op.outputs.sort_by_key(output_owner_bytes);
+    } else if let Some(nft_transfer_op) = _operation.nft_transfer_op.as_mut() {
+        sort_signature_indices(&mut nft_transfer_op.address_indices, _credential);
+        nft_transfer_op.output_owner.sort_canonical();
+    } else if let Some(property_mint_op) = _operation.property_mint_op.as_mut() {
+        sort_signature_indices(&mut property_mint_op.address_indices, _credential);
+        property_mint_op.mint_output.sort_canonical();
+        property_mint_op.owned_output.sort_canonical();
+    } else if let Some(property_burn_op) = _operation.property_burn_op.as_mut() {
+        sort_signature_indices(&mut property_burn_op.address_indices, _credential);
+    }
+}
+
+fn output_owner_bytes(_output_owner: &OutputOwner) -> Vec<u8> {
+    owners_bytes(
+        _output_owner.locktime,
+        _output_owner.threshold,
+        &_output_owner.addresses,
+    )
+}
+
+/// The serialized form of an operation , used to sort operations the way AvalancheGo does .
+fn operation_bytes(_operation: &TransferableOperation) -> Vec<u8> {
+    let mut bytes = decode(&_operation.asset_id).unwrap_or_default();
+    bytes.extend_from_slice(&(_operation.utxo_ids.len() as u32).to_be_bytes());
+    for utxo_id in &_operation.utxo_ids {
+        bytes.extend(utxo_key(&utxo_id.tx_id, utxo_id.utxo_index));
+    }
+
+    if let Some(mint_op) = &_operation.secp256k1_mint_op {
+        bytes.extend_from_slice(&mint_op.type_id.to_be_bytes());
+        extend_indices(
+            &mut bytes,
+            mint_op.address_indices.iter().map(|i| *i as u32),
+        );
+        // The outputs of an operation are embedded , without type id
+        bytes.extend(output_bytes(&mint_op.secp256k1_mint_output)[4..].iter());
+        bytes.extend(output_bytes(&mint_op.secp256k1_transfer_output)[4..].iter());
+    } else if let Some(nft_mint_op) = &_operation.nft_mint_op {
+        bytes.extend_from_slice(&nft_mint_op.type_id.to_be_bytes());
+        extend_indices(&mut bytes, nft_mint_op.address_indices.iter().copied());
+        bytes.extend_from_slice(&nft_mint_op.group_id.to_be_bytes());
+        bytes.extend_from_slice(&(nft_mint_op.payload.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(&nft_mint_op.payload);
+        bytes.extend_from_slice(&(nft_mint_op.outputs.len() as u32).to_be_bytes());
+        for output in &nft_mint_op.outputs {
+            bytes.extend(output_owner_bytes(output));
+        }
+    } else if let Some(nft_transfer_op) = &_operation.nft_transfer_op {
+        bytes.extend_from_slice(&nft_transfer_op.type_id.to_be_bytes());
+        extend_indices(&mut bytes, nft_transfer_op.address_indices.iter().copied());
+        bytes.extend_from_slice(&nft_transfer_op.group_id.to_be_bytes());
+        bytes.extend_from_slice(&(nft_transfer_op.payload.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(&nft_transfer_op.payload);
+        bytes.extend(output_owner_bytes(&nft_transfer_op.output_owner));
+    } else if let Some(property_mint_op) = &_operation.property_mint_op {
+        bytes.extend_from_slice(&property_mint_op.type_id.to_be_bytes());
+        extend_indices(&mut bytes, property_mint_op.address_indices.iter().copied());
+        bytes.extend(output_bytes(&property_mint_op.mint_output)[4..].iter());
+        bytes.extend(output_bytes(&property_mint_op.owned_output)[4..].iter());
+    } else if let Some(property_burn_op) = &_operation.property_burn_op {
+        bytes.extend_from_slice(&property_burn_op.type_id.to_be_bytes());
+        extend_indices(&mut bytes, property_burn_op.address_indices.iter().copied());
+    }
+
+    bytes
+}
+
+fn extend_indices(_bytes: &mut Vec<u8>, _indices: impl ExactSizeIterator<Item = u32>) {
+    _bytes.extend_from_slice(&(_indices.len() as u32).to_be_bytes());
+    for index in _indices {
+        _bytes.extend_from_slice(&index.to_be_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm::parser::input_parser::SECP256KTransferInput;
+    use crate::utils::amount::Amount;
+
+    const AVAX: &str = "FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z";
+    const TX_ID: &str = "SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2HdvDQU";
+
+    fn output(_amount: u64, _addresses: &[&str]) -> TransferableOutput {
+        TransferableOutput {
+            asset_id: AVAX.to_string(),
+            output: Output {
+                type_id: 7,
+                amount: Some(Amount(_amount)),
+                group_id: None,
+                payload: None,
+                locktime: 0,
+                threshold: 1,
+                addresses: _addresses
+                    .iter()
+                    .map(|address| address.to_string())
+                    .collect(),
+            },
+        }
+    }
+
+    fn input(_utxo_index: i32, _address_indices: Vec<i32>) -> TransferableInput {
+        TransferableInput {
+            tx_id: TX_ID.to_string(),
+            utxo_index: _utxo_index,
+            asset_id: AVAX.to_string(),
+            input: SECP256KTransferInput {
+                type_id: 5,
+                amount: Amount(1_000),
+                address_indices: _address_indices,
+            },
+        }
+    }
+
+    fn credential(_signatures: &[u8]) -> Credential {
+        Credential {
+            type_id: 9,
+            signatures: _signatures
+                .iter()
+                .map(|signature| vec![*signature])
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn sort_canonical_01() {
+        let address = "X-avax1d3qnae4dej47yd0pd28th7nxelwu6fp5mncjnd";
+        let mut tx = SignedTx {
+            codec_id: 0,
+            unsigned_tx_offset: 0,
+            type_id: 0,
+            tx_id: "a_tx".to_string(),
+            base_tx: Some(BaseTx {
+                type_id: 0,
+                network_id: 1,
+                blockchain_id: "2oYMBNV4eNHyqk2fjjV5nVQLDbtmNJzq5s3qs3Lo6ftnC6FByM".to_string(),
+                transferable_outputs: vec![output(2_000, &[address]), output(1_000, &[address])],
+                transferable_inputs: vec![input(2, vec![1, 0]), input(1, vec![0])],
+                memo: vec![],
+            }),
+            create_asset_tx: None,
+            operation_tx: None,
+            import_tx: None,
+            export_tx: None,
+            unsigned_tx: vec![],
+            credentials: vec![credential(&[11, 10]), credential(&[20])],
+        };
+        assert_eq!(tx.verify_syntax().len(), 2, "Checking the tx is not sorted");
+
+        tx.sort_canonical();
+        assert_eq!(tx.verify_syntax(), vec![], "Checking the tx is sorted");
+
+        let base_tx = tx.base_tx.as_ref().unwrap();
+        assert_eq!(
+            base_tx.transferable_outputs[0].output.amount,
+            Some(Amount(1_000)),
+            "Checking if outputs are sorted"
+        );
+        assert_eq!(
+            base_tx.transferable_inputs[0].utxo_index, 1,
+            "Checking if inputs are sorted"
+        );
+        assert_eq!(
+            base_tx.transferable_inputs[1].input.address_indices,
+            vec![0, 1],
+            "Checking if signature indices are sorted"
+        );
+        assert_eq!(
+            tx.credentials[0].signatures,
+            vec![vec![20]],
+            "Checking if credentials follow their input"
+        );
+        assert_eq!(
+            tx.credentials[1].signatures,
+            vec![vec![10], vec![11]],
+            "Checking if signatures follow their index"
+        );
+    }
+}
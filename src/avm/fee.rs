@@ -0,0 +1,19 @@
+use std::error::Error;
+
+use crate::avm::parser::signed_tx_parser::SignedTx;
+use crate::network::Network;
+use crate::utils::fee::{EstimateFee, FeeConfig};
+
+impl EstimateFee for SignedTx {
+    fn estimate_fee(&self, _config: &FeeConfig) -> u64 {
+        if self.create_asset_tx.is_some() {
+            _config.create_asset_tx_fee
+        } else {
+            _config.tx_fee
+        }
+    }
+
+    fn burned_fee(&self, _network: &Network) -> Result<u64, Box<dyn Error>> {
+        Ok(self.balance_summary(_network)?.fee)
+    }
+}
@@ -1 +1,7 @@
+pub mod balance;
+pub mod canonical;
+pub mod fee;
 pub mod parser;
+pub mod semantic;
+pub mod signers;
+pub mod syntax;
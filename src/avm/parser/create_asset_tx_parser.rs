@@ -33,7 +33,7 @@ pub fn create_asset_tx_parser(
     );
 
     let name = std::str::from_utf8(
-        &_raw_msg[*_context.offset..=(*_context.offset + usize::from(name_length))],
+        &_raw_msg[*_context.offset..*_context.offset + usize::from(name_length)],
     )?;
     trace!(
         "TxID: {} \n CreateAssetTx -- name : {:?} \n =======",
@@ -51,7 +51,7 @@ pub fn create_asset_tx_parser(
     *_context.offset += 2;
 
     let symbol = std::str::from_utf8(
-        &_raw_msg[*_context.offset..=(*_context.offset + usize::from(symbol_length))],
+        &_raw_msg[*_context.offset..*_context.offset + usize::from(symbol_length)],
     )?;
     trace!(
         "TxID: {} \n CreateAssetTx -- symbol : {:?} \n =======",
@@ -8,6 +8,7 @@ use crate::avm::parser::Context;
 use crate::utils::conversion::pop_i32;
 
 /// https://docs.avax.network/build/references/avm-transaction-serialization#credentials
+/// The secp256k1fx (9), nftfx (14) and propertyfx (19) credentials share the same layout .
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Credential {
     pub type_id: i32,
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::time::SystemTime;
+
+use crate::avm::parser::output_parser::Output;
+use crate::avm::parser::signed_tx_parser::{signed_tx_parser, SignedTx};
+use crate::avm::parser::Context;
+use crate::utxo::utxo_id::UtxoId;
+use crate::utxo::utxo_parser::Utxo;
+
+/// Txs shared by the tests of the X-chain modules .
+pub fn parse_tx(_raw_bytes: &[u8]) -> SignedTx {
+    signed_tx_parser(
+        _raw_bytes,
+        &mut Context {
+            tx_id: "a_tx",
+            uuid: Default::default(),
+            offset: &mut 0,
+            parsing_started: SystemTime::now(),
+            network_name: "avax".to_string(),
+        },
+    )
+    .unwrap()
+}
+
+/// The tx of `decode_base_tx_01` , a mainnet base tx burning 1_000_000 nAVAX .
+pub fn base_tx() -> SignedTx {
+    parse_tx(&[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 237, 95, 56, 52, 30, 67, 110, 93, 70, 226, 187, 0, 180, 93,
+        98, 174, 151, 209, 176, 80, 198, 75, 198, 52, 174, 16, 98, 103, 57, 227, 92, 75, 0, 0, 0,
+        2, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34,
+        116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 7, 0, 0, 0, 2, 83,
+        252, 161, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 42, 35, 215, 240, 118, 8,
+        138, 33, 197, 9, 97, 96, 186, 60, 10, 37, 192, 219, 164, 53, 33, 230, 115, 23, 203, 196,
+        190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145,
+        178, 48, 39, 168, 125, 255, 0, 0, 0, 7, 0, 0, 0, 48, 228, 249, 180, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 1, 0, 0, 0, 1, 122, 5, 190, 208, 244, 252, 49, 242, 116, 207, 9, 107, 113, 183,
+        5, 74, 160, 146, 100, 127, 0, 0, 0, 1, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103,
+        122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125,
+        255, 0, 0, 3, 90, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120,
+        168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0,
+        0, 51, 57, 5, 152, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 9, 0, 0, 0,
+        1, 236, 195, 9, 6, 243, 194, 58, 178, 100, 232, 7, 152, 184, 28, 21, 9, 232, 80, 182, 118,
+        74, 73, 56, 134, 99, 6, 217, 234, 236, 78, 85, 177, 70, 3, 28, 54, 223, 91, 120, 234, 15,
+        63, 152, 106, 10, 134, 52, 47, 230, 197, 38, 251, 250, 187, 79, 107, 25, 248, 44, 31, 199,
+        221, 139, 118, 0,
+    ])
+}
+
+/// The tx of `decode_create_asset_tx_01` , signed by X-avax1d3qnae4dej47yd0pd28th7nxelwu6fp5mncjnd .
+pub fn create_asset_tx() -> SignedTx {
+    parse_tx(&[
+        0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 237, 95, 56, 52, 30, 67, 110, 93, 70, 226, 187, 0, 180, 93,
+        98, 174, 151, 209, 176, 80, 198, 75, 198, 52, 174, 16, 98, 103, 57, 227, 92, 75, 0, 0, 0,
+        1, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34,
+        116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 7, 0, 0, 0, 0, 58,
+        242, 241, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 108, 65, 62, 230, 173, 204,
+        171, 226, 53, 225, 106, 142, 187, 250, 102, 207, 221, 205, 36, 52, 0, 0, 0, 1, 39, 218, 48,
+        1, 162, 88, 227, 247, 53, 155, 110, 210, 22, 67, 196, 63, 129, 38, 106, 67, 107, 151, 169,
+        207, 146, 88, 17, 217, 224, 36, 52, 186, 0, 0, 0, 0, 33, 230, 115, 23, 203, 196, 190, 42,
+        235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48,
+        39, 168, 125, 255, 0, 0, 0, 5, 0, 0, 0, 0, 59, 139, 135, 192, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0,
+        0, 4, 0, 0, 0, 0, 0, 15, 84, 101, 115, 116, 32, 67, 111, 108, 108, 101, 99, 116, 105, 111,
+        110, 0, 4, 84, 69, 83, 84, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 10, 0, 0, 0, 10, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 108, 65, 62, 230, 173, 204, 171, 226, 53,
+        225, 106, 142, 187, 250, 102, 207, 221, 205, 36, 52, 0, 0, 0, 10, 0, 0, 0, 1, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 108, 65, 62, 230, 173, 204, 171, 226, 53, 225, 106,
+        142, 187, 250, 102, 207, 221, 205, 36, 52, 0, 0, 0, 10, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 1, 0, 0, 0, 1, 108, 65, 62, 230, 173, 204, 171, 226, 53, 225, 106, 142, 187, 250,
+        102, 207, 221, 205, 36, 52, 0, 0, 0, 10, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+        0, 0, 1, 108, 65, 62, 230, 173, 204, 171, 226, 53, 225, 106, 142, 187, 250, 102, 207, 221,
+        205, 36, 52, 0, 0, 0, 10, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 108,
+        65, 62, 230, 173, 204, 171, 226, 53, 225, 106, 142, 187, 250, 102, 207, 221, 205, 36, 52,
+        0, 0, 0, 10, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 108, 65, 62, 230,
+        173, 204, 171, 226, 53, 225, 106, 142, 187, 250, 102, 207, 221, 205, 36, 52, 0, 0, 0, 10,
+        0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 108, 65, 62, 230, 173, 204,
+        171, 226, 53, 225, 106, 142, 187, 250, 102, 207, 221, 205, 36, 52, 0, 0, 0, 10, 0, 0, 0, 7,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 108, 65, 62, 230, 173, 204, 171, 226, 53,
+        225, 106, 142, 187, 250, 102, 207, 221, 205, 36, 52, 0, 0, 0, 10, 0, 0, 0, 8, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 108, 65, 62, 230, 173, 204, 171, 226, 53, 225, 106,
+        142, 187, 250, 102, 207, 221, 205, 36, 52, 0, 0, 0, 10, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 1, 0, 0, 0, 1, 108, 65, 62, 230, 173, 204, 171, 226, 53, 225, 106, 142, 187, 250,
+        102, 207, 221, 205, 36, 52, 0, 0, 0, 1, 0, 0, 0, 9, 0, 0, 0, 1, 186, 174, 141, 174, 79,
+        193, 55, 231, 65, 189, 14, 118, 165, 15, 219, 111, 177, 164, 213, 157, 180, 45, 141, 77,
+        231, 13, 119, 153, 37, 87, 89, 151, 81, 179, 207, 221, 87, 79, 91, 86, 182, 242, 163, 233,
+        154, 169, 112, 178, 233, 127, 194, 188, 189, 41, 99, 125, 12, 60, 135, 61, 19, 97, 184,
+        105, 0,
+    ])
+}
+
+/// The UTXO spent by `create_asset_tx` , owned by `_address` alone .
+pub fn create_asset_tx_utxos(_address: &str) -> HashMap<UtxoId, Utxo> {
+    let tx = create_asset_tx();
+    let input = &tx.create_asset_tx.unwrap().base_tx.transferable_inputs[0];
+    let utxo_id = UtxoId::from(input);
+
+    let mut utxos = HashMap::new();
+    utxos.insert(
+        utxo_id.clone(),
+        Utxo {
+            codec_id: 0,
+            utxo_id: utxo_id.input_id().unwrap(),
+            tx_id: input.tx_id.clone(),
+            output_index: input.utxo_index,
+            asset_id: input.asset_id.clone(),
+            avm_output: Some(Output {
+                type_id: 7,
+                amount: Some(input.input.amount),
+                group_id: None,
+                payload: None,
+                locktime: 0,
+                threshold: 1,
+                addresses: vec![_address.to_string()],
+            }),
+            pvm_output: None,
+        },
+    );
+
+    utxos
+}
@@ -5,12 +5,13 @@ use std::borrow::Borrow;
 use std::error::Error;
 
 use crate::avm::parser::Context;
-use crate::utils::conversion::{pop_i32, pop_i64};
+use crate::utils::amount::Amount;
+use crate::utils::conversion::{pop_i32, pop_u64};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct SECP256KTransferInput {
     pub type_id: i32,
-    pub amount: i64,
+    pub amount: Amount,
     pub address_indices: Vec<i32>,
 }
 
@@ -54,7 +55,9 @@ pub fn secp256k1_transfer_input_parser(
     _context: &mut Context,
 ) -> Result<SECP256KTransferInput, Box<dyn Error>> {
     // Amount
-    let amount = pop_i64(_raw_msg[*_context.offset..=(*_context.offset + 7)].borrow());
+    let amount = Amount(pop_u64(
+        _raw_msg[*_context.offset..=(*_context.offset + 7)].borrow(),
+    ));
     trace!(
         "{} \n Input -- SECP256K1TransferInput-- Amount : {:?}",
         _context.tx_id,
@@ -6,6 +6,8 @@ pub mod base_tx_parser;
 pub mod create_asset_tx_parser;
 pub mod credential_parser;
 pub mod export_tx_parser;
+#[cfg(test)]
+pub mod fixtures;
 pub mod import_tx_parser;
 pub mod initial_state_parser;
 pub mod input_parser;
@@ -16,6 +18,7 @@ pub mod signed_tx_parser;
 pub mod transfer_op_parser;
 pub mod transferable_input_parser;
 pub mod transferable_output_parser;
+pub mod vertex_parser;
 
 /// Represent a Context object used to keep track of the ... context of a transaction being parsed
 #[derive(Debug)]
@@ -8,7 +8,7 @@ use crate::avm::parser::Context;
 use crate::utils::conversion::{pop_i32, pop_i64};
 use bech32::ToBase32;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct OutputOwner {
     pub locktime: i64,
     pub threshold: i32,
@@ -6,13 +6,15 @@ use std::borrow::Borrow;
 use bech32::ToBase32;
 use std::error::Error;
 
+use crate::avm::parser::output_owner_parser::output_owner_parser;
 use crate::avm::parser::Context;
-use crate::utils::conversion::{pop_i32, pop_i64, pop_u32};
+use crate::utils::amount::Amount;
+use crate::utils::conversion::{pop_i32, pop_i64, pop_u32, pop_u64};
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Output {
     pub type_id: i32,
-    pub amount: Option<i64>,
+    pub amount: Option<Amount>,
     pub group_id: Option<i32>,
     pub payload: Option<Vec<u8>>,
     pub locktime: i64,
@@ -34,6 +36,8 @@ pub fn output_parser(_raw_msg: &[u8], _context: &mut Context) -> Result<Output,
         7 => output = secp256k1_transfer_output_parser(_raw_msg, _context)?,
         10 => output = nft_mint_output_parser(_raw_msg, _context)?,
         11 => output = nft_transfer_output_parser(_raw_msg, _context)?,
+        15 => output = property_mint_output_parser(_raw_msg, _context)?,
+        16 => output = property_owned_output_parser(_raw_msg, _context)?,
         _ => {
             error!(
                 "{} \n This type id {} for this output is not expected \n Dump of the tx bytes : {:?} \n +++++++",
@@ -123,7 +127,9 @@ pub fn secp256k1_transfer_output_parser(
     _context: &mut Context,
 ) -> Result<Output, Box<dyn Error>> {
     // Amount
-    let amount = pop_i64(_raw_msg[*_context.offset..=(*_context.offset + 7)].borrow());
+    let amount = Amount(pop_u64(
+        _raw_msg[*_context.offset..=(*_context.offset + 7)].borrow(),
+    ));
     trace!(
         "{} \n Output -- SECP256K1TransferOutput -- Parser -- Amount : {:?}",
         _context.tx_id,
@@ -373,3 +379,51 @@ pub fn nft_transfer_output_parser(
         addresses,
     })
 }
+
+/// https://docs.avax.network/build/references/avm-transaction-serialization#property-mint-output
+#[instrument(skip(_raw_msg), fields(tx_id = %_context.tx_id))]
+pub fn property_mint_output_parser(
+    _raw_msg: &[u8],
+    _context: &mut Context,
+) -> Result<Output, Box<dyn Error>> {
+    let output_owner = output_owner_parser(_raw_msg, _context)?;
+    trace!(
+        "{} \n Output -- PropertyMintOutput Parser -- Owners : {:?}",
+        _context.tx_id,
+        output_owner
+    );
+
+    Ok(Output {
+        type_id: 15,
+        amount: None,
+        group_id: None,
+        payload: None,
+        locktime: output_owner.locktime,
+        threshold: output_owner.threshold,
+        addresses: output_owner.addresses,
+    })
+}
+
+/// https://docs.avax.network/build/references/avm-transaction-serialization#property-owned-output
+#[instrument(skip(_raw_msg), fields(tx_id = %_context.tx_id))]
+pub fn property_owned_output_parser(
+    _raw_msg: &[u8],
+    _context: &mut Context,
+) -> Result<Output, Box<dyn Error>> {
+    let output_owner = output_owner_parser(_raw_msg, _context)?;
+    trace!(
+        "{} \n Output -- PropertyOwnedOutput Parser -- Owners : {:?}",
+        _context.tx_id,
+        output_owner
+    );
+
+    Ok(Output {
+        type_id: 16,
+        amount: None,
+        group_id: None,
+        payload: None,
+        locktime: output_owner.locktime,
+        threshold: output_owner.threshold,
+        addresses: output_owner.addresses,
+    })
+}
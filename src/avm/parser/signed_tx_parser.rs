@@ -24,6 +24,8 @@ pub struct SignedTx {
     pub operation_tx: Option<OperationTx>,
     pub import_tx: Option<ImportTx>,
     pub export_tx: Option<ExportTx>,
+    /// The codec prefixed unsigned tx , the bytes signed by the credentials
+    pub unsigned_tx: Vec<u8>,
     pub credentials: Vec<Credential>,
 }
 
@@ -33,6 +35,7 @@ pub fn signed_tx_parser(
     _raw_msg: &[u8],
     _context: &mut Context,
 ) -> Result<SignedTx, Box<dyn Error>> {
+    let tx_start = *_context.offset;
     let codec_id = pop_i16(_raw_msg[*_context.offset..=(*_context.offset + 1)].borrow());
     trace!(
         "SignedTx Parser-- TxID: {} \n Codec_id : {:?} \n +++++++",
@@ -67,6 +70,7 @@ pub fn signed_tx_parser(
     }
 
     let unsigned_tx_offset = *_context.offset;
+    let unsigned_tx = _raw_msg[tx_start..unsigned_tx_offset].to_vec();
 
     // Number of credentials
     let number_of_credentials: u32 =
@@ -103,6 +107,7 @@ pub fn signed_tx_parser(
         credentials,
         type_id,
         export_tx: export,
+        unsigned_tx,
     })
 }
 
@@ -600,4 +605,142 @@ mod tests {
             "Checking if the type of the tx is correctly set"
         );
     }
+
+    #[test]
+    fn decode_create_asset_tx_property_fx_01() {
+        let raw_bytes: Vec<u8> = Vec::from([
+            0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 237, 95, 56, 52, 30, 67, 110, 93, 70, 226, 187, 0, 180,
+            93, 98, 174, 151, 209, 176, 80, 198, 75, 198, 52, 174, 16, 98, 103, 57, 227, 92, 75, 0,
+            0, 0, 0, 0, 0, 0, 1, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+            20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 0, 0, 0, 0, 33, 230, 115, 23, 203,
+            196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223,
+            37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0, 0, 0, 0, 152, 150, 128, 0, 0, 0,
+            1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 68, 101, 101, 100, 115, 0, 4, 68, 69, 69, 68, 0, 0, 0,
+            0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+            1, 108, 65, 62, 230, 173, 204, 171, 226, 53, 225, 106, 142, 187, 250, 102, 207, 221,
+            205, 36, 52, 0, 0, 0, 1, 0, 0, 0, 9, 0, 0, 0, 1, 0, 11, 22, 33, 44, 55, 66, 77, 88, 99,
+            110, 121, 132, 143, 154, 165, 176, 187, 198, 209, 220, 231, 242, 253, 8, 19, 30, 41,
+            52, 63, 74, 85, 96, 107, 118, 129, 140, 151, 162, 173, 184, 195, 206, 217, 228, 239,
+            250, 5, 16, 27, 38, 49, 60, 71, 82, 93, 104, 115, 126, 137, 148, 159, 170, 181, 192,
+        ]);
+        let tx = signed_tx_parser(
+            &raw_bytes,
+            &mut Context {
+                tx_id: "a_tx",
+                uuid: Default::default(),
+                offset: &mut 0,
+                parsing_started: SystemTime::now(),
+                network_name: "avax".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            tx.tx_id, "U2VWLY84CDpdzdgdCdpbSu2giYpw6ANYWXigphUSi2GmXqC1p",
+            "Checking if tx_id is correctly set"
+        );
+
+        let create_asset_tx = tx.create_asset_tx.unwrap();
+        let initial_state = create_asset_tx.initial_states.first().unwrap();
+        assert_eq!(
+            initial_state.fx_id, 2,
+            "Checking if the initial state is a propertyfx one"
+        );
+        assert_eq!(
+            initial_state.outputs.first().unwrap().type_id,
+            15,
+            "Checking if the initial state output is a property mint output"
+        );
+        assert_eq!(
+            initial_state.outputs.first().unwrap().addresses,
+            vec!["X-avax1d3qnae4dej47yd0pd28th7nxelwu6fp5mncjnd".to_string()],
+            "Checking if the property mint output owners are correctly set"
+        );
+    }
+
+    #[test]
+    fn decode_operation_tx_property_fx_01() {
+        let raw_bytes: Vec<u8> = Vec::from([
+            0, 0, 0, 0, 0, 2, 0, 0, 0, 1, 237, 95, 56, 52, 30, 67, 110, 93, 70, 226, 187, 0, 180,
+            93, 98, 174, 151, 209, 176, 80, 198, 75, 198, 52, 174, 16, 98, 103, 57, 227, 92, 75, 0,
+            0, 0, 0, 0, 0, 0, 1, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+            20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 0, 0, 0, 0, 33, 230, 115, 23, 203,
+            196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223,
+            37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0, 0, 0, 0, 152, 150, 128, 0, 0, 0,
+            1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51,
+            52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 0, 0,
+            0, 1, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115,
+            116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128, 129, 130, 131, 0, 0,
+            0, 0, 0, 0, 0, 17, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+            1, 108, 65, 62, 230, 173, 204, 171, 226, 53, 225, 106, 142, 187, 250, 102, 207, 221,
+            205, 36, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 108, 65, 62, 230, 173,
+            204, 171, 226, 53, 225, 106, 142, 187, 250, 102, 207, 221, 205, 36, 52, 40, 41, 42, 43,
+            44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65,
+            66, 67, 68, 69, 70, 71, 0, 0, 0, 1, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109,
+            110, 111, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126,
+            127, 128, 129, 130, 131, 0, 0, 0, 1, 0, 0, 0, 18, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3,
+            0, 0, 0, 9, 0, 0, 0, 1, 0, 11, 22, 33, 44, 55, 66, 77, 88, 99, 110, 121, 132, 143, 154,
+            165, 176, 187, 198, 209, 220, 231, 242, 253, 8, 19, 30, 41, 52, 63, 74, 85, 96, 107,
+            118, 129, 140, 151, 162, 173, 184, 195, 206, 217, 228, 239, 250, 5, 16, 27, 38, 49, 60,
+            71, 82, 93, 104, 115, 126, 137, 148, 159, 170, 181, 192, 0, 0, 0, 19, 0, 0, 0, 1, 0,
+            11, 22, 33, 44, 55, 66, 77, 88, 99, 110, 121, 132, 143, 154, 165, 176, 187, 198, 209,
+            220, 231, 242, 253, 8, 19, 30, 41, 52, 63, 74, 85, 96, 107, 118, 129, 140, 151, 162,
+            173, 184, 195, 206, 217, 228, 239, 250, 5, 16, 27, 38, 49, 60, 71, 82, 93, 104, 115,
+            126, 137, 148, 159, 170, 181, 192, 0, 0, 0, 19, 0, 0, 0, 1, 0, 11, 22, 33, 44, 55, 66,
+            77, 88, 99, 110, 121, 132, 143, 154, 165, 176, 187, 198, 209, 220, 231, 242, 253, 8,
+            19, 30, 41, 52, 63, 74, 85, 96, 107, 118, 129, 140, 151, 162, 173, 184, 195, 206, 217,
+            228, 239, 250, 5, 16, 27, 38, 49, 60, 71, 82, 93, 104, 115, 126, 137, 148, 159, 170,
+            181, 192,
+        ]);
+        let tx = signed_tx_parser(
+            &raw_bytes,
+            &mut Context {
+                tx_id: "a_tx",
+                uuid: Default::default(),
+                offset: &mut 0,
+                parsing_started: SystemTime::now(),
+                network_name: "avax".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            tx.tx_id, "JY5vM4AeWARXv9w5bJtEsVatVnRMPgDsw6AWnRWASDAFuhbJd",
+            "Checking if tx_id is correctly set"
+        );
+
+        let operation_tx = tx.operation_tx.unwrap();
+        let mint_op = operation_tx
+            .transferable_ops
+            .first()
+            .unwrap()
+            .property_mint_op
+            .as_ref()
+            .unwrap();
+        assert_eq!(mint_op.type_id, 17, "Checking if mint op is correctly set");
+        assert_eq!(
+            mint_op.mint_output.type_id, 15,
+            "Checking if mint op mint output is correctly set"
+        );
+        assert_eq!(
+            mint_op.owned_output.type_id, 16,
+            "Checking if mint op owned output is correctly set"
+        );
+
+        let burn_op = operation_tx
+            .transferable_ops
+            .get(1)
+            .unwrap()
+            .property_burn_op
+            .as_ref()
+            .unwrap();
+        assert_eq!(
+            burn_op.address_indices,
+            vec![0],
+            "Checking if burn op is correctly set"
+        );
+        assert_eq!(
+            tx.credentials.get(2).unwrap().type_id,
+            19,
+            "Checking if property credential is correctly set"
+        );
+    }
 }
@@ -2,11 +2,12 @@ use std::borrow::Borrow;
 use std::error::Error;
 
 use rust_base58::ToBase58;
-use tracing::{instrument, trace};
+use tracing::{error, instrument, trace};
 
 use crate::avm::parser::output_owner_parser::{output_owner_parser, OutputOwner};
 use crate::avm::parser::output_parser::{
-    secp256k1_mint_output_parser, secp256k1_transfer_output_parser, Output,
+    property_mint_output_parser, property_owned_output_parser, secp256k1_mint_output_parser,
+    secp256k1_transfer_output_parser, Output,
 };
 use crate::avm::parser::Context;
 use crate::utils::cb58::encode;
@@ -19,6 +20,8 @@ pub struct TransferableOperation {
     pub secp256k1_mint_op: Option<SECP256K1MintOp>,
     pub nft_mint_op: Option<NFTMintOp>,
     pub nft_transfer_op: Option<NFTTransferOp>,
+    pub property_mint_op: Option<PropertyMintOp>,
+    pub property_burn_op: Option<PropertyBurnOp>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -47,6 +50,22 @@ pub struct NFTTransferOp {
     pub output_owner: OutputOwner,
 }
 
+/// https://docs.avax.network/build/references/avm-transaction-serialization#property-mint-op
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PropertyMintOp {
+    pub type_id: i32,
+    pub address_indices: Vec<u32>,
+    pub mint_output: Output,
+    pub owned_output: Output,
+}
+
+/// https://docs.avax.network/build/references/avm-transaction-serialization#property-burn-op
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PropertyBurnOp {
+    pub type_id: i32,
+    pub address_indices: Vec<u32>,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct UtxoIds {
     pub tx_id: String,
@@ -114,13 +133,24 @@ pub fn transfer_op_parser(
     let mut secp256k1_mint_op = None;
     let mut nft_mint_op = None;
     let mut nft_transfer_op = None;
-
-    if type_id == 8 {
-        secp256k1_mint_op = Some(secp256k1_mint_operation_parser(_raw_msg, _context)?);
-    } else if type_id == 12 {
-        nft_mint_op = Some(nft_mint_operation_parser(_raw_msg, _context)?);
-    } else if type_id == 13 {
-        nft_transfer_op = Some(nft_transfer_operation_parser(_raw_msg, _context)?);
+    let mut property_mint_op = None;
+    let mut property_burn_op = None;
+
+    match type_id {
+        8 => secp256k1_mint_op = Some(secp256k1_mint_operation_parser(_raw_msg, _context)?),
+        12 => nft_mint_op = Some(nft_mint_operation_parser(_raw_msg, _context)?),
+        13 => nft_transfer_op = Some(nft_transfer_operation_parser(_raw_msg, _context)?),
+        17 => property_mint_op = Some(property_mint_operation_parser(_raw_msg, _context)?),
+        18 => property_burn_op = Some(property_burn_operation_parser(_raw_msg, _context)?),
+        _ => {
+            error!(
+                "{} \n This type id {} for this operation is not expected \n Dump of the tx bytes : {:?} \n +++++++",
+                _context.tx_id,
+                type_id,
+                _raw_msg
+            );
+            return Err(format!("Operation type id {} is not supported", type_id).into());
+        }
     }
 
     Ok(TransferableOperation {
@@ -129,6 +159,8 @@ pub fn transfer_op_parser(
         secp256k1_mint_op,
         nft_mint_op,
         nft_transfer_op,
+        property_mint_op,
+        property_burn_op,
     })
 }
 
@@ -352,3 +384,68 @@ pub fn nft_transfer_operation_parser(
         output_owner,
     })
 }
+
+#[instrument(skip(_raw_msg), fields(tx_id = % _context.tx_id))]
+pub fn property_mint_operation_parser(
+    _raw_msg: &[u8],
+    _context: &mut Context,
+) -> Result<PropertyMintOp, Box<dyn Error>> {
+    let address_indices = property_address_indices_parser(_raw_msg, _context)?;
+
+    let mint_output = property_mint_output_parser(_raw_msg, _context)?;
+    let owned_output = property_owned_output_parser(_raw_msg, _context)?;
+
+    Ok(PropertyMintOp {
+        type_id: 17,
+        address_indices,
+        mint_output,
+        owned_output,
+    })
+}
+
+#[instrument(skip(_raw_msg), fields(tx_id = % _context.tx_id))]
+pub fn property_burn_operation_parser(
+    _raw_msg: &[u8],
+    _context: &mut Context,
+) -> Result<PropertyBurnOp, Box<dyn Error>> {
+    let address_indices = property_address_indices_parser(_raw_msg, _context)?;
+
+    Ok(PropertyBurnOp {
+        type_id: 18,
+        address_indices,
+    })
+}
+
+fn property_address_indices_parser(
+    _raw_msg: &[u8],
+    _context: &mut Context,
+) -> Result<Vec<u32>, Box<dyn Error>> {
+    // Address indices number
+    let number_of_address_indice =
+        pop_u32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+    trace!(
+        "{} \n TransferOp -- PropertyOp -- Number of address indices : {:?}",
+        _context.tx_id,
+        number_of_address_indice
+    );
+    *_context.offset += 4;
+
+    // Addresses
+    let mut index = 0;
+    let mut address_indices = Vec::new();
+
+    while index < number_of_address_indice {
+        let address_indice = pop_u32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+        trace!(
+            "{} \n TransferOp -- PropertyOp Addresses indice number {} {:?}",
+            _context.tx_id,
+            index,
+            address_indice
+        );
+        address_indices.push(address_indice);
+        *_context.offset += 4;
+        index += 1;
+    }
+
+    Ok(address_indices)
+}
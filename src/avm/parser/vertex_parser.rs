@@ -0,0 +1,267 @@
+use rust_base58::ToBase58;
+use tracing::{instrument, trace};
+
+use std::borrow::Borrow;
+use std::error::Error;
+
+use crate::avm::parser::signed_tx_parser::{signed_tx_parser, SignedTx};
+use crate::avm::parser::Context;
+use crate::utils::cb58::encode;
+use crate::utils::conversion::{pop_i64, pop_u16, pop_u32};
+use crate::utils::misc::generate_id;
+
+/// Pre-Cortina X-chain vertex, as gossiped by the Avalanche consensus engine .
+/// A stop vertex (codec 1) carries no epoch and no transactions .
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Vertex {
+    pub codec_id: u16,
+    pub vertex_id: String,
+    pub chain_id: String,
+    pub height: i64,
+    pub epoch: u32,
+    pub parent_ids: Vec<String>,
+    pub transactions: Vec<SignedTx>,
+}
+
+/// Will parse a Vector of bytes (u8) containing a whole vertex and return a `Vertex` .
+/// Each embedded tx is parsed on its own bytes through `signed_tx_parser` .
+#[instrument(skip(_raw_msg), fields(tx_id = % _context.tx_id))]
+pub fn vertex_parser(_raw_msg: &[u8], _context: &mut Context) -> Result<Vertex, Box<dyn Error>> {
+    let vertex_start = *_context.offset;
+
+    let codec_id = pop_u16(_raw_msg[*_context.offset..=(*_context.offset + 1)].borrow());
+    trace!(
+        "Vertex Parser -- {} \n Codec_id : {:?} \n +++++++",
+        _context.tx_id,
+        codec_id
+    );
+    *_context.offset += 2;
+
+    // Chain Id
+    let chain_id = encode(&_raw_msg[*_context.offset..=(*_context.offset + 31)]).to_base58();
+    trace!(
+        "Vertex Parser -- {} \n Chain_id : {:?} \n +++++++",
+        _context.tx_id,
+        chain_id
+    );
+    *_context.offset += 32;
+
+    // Height
+    let height = pop_i64(_raw_msg[*_context.offset..=(*_context.offset + 7)].borrow());
+    trace!(
+        "Vertex Parser -- {} \n Height : {:?} \n +++++++",
+        _context.tx_id,
+        height
+    );
+    *_context.offset += 8;
+
+    // Epoch, only serialized in codec 0
+    let mut epoch = 0;
+    if codec_id == 0 {
+        epoch = pop_u32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+        trace!(
+            "Vertex Parser -- {} \n Epoch : {:?} \n +++++++",
+            _context.tx_id,
+            epoch
+        );
+        *_context.offset += 4;
+    }
+
+    // Parent Ids
+    let number_of_parents = pop_u32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+    trace!(
+        "Vertex Parser -- {} \n Number of parents : {:?} \n +++++++",
+        _context.tx_id,
+        number_of_parents
+    );
+    *_context.offset += 4;
+
+    let mut parent_ids = Vec::new();
+    let mut index = 0;
+
+    while index < number_of_parents {
+        let parent_id = encode(&_raw_msg[*_context.offset..=(*_context.offset + 31)]).to_base58();
+        trace!(
+            "Vertex Parser -- {} \n Parent number {} : {:?} \n +++++++",
+            _context.tx_id,
+            index,
+            parent_id
+        );
+        parent_ids.push(parent_id);
+        *_context.offset += 32;
+        index += 1;
+    }
+
+    // Transactions, only serialized in codec 0
+    let mut transactions = Vec::new();
+    if codec_id == 0 {
+        let number_of_txs = pop_u32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+        trace!(
+            "Vertex Parser -- {} \n Number of txs : {:?} \n +++++++",
+            _context.tx_id,
+            number_of_txs
+        );
+        *_context.offset += 4;
+
+        let mut index = 0;
+
+        while index < number_of_txs {
+            let tx_size =
+                pop_u32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow()) as usize;
+            trace!(
+                "Vertex Parser -- {} \n Tx number {} -- size {} \n +++++++",
+                _context.tx_id,
+                index,
+                tx_size
+            );
+            *_context.offset += 4;
+
+            // Every tx is parsed on its own bytes, as its id is the hash of those bytes only
+            let tx_bytes = &_raw_msg[*_context.offset..*_context.offset + tx_size];
+            let tx = signed_tx_parser(
+                tx_bytes,
+                &mut Context {
+                    tx_id: _context.tx_id,
+                    uuid: _context.uuid,
+                    offset: &mut 0,
+                    parsing_started: _context.parsing_started,
+                    network_name: _context.network_name.clone(),
+                },
+            )?;
+            transactions.push(tx);
+            *_context.offset += tx_size;
+            index += 1;
+        }
+    }
+
+    Ok(Vertex {
+        codec_id,
+        vertex_id: generate_id(&_raw_msg[vertex_start..*_context.offset]),
+        chain_id,
+        height,
+        epoch,
+        parent_ids,
+        transactions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+
+    // The signed base tx used in `signed_tx_parser::tests::decode_base_tx_01`
+    const BASE_TX: [u8; 379] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 237, 95, 56, 52, 30, 67, 110, 93, 70, 226, 187, 0, 180, 93,
+        98, 174, 151, 209, 176, 80, 198, 75, 198, 52, 174, 16, 98, 103, 57, 227, 92, 75, 0, 0, 0,
+        2, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34,
+        116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 7, 0, 0, 0, 2, 83,
+        252, 161, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 42, 35, 215, 240, 118, 8,
+        138, 33, 197, 9, 97, 96, 186, 60, 10, 37, 192, 219, 164, 53, 33, 230, 115, 23, 203, 196,
+        190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145,
+        178, 48, 39, 168, 125, 255, 0, 0, 0, 7, 0, 0, 0, 48, 228, 249, 180, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 1, 0, 0, 0, 1, 122, 5, 190, 208, 244, 252, 49, 242, 116, 207, 9, 107, 113, 183,
+        5, 74, 160, 146, 100, 127, 0, 0, 0, 1, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103,
+        122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125,
+        255, 0, 0, 3, 90, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120,
+        168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0,
+        0, 51, 57, 5, 152, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 9, 0, 0, 0,
+        1, 236, 195, 9, 6, 243, 194, 58, 178, 100, 232, 7, 152, 184, 28, 21, 9, 232, 80, 182, 118,
+        74, 73, 56, 134, 99, 6, 217, 234, 236, 78, 85, 177, 70, 3, 28, 54, 223, 91, 120, 234, 15,
+        63, 152, 106, 10, 134, 52, 47, 230, 197, 38, 251, 250, 187, 79, 107, 25, 248, 44, 31, 199,
+        221, 139, 118, 0,
+    ];
+
+    // X-chain id on mainnet
+    const CHAIN_ID: [u8; 32] = [
+        237, 95, 56, 52, 30, 67, 110, 93, 70, 226, 187, 0, 180, 93, 98, 174, 151, 209, 176, 80,
+        198, 75, 198, 52, 174, 16, 98, 103, 57, 227, 92, 75,
+    ];
+
+    fn vertex_header(codec_id: u16, epoch: bool) -> Vec<u8> {
+        let mut raw_bytes = Vec::new();
+        raw_bytes.extend_from_slice(&codec_id.to_be_bytes());
+        raw_bytes.extend_from_slice(&CHAIN_ID);
+        raw_bytes.extend_from_slice(&42_u64.to_be_bytes());
+        if epoch {
+            raw_bytes.extend_from_slice(&0_u32.to_be_bytes());
+        }
+        raw_bytes.extend_from_slice(&2_u32.to_be_bytes());
+        raw_bytes.extend_from_slice(&[1; 32]);
+        raw_bytes.extend_from_slice(&[2; 32]);
+        raw_bytes
+    }
+
+    #[test]
+    fn decode_vertex_01() {
+        let mut raw_bytes = vertex_header(0, true);
+        raw_bytes.extend_from_slice(&2_u32.to_be_bytes());
+        raw_bytes.extend_from_slice(&(BASE_TX.len() as u32).to_be_bytes());
+        raw_bytes.extend_from_slice(&BASE_TX);
+        raw_bytes.extend_from_slice(&(BASE_TX.len() as u32).to_be_bytes());
+        raw_bytes.extend_from_slice(&BASE_TX);
+
+        let vertex = vertex_parser(
+            &raw_bytes,
+            &mut Context {
+                tx_id: "a_vertex",
+                uuid: Default::default(),
+                offset: &mut 0,
+                parsing_started: SystemTime::now(),
+                network_name: "avax".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(vertex.codec_id, 0, "Checking if codec id is correctly set");
+        assert_eq!(
+            vertex.vertex_id, "M9YUmAq9cFkjjVWECBpBQigesjEckb2bakLEkcQ1Spm5i2PeX",
+            "Checking if vertex id is correctly set"
+        );
+        assert_eq!(
+            vertex.chain_id, "2oYMBNV4eNHyqk2fjjV5nVQLDbtmNJzq5s3qs3Lo6ftnC6FByM",
+            "Checking if chain id is correctly set"
+        );
+        assert_eq!(vertex.height, 42, "Checking if height is correctly set");
+        assert_eq!(
+            vertex.parent_ids,
+            vec![
+                "SeLqn3UAUoRymWmwW7axrzJK7JfNaBR2cHCryA6cFscgkny8",
+                "tHggZ5wKxbrxY2Yt1EAviybdDcKk9Mq4DZQiwKCDWkFWVpsj"
+            ],
+            "Checking if parent ids are correctly set"
+        );
+        assert_eq!(
+            vertex.transactions.len(),
+            2,
+            "Checking if both txs are parsed"
+        );
+        assert_eq!(
+            vertex.transactions[1].tx_id, "nVDmTRdjb9T83HPsZxd4SMZ1oEGymJ5sUrWnLB4X5MSFBowkP",
+            "Checking if tx_id is computed on the tx bytes only"
+        );
+    }
+
+    #[test]
+    fn decode_stop_vertex_01() {
+        let raw_bytes = vertex_header(1, false);
+
+        let vertex = vertex_parser(
+            &raw_bytes,
+            &mut Context {
+                tx_id: "a_vertex",
+                uuid: Default::default(),
+                offset: &mut 0,
+                parsing_started: SystemTime::now(),
+                network_name: "avax".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(vertex.codec_id, 1, "Checking if codec id is correctly set");
+        assert_eq!(vertex.parent_ids.len(), 2, "Checking parents are set");
+        assert_eq!(
+            vertex.transactions.len(),
+            0,
+            "Checking a stop vertex has no tx"
+        );
+    }
+}
@@ -0,0 +1,377 @@
+use std::error::Error;
+
+use crate::avm::parser::signed_tx_parser::SignedTx;
+use crate::avm::parser::transfer_op_parser::TransferableOperation;
+use crate::avm::parser::transferable_input_parser::TransferableInput;
+use crate::network::Network;
+use crate::utils::address::address_bytes;
+use crate::utils::fee::{EstimateFee, FeeConfig};
+use crate::utils::signature::recover_address;
+use crate::utxo::signers::{
+    resolve_input_signers, utxo_amount, utxo_locktime, utxo_type_id, InputSigners, UtxoLookup,
+};
+use crate::utxo::utxo_id::UtxoId;
+use crate::utxo::utxo_parser::Utxo;
+
+const SECP256K1_MINT_OUTPUT_TYPE_ID: i32 = 6;
+const SECP256K1_TRANSFER_OUTPUT_TYPE_ID: i32 = 7;
+const NFT_MINT_OUTPUT_TYPE_ID: i32 = 10;
+const NFT_TRANSFER_OUTPUT_TYPE_ID: i32 = 11;
+const PROPERTY_MINT_OUTPUT_TYPE_ID: i32 = 15;
+const PROPERTY_OWNED_OUTPUT_TYPE_ID: i32 = 16;
+
+impl SignedTx {
+    /// Check the tx against the UTXOs it spends , as AvalancheGo does before accepting it .
+    /// `_timestamp` is the unix time in seconds the locktimes of the UTXOs are compared to .
+    /// Returns the first rule broken , the syntax is checked by `verify_syntax` .
+    pub fn verify_semantics(
+        &self,
+        _utxos: &dyn UtxoLookup,
+        _timestamp: u64,
+        _fee_config: &FeeConfig,
+        _network: &Network,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut inputs: Vec<&TransferableInput> = Vec::new();
+        let mut operations: Vec<&TransferableOperation> = Vec::new();
+        if let Some(base_tx) = &self.base_tx {
+            inputs.extend(&base_tx.transferable_inputs);
+        }
+        if let Some(create_asset_tx) = &self.create_asset_tx {
+            inputs.extend(&create_asset_tx.base_tx.transferable_inputs);
+        }
+        if let Some(operation_tx) = &self.operation_tx {
+            inputs.extend(&operation_tx.base_tx.transferable_inputs);
+            operations.extend(&operation_tx.transferable_ops);
+        }
+        if let Some(import_tx) = &self.import_tx {
+            inputs.extend(&import_tx.base_tx.transferable_inputs);
+        }
+        if let Some(export_tx) = &self.export_tx {
+            inputs.extend(&export_tx.base_tx.transferable_inputs);
+        }
+        // Imported inputs come after the inputs of the base tx , and after no operation
+        let number_of_base_inputs = inputs.len();
+        if let Some(import_tx) = &self.import_tx {
+            inputs.extend(&import_tx.transferable_inputs);
+        }
+
+        let expected_credentials = inputs.len() + operations.len();
+        if self.credentials.len() != expected_credentials {
+            return Err(format!(
+                "Tx {} has {} credentials , expected {}",
+                self.tx_id,
+                self.credentials.len(),
+                expected_credentials
+            )
+            .into());
+        }
+
+        for (index, transferable_input) in inputs.iter().enumerate() {
+            let credential_index = if index < number_of_base_inputs {
+                index
+            } else {
+                index + operations.len()
+            };
+            let utxo_id = UtxoId::from(*transferable_input);
+            let utxo = spendable_utxo(_utxos, &utxo_id, _timestamp)?;
+
+            if utxo.asset_id != transferable_input.asset_id {
+                return Err(format!(
+                    "Input spending {} is of asset {} , the UTXO is of asset {}",
+                    utxo_id, transferable_input.asset_id, utxo.asset_id
+                )
+                .into());
+            }
+            if utxo_type_id(utxo) != Some(SECP256K1_TRANSFER_OUTPUT_TYPE_ID) {
+                return Err(format!("UTXO {} is not a transfer output", utxo_id).into());
+            }
+            let input_amount = transferable_input.input.amount;
+            if utxo_amount(utxo) != Some(input_amount) {
+                return Err(format!(
+                    "Input spending {} has amount {} , the UTXO has {:?}",
+                    utxo_id,
+                    input_amount,
+                    utxo_amount(utxo)
+                )
+                .into());
+            }
+
+            let address_indices: Vec<u32> = transferable_input
+                .input
+                .address_indices
+                .iter()
+                .map(|address_index| *address_index as u32)
+                .collect();
+            verify_signatures(
+                &self.unsigned_tx,
+                &resolve_input_signers(
+                    _utxos,
+                    &utxo_id,
+                    &address_indices,
+                    self.credentials.get(credential_index),
+                )?,
+            )?;
+        }
+
+        for (index, operation) in operations.iter().enumerate() {
+            let credential_index = number_of_base_inputs + index;
+            for utxo_ids in &operation.utxo_ids {
+                let utxo_id = UtxoId::from(utxo_ids);
+                let utxo = spendable_utxo(_utxos, &utxo_id, _timestamp)?;
+                if utxo.asset_id != operation.asset_id {
+                    return Err(format!(
+                        "Operation spending {} is on asset {} , the UTXO is of asset {}",
+                        utxo_id, operation.asset_id, utxo.asset_id
+                    )
+                    .into());
+                }
+
+                let address_indices = verify_operation_utxo(operation, &utxo_id, utxo)?;
+                verify_signatures(
+                    &self.unsigned_tx,
+                    &resolve_input_signers(
+                        _utxos,
+                        &utxo_id,
+                        &address_indices,
+                        self.credentials.get(credential_index),
+                    )?,
+                )?;
+            }
+        }
+
+        // Outputs may not exceed inputs for any asset , and at least the fee has to be burned in AVAX
+        let summary = self.balance_summary(_network)?;
+        let fee = self.estimate_fee(_fee_config);
+        if summary.fee < fee {
+            return Err(format!(
+                "Tx {} burns {} of AVAX , its fee is {}",
+                self.tx_id, summary.fee, fee
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+fn spendable_utxo<'a>(
+    _utxos: &'a dyn UtxoLookup,
+    _utxo_id: &UtxoId,
+    _timestamp: u64,
+) -> Result<&'a Utxo, Box<dyn Error>> {
+    let utxo = _utxos
+        .get_utxo(_utxo_id)
+        .ok_or_else(|| format!("UTXO {} not found", _utxo_id))?;
+
+    let locktime = utxo_locktime(utxo);
+    if locktime > 0 && locktime as u64 > _timestamp {
+        return Err(format!("UTXO {} is locked until {}", _utxo_id, locktime).into());
+    }
+
+    Ok(utxo)
+}
+
+/// Check the operation spends the kind of output it applies to , and return its address indices .
+/// A mint operation spends a mint output , so its owners are the ones allowed to mint , and the new mint output keeps them .
+fn verify_operation_utxo(
+    _operation: &TransferableOperation,
+    _utxo_id: &UtxoId,
+    _utxo: &Utxo,
+) -> Result<Vec<u32>, Box<dyn Error>> {
+    let output = _utxo
+        .avm_output
+        .as_ref()
+        .ok_or_else(|| format!("UTXO {} is not an X-chain output", _utxo_id))?;
+
+    let (expected_type_id, address_indices) = if let Some(mint_op) = &_operation.secp256k1_mint_op {
+        let mint_output = &mint_op.secp256k1_mint_output;
+        if mint_output.locktime != output.locktime
+            || mint_output.threshold != output.threshold
+            || mint_output.addresses != output.addresses
+        {
+            return Err(format!(
+                "Mint operation does not keep the owners of UTXO {}",
+                _utxo_id
+            )
+            .into());
+        }
+        let address_indices = mint_op
+            .address_indices
+            .iter()
+            .map(|address_index| *address_index as u32)
+            .collect();
+        (SECP256K1_MINT_OUTPUT_TYPE_ID, address_indices)
+    } else if let Some(nft_mint_op) = &_operation.nft_mint_op {
+        if output.group_id != Some(nft_mint_op.group_id) {
+            return Err(format!(
+                "NFT mint operation of group {} spends UTXO {} of group {:?}",
+                nft_mint_op.group_id, _utxo_id, output.group_id
+            )
+            .into());
+        }
+        (NFT_MINT_OUTPUT_TYPE_ID, nft_mint_op.address_indices.clone())
+    } else if let Some(nft_transfer_op) = &_operation.nft_transfer_op {
+        if output.group_id != Some(nft_transfer_op.group_id)
+            || output.payload.as_ref() != Some(&nft_transfer_op.payload)
+        {
+            return Err(format!(
+                "NFT transfer operation does not keep the group and payload of UTXO {}",
+                _utxo_id
+            )
+            .into());
+        }
+        (
+            NFT_TRANSFER_OUTPUT_TYPE_ID,
+            nft_transfer_op.address_indices.clone(),
+        )
+    } else if let Some(property_mint_op) = &_operation.property_mint_op {
+        (
+            PROPERTY_MINT_OUTPUT_TYPE_ID,
+            property_mint_op.address_indices.clone(),
+        )
+    } else if let Some(property_burn_op) = &_operation.property_burn_op {
+        (
+            PROPERTY_OWNED_OUTPUT_TYPE_ID,
+            property_burn_op.address_indices.clone(),
+        )
+    } else {
+        return Err(format!("Unknown operation spending UTXO {}", _utxo_id).into());
+    };
+
+    if output.type_id != expected_type_id {
+        return Err(format!(
+            "Operation expects an output of type {} , UTXO {} is of type {}",
+            expected_type_id, _utxo_id, output.type_id
+        )
+        .into());
+    }
+
+    Ok(address_indices)
+}
+
+/// Each signature must recover to the owner its address index points to .
+fn verify_signatures(
+    _unsigned_tx: &[u8],
+    _input_signers: &InputSigners,
+) -> Result<(), Box<dyn Error>> {
+    for signer in &_input_signers.signers {
+        let recovered = recover_address(_unsigned_tx, &signer.signature)?;
+        if recovered != address_bytes(&signer.address)? {
+            return Err(format!(
+                "Signature of address index {} of UTXO {} is not from {}",
+                signer.address_index, _input_signers.utxo_id, signer.address
+            )
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm::parser::fixtures::{create_asset_tx, create_asset_tx_utxos};
+    use crate::avm::parser::output_parser::Output;
+    use crate::avm::parser::transfer_op_parser::{SECP256K1MintOp, UtxoIds};
+    use crate::utils::amount::Amount;
+
+    #[test]
+    fn verify_semantics_01() {
+        let tx = create_asset_tx();
+        let network = Network::mainnet();
+        let utxos = create_asset_tx_utxos("X-avax1d3qnae4dej47yd0pd28th7nxelwu6fp5mncjnd");
+        assert!(
+            tx.verify_semantics(&utxos, 0, &FeeConfig::mainnet(), &network)
+                .is_ok(),
+            "Checking if the tx is semantically valid"
+        );
+
+        let mut fee_config = FeeConfig::mainnet();
+        fee_config.create_asset_tx_fee = 1_000_000;
+        assert!(
+            tx.verify_semantics(&utxos, 0, &fee_config, &network)
+                .is_ok(),
+            "Checking if burning more AVAX than the fee is allowed"
+        );
+        fee_config.create_asset_tx_fee = 20_000_000;
+        assert!(
+            tx.verify_semantics(&utxos, 0, &fee_config, &network)
+                .is_err(),
+            "Checking if burning less AVAX than the fee is rejected"
+        );
+
+        let utxos = create_asset_tx_utxos("X-avax19g3a0urkpz9zr3gfv9st50q2yhqdhfp4jgzn0d");
+        assert!(
+            tx.verify_semantics(&utxos, 0, &FeeConfig::mainnet(), &network)
+                .is_err(),
+            "Checking if a signature from another address is rejected"
+        );
+    }
+
+    #[test]
+    fn verify_operation_utxo_01() {
+        let mint_output = |_addresses: Vec<String>| Output {
+            type_id: SECP256K1_MINT_OUTPUT_TYPE_ID,
+            amount: None,
+            group_id: None,
+            payload: None,
+            locktime: 0,
+            threshold: 1,
+            addresses: _addresses,
+        };
+        let owner = "X-avax1d3qnae4dej47yd0pd28th7nxelwu6fp5mncjnd".to_string();
+        let utxo_id = UtxoId::new("SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2HdvDQU", 0);
+        let utxo = Utxo {
+            codec_id: 0,
+            utxo_id: utxo_id.input_id().unwrap(),
+            tx_id: utxo_id.tx_id.clone(),
+            output_index: 0,
+            asset_id: "FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z".to_string(),
+            avm_output: Some(mint_output(vec![owner.clone()])),
+            pvm_output: None,
+        };
+        let operation = |_addresses: Vec<String>| TransferableOperation {
+            asset_id: utxo.asset_id.clone(),
+            utxo_ids: vec![UtxoIds {
+                tx_id: utxo_id.tx_id.clone(),
+                utxo_index: 0,
+            }],
+            secp256k1_mint_op: Some(SECP256K1MintOp {
+                type_id: 8,
+                address_indices: vec![0],
+                secp256k1_mint_output: mint_output(_addresses),
+                secp256k1_transfer_output: Output {
+                    type_id: SECP256K1_TRANSFER_OUTPUT_TYPE_ID,
+                    amount: Some(Amount(1_000)),
+                    group_id: None,
+                    payload: None,
+                    locktime: 0,
+                    threshold: 1,
+                    addresses: vec!["X-avax19g3a0urkpz9zr3gfv9st50q2yhqdhfp4jgzn0d".to_string()],
+                },
+            }),
+            nft_mint_op: None,
+            nft_transfer_op: None,
+            property_mint_op: None,
+            property_burn_op: None,
+        };
+
+        assert_eq!(
+            verify_operation_utxo(&operation(vec![owner]), &utxo_id, &utxo).unwrap(),
+            vec![0],
+            "Checking if a mint keeping the owners returns its address indices"
+        );
+        assert!(
+            verify_operation_utxo(
+                &operation(vec![
+                    "X-avax19g3a0urkpz9zr3gfv9st50q2yhqdhfp4jgzn0d".to_string()
+                ]),
+                &utxo_id,
+                &utxo
+            )
+            .is_err(),
+            "Checking if a mint handing the mint output to other owners is rejected"
+        );
+    }
+}
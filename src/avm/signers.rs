@@ -0,0 +1,159 @@
+use std::error::Error;
+
+use crate::avm::parser::base_tx_parser::BaseTx;
+use crate::avm::parser::credential_parser::Credential;
+use crate::avm::parser::signed_tx_parser::SignedTx;
+use crate::avm::parser::transfer_op_parser::TransferableOperation;
+use crate::avm::parser::transferable_input_parser::TransferableInput;
+use crate::utxo::signers::{resolve_input_signers, InputSigners, UtxoLookup};
+use crate::utxo::utxo_id::UtxoId;
+
+impl SignedTx {
+    /// Resolve every input and operation of the tx to the addresses which had to sign it .
+    /// Credentials follow the inputs of the base tx , then the operations or the imported inputs .
+    pub fn resolve_signers(
+        &self,
+        _utxos: &dyn UtxoLookup,
+    ) -> Result<Vec<InputSigners>, Box<dyn Error>> {
+        let mut resolved = Vec::new();
+
+        if let Some(base_tx) = &self.base_tx {
+            resolve_base_tx(&mut resolved, _utxos, base_tx, &self.credentials)?;
+        }
+        if let Some(create_asset_tx) = &self.create_asset_tx {
+            resolve_base_tx(
+                &mut resolved,
+                _utxos,
+                &create_asset_tx.base_tx,
+                &self.credentials,
+            )?;
+        }
+        if let Some(operation_tx) = &self.operation_tx {
+            let offset = resolve_base_tx(
+                &mut resolved,
+                _utxos,
+                &operation_tx.base_tx,
+                &self.credentials,
+            )?;
+            for (index, operation) in operation_tx.transferable_ops.iter().enumerate() {
+                let credential = self.credentials.get(offset + index);
+                for utxo_ids in &operation.utxo_ids {
+                    resolved.push(resolve_input_signers(
+                        _utxos,
+                        &UtxoId::from(utxo_ids),
+                        &operation_address_indices(operation),
+                        credential,
+                    )?);
+                }
+            }
+        }
+        if let Some(import_tx) = &self.import_tx {
+            let offset =
+                resolve_base_tx(&mut resolved, _utxos, &import_tx.base_tx, &self.credentials)?;
+            resolve_inputs(
+                &mut resolved,
+                _utxos,
+                &import_tx.transferable_inputs,
+                self.credentials.get(offset..).unwrap_or_default(),
+            )?;
+        }
+        if let Some(export_tx) = &self.export_tx {
+            resolve_base_tx(&mut resolved, _utxos, &export_tx.base_tx, &self.credentials)?;
+        }
+
+        Ok(resolved)
+    }
+}
+
+/// Return the number of credentials used by the inputs of the base tx .
+fn resolve_base_tx(
+    _resolved: &mut Vec<InputSigners>,
+    _utxos: &dyn UtxoLookup,
+    _base_tx: &BaseTx,
+    _credentials: &[Credential],
+) -> Result<usize, Box<dyn Error>> {
+    resolve_inputs(
+        _resolved,
+        _utxos,
+        &_base_tx.transferable_inputs,
+        _credentials,
+    )?;
+
+    Ok(_base_tx.transferable_inputs.len())
+}
+
+fn resolve_inputs(
+    _resolved: &mut Vec<InputSigners>,
+    _utxos: &dyn UtxoLookup,
+    _inputs: &[TransferableInput],
+    _credentials: &[Credential],
+) -> Result<(), Box<dyn Error>> {
+    for (index, transferable_input) in _inputs.iter().enumerate() {
+        let address_indices: Vec<u32> = transferable_input
+            .input
+            .address_indices
+            .iter()
+            .map(|address_index| *address_index as u32)
+            .collect();
+        _resolved.push(resolve_input_signers(
+            _utxos,
+            &UtxoId::from(transferable_input),
+            &address_indices,
+            _credentials.get(index),
+        )?);
+    }
+
+    Ok(())
+}
+
+fn operation_address_indices(_operation: &TransferableOperation) -> Vec<u32> {
+    if let Some(mint_op) = &_operation.secp256k1_mint_op {
+        mint_op
+            .address_indices
+            .iter()
+            .map(|address_index| *address_index as u32)
+            .collect()
+    } else if let Some(nft_mint_op) = &_operation.nft_mint_op {
+        nft_mint_op.address_indices.clone()
+    } else if let Some(nft_transfer_op) = &_operation.nft_transfer_op {
+        nft_transfer_op.address_indices.clone()
+    } else if let Some(property_mint_op) = &_operation.property_mint_op {
+        property_mint_op.address_indices.clone()
+    } else if let Some(property_burn_op) = &_operation.property_burn_op {
+        property_burn_op.address_indices.clone()
+    } else {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::avm::parser::fixtures::{create_asset_tx, create_asset_tx_utxos};
+    use std::collections::HashMap;
+
+    #[test]
+    fn resolve_signers_01() {
+        let tx = create_asset_tx();
+        let utxos = create_asset_tx_utxos("X-avax1d3qnae4dej47yd0pd28th7nxelwu6fp5mncjnd");
+
+        let resolved = tx.resolve_signers(&utxos).unwrap();
+        assert_eq!(
+            resolved.len(),
+            1,
+            "Checking if the single input is resolved"
+        );
+        assert_eq!(resolved[0].threshold, 1, "Checking if the threshold is set");
+        assert_eq!(
+            resolved[0].signers[0].address, "X-avax1d3qnae4dej47yd0pd28th7nxelwu6fp5mncjnd",
+            "Checking if the signer is the owner of the UTXO"
+        );
+        assert_eq!(
+            resolved[0].signers[0].signature, tx.credentials[0].signatures[0],
+            "Checking if the signature comes from the credential of the input"
+        );
+        assert!(
+            tx.resolve_signers(&HashMap::new()).is_err(),
+            "Checking if an unknown UTXO is rejected"
+        );
+    }
+}
@@ -0,0 +1,311 @@
+use crate::avm::parser::base_tx_parser::BaseTx;
+use crate::avm::parser::output_parser::Output;
+use crate::avm::parser::signed_tx_parser::SignedTx;
+use crate::avm::parser::transferable_input_parser::TransferableInput;
+use crate::avm::parser::transferable_output_parser::TransferableOutput;
+use crate::utils::cb58::decode;
+use crate::utils::syntax::{
+    owners_bytes, verify_amount, verify_inputs_sorted_and_unique, verify_memo,
+    verify_outputs_sorted, verify_owners, violation, SyntaxViolation,
+};
+
+impl SignedTx {
+    /// Check the rules AvalancheGo enforces on a tx before looking at the state .
+    /// An empty list means the tx is syntactically valid .
+    pub fn verify_syntax(&self) -> Vec<SyntaxViolation> {
+        let mut violations = Vec::new();
+
+        if let Some(base_tx) = &self.base_tx {
+            verify_base_tx(&mut violations, base_tx);
+        }
+
+        if let Some(create_asset_tx) = &self.create_asset_tx {
+            verify_base_tx(&mut violations, &create_asset_tx.base_tx);
+
+            for (index, initial_state) in create_asset_tx.initial_states.iter().enumerate() {
+                let path = format!("initial_states[{}].outputs", index);
+                let mut keys = Vec::new();
+                for (output_index, output) in initial_state.outputs.iter().enumerate() {
+                    verify_output(
+                        &mut violations,
+                        &format!("{}[{}]", path, output_index),
+                        output,
+                    );
+                    keys.push(output_bytes(output));
+                }
+                verify_outputs_sorted(&mut violations, &path, &keys);
+            }
+        }
+
+        if let Some(operation_tx) = &self.operation_tx {
+            verify_base_tx(&mut violations, &operation_tx.base_tx);
+
+            for (index, operation) in operation_tx.transferable_ops.iter().enumerate() {
+                let path = format!("operations[{}]", index);
+                let utxos: Vec<(&str, i32)> = operation
+                    .utxo_ids
+                    .iter()
+                    .map(|utxo_id| (utxo_id.tx_id.as_str(), utxo_id.utxo_index))
+                    .collect();
+                verify_inputs_sorted_and_unique(
+                    &mut violations,
+                    &format!("{}.utxo_ids", path),
+                    &utxos,
+                );
+
+                if let Some(mint_op) = &operation.secp256k1_mint_op {
+                    verify_output(
+                        &mut violations,
+                        &format!("{}.mint_output", path),
+                        &mint_op.secp256k1_mint_output,
+                    );
+                    verify_output(
+                        &mut violations,
+                        &format!("{}.transfer_output", path),
+                        &mint_op.secp256k1_transfer_output,
+                    );
+                }
+                if let Some(nft_mint_op) = &operation.nft_mint_op {
+                    for (output_index, owner) in nft_mint_op.outputs.iter().enumerate() {
+                        verify_owners(
+                            &mut violations,
+                            &format!("{}.outputs[{}]", path, output_index),
+                            owner.threshold,
+                            &owner.addresses,
+                        );
+                    }
+                }
+                if let Some(nft_transfer_op) = &operation.nft_transfer_op {
+                    verify_owners(
+                        &mut violations,
+                        &format!("{}.output", path),
+                        nft_transfer_op.output_owner.threshold,
+                        &nft_transfer_op.output_owner.addresses,
+                    );
+                }
+                if let Some(property_mint_op) = &operation.property_mint_op {
+                    verify_output(
+                        &mut violations,
+                        &format!("{}.mint_output", path),
+                        &property_mint_op.mint_output,
+                    );
+                    verify_output(
+                        &mut violations,
+                        &format!("{}.owned_output", path),
+                        &property_mint_op.owned_output,
+                    );
+                }
+            }
+        }
+
+        if let Some(import_tx) = &self.import_tx {
+            verify_base_tx(&mut violations, &import_tx.base_tx);
+            verify_inputs(
+                &mut violations,
+                "imported_inputs",
+                &import_tx.transferable_inputs,
+            );
+        }
+
+        if let Some(export_tx) = &self.export_tx {
+            verify_base_tx(&mut violations, &export_tx.base_tx);
+            verify_outputs(
+                &mut violations,
+                "exported_outputs",
+                &export_tx.transferable_outputs,
+            );
+        }
+
+        violations
+    }
+}
+
+fn verify_base_tx(_violations: &mut Vec<SyntaxViolation>, _base_tx: &BaseTx) {
+    verify_outputs(_violations, "outputs", &_base_tx.transferable_outputs);
+    verify_inputs(_violations, "inputs", &_base_tx.transferable_inputs);
+    verify_memo(_violations, &_base_tx.memo);
+}
+
+fn verify_outputs(
+    _violations: &mut Vec<SyntaxViolation>,
+    _path: &str,
+    _outputs: &[TransferableOutput],
+) {
+    let mut keys = Vec::new();
+    for (index, transferable_output) in _outputs.iter().enumerate() {
+        let path = format!("{}[{}]", _path, index);
+        verify_output(_violations, &path, &transferable_output.output);
+
+        match decode(&transferable_output.asset_id) {
+            Ok(mut key) => {
+                key.extend(output_bytes(&transferable_output.output));
+                keys.push(key);
+            }
+            Err(err) => {
+                violation(_violations, &format!("{}.asset_id", path), &err.to_string());
+                return;
+            }
+        }
+    }
+
+    verify_outputs_sorted(_violations, _path, &keys);
+}
+
+fn verify_output(_violations: &mut Vec<SyntaxViolation>, _path: &str, _output: &Output) {
+    if let Some(amount) = _output.amount {
+        verify_amount(_violations, _path, amount);
+    }
+    verify_owners(_violations, _path, _output.threshold, &_output.addresses);
+}
+
+fn verify_inputs(
+    _violations: &mut Vec<SyntaxViolation>,
+    _path: &str,
+    _inputs: &[TransferableInput],
+) {
+    for (index, transferable_input) in _inputs.iter().enumerate() {
+        verify_amount(
+            _violations,
+            &format!("{}[{}]", _path, index),
+            transferable_input.input.amount,
+        );
+    }
+
+    let utxos: Vec<(&str, i32)> = _inputs
+        .iter()
+        .map(|input| (input.tx_id.as_str(), input.utxo_index))
+        .collect();
+    verify_inputs_sorted_and_unique(_violations, _path, &utxos);
+}
+
+/// The serialized form of an output , type id included , used to sort outputs the way AvalancheGo does .
+pub(crate) fn output_bytes(_output: &Output) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&_output.type_id.to_be_bytes());
+    if let Some(amount) = _output.amount {
+        bytes.extend_from_slice(&amount.to_be_bytes());
+    }
+    if let Some(group_id) = _output.group_id {
+        bytes.extend_from_slice(&group_id.to_be_bytes());
+    }
+    if let Some(payload) = &_output.payload {
+        bytes.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(payload);
+    }
+    bytes.extend(owners_bytes(
+        _output.locktime,
+        _output.threshold,
+        &_output.addresses,
+    ));
+
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm::parser::input_parser::SECP256KTransferInput;
+    use crate::utils::amount::Amount;
+
+    const AVAX: &str = "FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z";
+    const TX_ID: &str = "SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2HdvDQU";
+
+    fn output(_amount: u64, _threshold: i32, _addresses: &[&str]) -> TransferableOutput {
+        TransferableOutput {
+            asset_id: AVAX.to_string(),
+            output: Output {
+                type_id: 7,
+                amount: Some(Amount(_amount)),
+                group_id: None,
+                payload: None,
+                locktime: 0,
+                threshold: _threshold,
+                addresses: _addresses
+                    .iter()
+                    .map(|address| address.to_string())
+                    .collect(),
+            },
+        }
+    }
+
+    fn input(_utxo_index: i32, _amount: u64) -> TransferableInput {
+        TransferableInput {
+            tx_id: TX_ID.to_string(),
+            utxo_index: _utxo_index,
+            asset_id: AVAX.to_string(),
+            input: SECP256KTransferInput {
+                type_id: 5,
+                amount: Amount(_amount),
+                address_indices: vec![0],
+            },
+        }
+    }
+
+    fn base_tx(_outputs: Vec<TransferableOutput>, _inputs: Vec<TransferableInput>) -> SignedTx {
+        SignedTx {
+            codec_id: 0,
+            unsigned_tx_offset: 0,
+            type_id: 0,
+            tx_id: "a_tx".to_string(),
+            base_tx: Some(BaseTx {
+                type_id: 0,
+                network_id: 1,
+                blockchain_id: "2oYMBNV4eNHyqk2fjjV5nVQLDbtmNJzq5s3qs3Lo6ftnC6FByM".to_string(),
+                transferable_outputs: _outputs,
+                transferable_inputs: _inputs,
+                memo: vec![],
+            }),
+            create_asset_tx: None,
+            operation_tx: None,
+            import_tx: None,
+            export_tx: None,
+            unsigned_tx: vec![],
+            credentials: vec![],
+        }
+    }
+
+    #[test]
+    fn verify_syntax_01() {
+        let address = "X-avax1d3qnae4dej47yd0pd28th7nxelwu6fp5mncjnd";
+        let tx = base_tx(
+            vec![output(1_000, 1, &[address]), output(2_000, 1, &[address])],
+            vec![input(0, 3_000), input(1, 1_000)],
+        );
+        assert_eq!(
+            tx.verify_syntax(),
+            vec![],
+            "Checking a valid tx has no violation"
+        );
+    }
+
+    #[test]
+    fn verify_syntax_02() {
+        let address = "X-avax1d3qnae4dej47yd0pd28th7nxelwu6fp5mncjnd";
+        let mut tx = base_tx(
+            vec![
+                output(2_000, 1, &[address]),
+                output(1_000, 1, &[address]),
+                output(0, 2, &[address, address]),
+            ],
+            vec![input(1, 3_000), input(1, 1_000)],
+        );
+        tx.base_tx.as_mut().unwrap().memo = vec![0; 257];
+
+        let violations: Vec<String> = tx
+            .verify_syntax()
+            .iter()
+            .map(|violation| violation.to_string())
+            .collect();
+        assert_eq!(
+            violations,
+            vec![
+                "outputs[2].amount : amount is zero",
+                "outputs[2].addresses : addresses are not sorted and unique",
+                "outputs[1] : outputs are not sorted",
+                "inputs[1] : inputs are not sorted and unique",
+                "memo : memo is longer than 256 bytes",
+            ],
+            "Checking every violation is reported"
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use rust_base58::ToBase58;
+use tracing::{instrument, trace};
+
+use std::borrow::Borrow;
+use std::error::Error;
+
+use crate::avm::parser::Context;
+use crate::cvm::parser::evm_address;
+use crate::utils::amount::Amount;
+use crate::utils::cb58::encode;
+use crate::utils::conversion::{pop_i64, pop_u64};
+
+/// https://docs.avax.network/build/references/coreth-atomic-transaction-serialization#evm-input
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EVMInput {
+    pub address: String,
+    pub amount: Amount,
+    pub asset_id: String,
+    pub nonce: i64,
+}
+
+#[instrument(skip(_raw_msg), fields(tx_id = % _context.tx_id))]
+pub fn evm_input_parser(
+    _raw_msg: &[u8],
+    _context: &mut Context,
+) -> Result<EVMInput, Box<dyn Error>> {
+    // Address
+    let address = evm_address(&_raw_msg[*_context.offset..=(*_context.offset + 19)]);
+    trace!("{} \n EVMInput -- Address : {:?}", _context.tx_id, address);
+    *_context.offset += 20;
+
+    // Amount
+    let amount = Amount(pop_u64(
+        _raw_msg[*_context.offset..=(*_context.offset + 7)].borrow(),
+    ));
+    trace!("{} \n EVMInput -- Amount : {:?}", _context.tx_id, amount);
+    *_context.offset += 8;
+
+    // Asset Id
+    let asset_id = encode(&_raw_msg[*_context.offset..=(*_context.offset + 31)]).to_base58();
+    trace!("{} \n EVMInput -- AssetID : {:?}", _context.tx_id, asset_id);
+    *_context.offset += 32;
+
+    // Nonce
+    let nonce = pop_i64(_raw_msg[*_context.offset..=(*_context.offset + 7)].borrow());
+    trace!("{} \n EVMInput -- Nonce : {:?}", _context.tx_id, nonce);
+    *_context.offset += 8;
+
+    Ok(EVMInput {
+        address,
+        amount,
+        asset_id,
+        nonce,
+    })
+}
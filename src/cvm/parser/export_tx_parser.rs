@@ -0,0 +1,121 @@
+use rust_base58::ToBase58;
+use tracing::{instrument, trace};
+
+use std::borrow::Borrow;
+use std::error::Error;
+
+use crate::avm::parser::transferable_output_parser::{
+    transferable_output_parser, TransferableOutput,
+};
+use crate::avm::parser::Context;
+use crate::cvm::parser::evm_input_parser::{evm_input_parser, EVMInput};
+use crate::utils::cb58::encode;
+use crate::utils::conversion::{pop_i32, pop_u32};
+
+/// https://docs.avax.network/build/references/coreth-atomic-transaction-serialization#unsigned-export-tx
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportTx {
+    pub type_id: i32,
+    pub network_id: u32,
+    pub blockchain_id: String,
+    pub destination_chain: String,
+    pub evm_inputs: Vec<EVMInput>,
+    pub transferable_outputs: Vec<TransferableOutput>,
+}
+
+#[instrument(skip(_raw_msg), fields(tx_id = % _context.tx_id))]
+pub fn export_tx_parser(
+    _raw_msg: &[u8],
+    _context: &mut Context,
+) -> Result<ExportTx, Box<dyn Error>> {
+    let type_id = pop_i32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+    trace!(
+        "ExportTx Parser -- {} \n Type_id : {:?} \n +++++++",
+        _context.tx_id,
+        type_id
+    );
+    *_context.offset += 4;
+
+    // Network Id
+    let network_id = pop_u32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+    trace!(
+        "ExportTx Parser -- {} \n Network_id : {:?} \n +++++++",
+        _context.tx_id,
+        network_id
+    );
+    *_context.offset += 4;
+
+    // Blockchain Id
+    let blockchain_id = encode(&_raw_msg[*_context.offset..=(*_context.offset + 31)]).to_base58();
+    trace!(
+        "ExportTx Parser -- {} \n Blockchain_id : {:?} \n +++++++",
+        _context.tx_id,
+        blockchain_id
+    );
+    *_context.offset += 32;
+
+    // Destination chain
+    let destination_chain =
+        encode(&_raw_msg[*_context.offset..=(*_context.offset + 31)]).to_base58();
+    trace!(
+        "ExportTx Parser -- {} \n Destination chain : {:?} \n +++++++",
+        _context.tx_id,
+        destination_chain
+    );
+    *_context.offset += 32;
+
+    // Inputs Array Size
+    let number_of_inputs = pop_u32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+    trace!(
+        "ExportTx Parser -- {} \n Inputs' array size : {:?} \n +++++++",
+        _context.tx_id,
+        number_of_inputs
+    );
+    *_context.offset += 4;
+
+    // Inputs
+    let mut inputs = Vec::new();
+    let mut index = 0;
+
+    while index < number_of_inputs {
+        trace!(
+            "ExportTx Parser -- {} \n Input number {} \n +++++++",
+            _context.tx_id,
+            index
+        );
+        inputs.push(evm_input_parser(_raw_msg, _context)?);
+        index += 1;
+    }
+
+    // Outputs Array Size
+    let number_of_outputs = pop_u32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+    trace!(
+        "ExportTx Parser -- {} \n Outputs' array size : {:?} \n +++++++",
+        _context.tx_id,
+        number_of_outputs
+    );
+    *_context.offset += 4;
+
+    // Outputs
+    let mut outputs = Vec::new();
+    let mut index = 0;
+
+    while index < number_of_outputs {
+        trace!(
+            "ExportTx Parser -- {} \n Output number {} \n +++++++",
+            _context.tx_id,
+            index
+        );
+        outputs.push(transferable_output_parser(_raw_msg, _context)?);
+        index += 1;
+    }
+
+    Ok(ExportTx {
+        type_id,
+        network_id,
+        blockchain_id,
+        destination_chain,
+        evm_inputs: inputs,
+        transferable_outputs: outputs,
+    })
+}
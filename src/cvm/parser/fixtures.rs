@@ -0,0 +1,64 @@
+use std::time::SystemTime;
+
+use crate::avm::parser::Context;
+use crate::cvm::parser::signed_tx_parser::{signed_tx_parser, SignedTx};
+
+/// Txs shared by the tests of the modules replaying C-chain txs .
+pub fn parse_tx(_raw_bytes: &[u8]) -> SignedTx {
+    signed_tx_parser(
+        _raw_bytes,
+        &mut Context {
+            tx_id: "a_tx",
+            uuid: Default::default(),
+            offset: &mut 0,
+            parsing_started: SystemTime::now(),
+            network_name: "avax".to_string(),
+        },
+    )
+    .unwrap()
+}
+
+/// The tx of `decode_export_tx_01` , exporting 999_000_000 nAVAX to the X-chain for 1_000_000_000 .
+pub fn export_tx() -> SignedTx {
+    parse_tx(&[
+        0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 4, 39, 212, 178, 42, 42, 120, 188, 221, 212, 86, 116, 44,
+        175, 145, 181, 107, 173, 191, 249, 133, 238, 25, 174, 241, 69, 115, 231, 52, 63, 214, 82,
+        237, 95, 56, 52, 30, 67, 110, 93, 70, 226, 187, 0, 180, 93, 98, 174, 151, 209, 176, 80,
+        198, 75, 198, 52, 174, 16, 98, 103, 57, 227, 92, 75, 0, 0, 0, 1, 160, 161, 162, 163, 164,
+        165, 166, 167, 168, 169, 170, 171, 172, 173, 174, 175, 176, 177, 178, 179, 0, 0, 0, 0, 59,
+        154, 202, 0, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168,
+        245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 0, 0, 0, 0,
+        5, 0, 0, 0, 1, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120,
+        168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 7, 0, 0,
+        0, 0, 59, 139, 135, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 42, 35, 215, 240,
+        118, 8, 138, 33, 197, 9, 97, 96, 186, 60, 10, 37, 192, 219, 164, 53, 0, 0, 0, 1, 0, 0, 0,
+        9, 0, 0, 0, 1, 0, 7, 14, 21, 28, 35, 42, 49, 56, 63, 70, 77, 84, 91, 98, 105, 112, 119,
+        126, 133, 140, 147, 154, 161, 168, 175, 182, 189, 196, 203, 210, 217, 224, 231, 238, 245,
+        252, 3, 10, 17, 24, 31, 38, 45, 52, 59, 66, 73, 80, 87, 94, 101, 108, 115, 122, 129, 136,
+        143, 150, 157, 164, 171, 178, 185, 192,
+    ])
+}
+
+/// The tx of `decode_import_tx_01` , importing 500_000_000 nAVAX from the X-chain and burning 999_999 .
+pub fn import_tx() -> SignedTx {
+    parse_tx(&[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 4, 39, 212, 178, 42, 42, 120, 188, 221, 212, 86, 116, 44,
+        175, 145, 181, 107, 173, 191, 249, 133, 238, 25, 174, 241, 69, 115, 231, 52, 63, 214, 82,
+        237, 95, 56, 52, 30, 67, 110, 93, 70, 226, 187, 0, 180, 93, 98, 174, 151, 209, 176, 80,
+        198, 75, 198, 52, 174, 16, 98, 103, 57, 227, 92, 75, 0, 0, 0, 1, 1, 2, 3, 4, 5, 6, 7, 8, 9,
+        10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+        0, 0, 0, 0, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168,
+        245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0, 0,
+        0, 29, 205, 101, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 160, 161, 162, 163, 164, 165, 166,
+        167, 168, 169, 170, 171, 172, 173, 174, 175, 176, 177, 178, 179, 0, 0, 0, 0, 29, 190, 34,
+        192, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34,
+        116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 160, 161, 162, 163, 164, 165,
+        166, 167, 168, 169, 170, 171, 172, 173, 174, 175, 176, 177, 178, 179, 0, 0, 0, 0, 0, 0, 0,
+        1, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34,
+        116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 1, 0, 0, 0, 9, 0, 0,
+        0, 1, 0, 7, 14, 21, 28, 35, 42, 49, 56, 63, 70, 77, 84, 91, 98, 105, 112, 119, 126, 133,
+        140, 147, 154, 161, 168, 175, 182, 189, 196, 203, 210, 217, 224, 231, 238, 245, 252, 3, 10,
+        17, 24, 31, 38, 45, 52, 59, 66, 73, 80, 87, 94, 101, 108, 115, 122, 129, 136, 143, 150,
+        157, 164, 171, 178, 185, 192,
+    ])
+}
@@ -0,0 +1,118 @@
+use rust_base58::ToBase58;
+use tracing::{instrument, trace};
+
+use std::borrow::Borrow;
+use std::error::Error;
+
+use crate::avm::parser::transferable_input_parser::{transferable_input_parser, TransferableInput};
+use crate::avm::parser::Context;
+use crate::cvm::parser::evm_output_parser::{evm_output_parser, EVMOutput};
+use crate::utils::cb58::encode;
+use crate::utils::conversion::{pop_i32, pop_u32};
+
+/// https://docs.avax.network/build/references/coreth-atomic-transaction-serialization#unsigned-import-tx
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ImportTx {
+    pub type_id: i32,
+    pub network_id: u32,
+    pub blockchain_id: String,
+    pub source_chain: String,
+    pub transferable_inputs: Vec<TransferableInput>,
+    pub evm_outputs: Vec<EVMOutput>,
+}
+
+#[instrument(skip(_raw_msg), fields(tx_id = % _context.tx_id))]
+pub fn import_tx_parser(
+    _raw_msg: &[u8],
+    _context: &mut Context,
+) -> Result<ImportTx, Box<dyn Error>> {
+    let type_id = pop_i32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+    trace!(
+        "ImportTx Parser -- {} \n Type_id : {:?} \n +++++++",
+        _context.tx_id,
+        type_id
+    );
+    *_context.offset += 4;
+
+    // Network Id
+    let network_id = pop_u32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+    trace!(
+        "ImportTx Parser -- {} \n Network_id : {:?} \n +++++++",
+        _context.tx_id,
+        network_id
+    );
+    *_context.offset += 4;
+
+    // Blockchain Id
+    let blockchain_id = encode(&_raw_msg[*_context.offset..=(*_context.offset + 31)]).to_base58();
+    trace!(
+        "ImportTx Parser -- {} \n Blockchain_id : {:?} \n +++++++",
+        _context.tx_id,
+        blockchain_id
+    );
+    *_context.offset += 32;
+
+    // Source chain
+    let source_chain = encode(&_raw_msg[*_context.offset..=(*_context.offset + 31)]).to_base58();
+    trace!(
+        "ImportTx Parser -- {} \n Source chain : {:?} \n +++++++",
+        _context.tx_id,
+        source_chain
+    );
+    *_context.offset += 32;
+
+    // Inputs Array Size
+    let number_of_inputs = pop_u32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+    trace!(
+        "ImportTx Parser -- {} \n Inputs' array size : {:?} \n +++++++",
+        _context.tx_id,
+        number_of_inputs
+    );
+    *_context.offset += 4;
+
+    // Inputs
+    let mut inputs = Vec::new();
+    let mut index = 0;
+
+    while index < number_of_inputs {
+        trace!(
+            "ImportTx Parser -- {} \n Input number {} \n +++++++",
+            _context.tx_id,
+            index
+        );
+        inputs.push(transferable_input_parser(_raw_msg, _context)?);
+        index += 1;
+    }
+
+    // Outputs Array Size
+    let number_of_outputs = pop_u32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+    trace!(
+        "ImportTx Parser -- {} \n Outputs' array size : {:?} \n +++++++",
+        _context.tx_id,
+        number_of_outputs
+    );
+    *_context.offset += 4;
+
+    // Outputs
+    let mut outputs = Vec::new();
+    let mut index = 0;
+
+    while index < number_of_outputs {
+        trace!(
+            "ImportTx Parser -- {} \n Output number {} \n +++++++",
+            _context.tx_id,
+            index
+        );
+        outputs.push(evm_output_parser(_raw_msg, _context)?);
+        index += 1;
+    }
+
+    Ok(ImportTx {
+        type_id,
+        network_id,
+        blockchain_id,
+        source_chain,
+        transferable_inputs: inputs,
+        evm_outputs: outputs,
+    })
+}
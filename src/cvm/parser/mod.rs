@@ -0,0 +1,17 @@
+pub mod evm_input_parser;
+pub mod evm_output_parser;
+pub mod export_tx_parser;
+#[cfg(test)]
+pub mod fixtures;
+pub mod import_tx_parser;
+pub mod signed_tx_parser;
+
+/// Ethereum addresses are displayed as 0x prefixed lowercase hex .
+pub fn evm_address(_raw_address: &[u8]) -> String {
+    let hex: String = _raw_address
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+
+    format!("0x{}", hex)
+}
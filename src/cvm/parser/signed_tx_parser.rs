@@ -0,0 +1,266 @@
+use tracing::{instrument, trace};
+
+use std::borrow::Borrow;
+use std::error::Error;
+
+use crate::avm::parser::credential_parser::{credential_parser, Credential};
+use crate::avm::parser::Context;
+use crate::cvm::parser::export_tx_parser::{export_tx_parser, ExportTx};
+use crate::cvm::parser::import_tx_parser::{import_tx_parser, ImportTx};
+use crate::utils::conversion::{pop_i16, pop_i32, pop_u32};
+use crate::utils::misc::generate_id;
+
+/// C-chain atomic transaction, moving funds between the C-chain and the X or P chain .
+/// https://docs.avax.network/build/references/coreth-atomic-transaction-serialization
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SignedTx {
+    pub codec_id: i16,
+    pub unsigned_tx_offset: usize,
+    pub type_id: i32,
+    pub tx_id: String,
+    pub import_tx: Option<ImportTx>,
+    pub export_tx: Option<ExportTx>,
+    pub credentials: Vec<Credential>,
+}
+
+/// Will parse a Vector of bytes (u8) containing an atomic tx and return a `SignedTx`
+#[instrument(skip(_raw_msg), fields(tx_id = % _context.tx_id))]
+pub fn signed_tx_parser(
+    _raw_msg: &[u8],
+    _context: &mut Context,
+) -> Result<SignedTx, Box<dyn Error>> {
+    let codec_id = pop_i16(_raw_msg[*_context.offset..=(*_context.offset + 1)].borrow());
+    trace!(
+        "SignedTx Parser-- TxID: {} \n Codec_id : {:?} \n +++++++",
+        _context.tx_id,
+        codec_id
+    );
+    *_context.offset += 2;
+
+    let type_id = pop_i32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+    trace!(
+        "SignedTx Parser-- TxID: {} \n Type_id : {:?} \n +++++++",
+        _context.tx_id,
+        type_id
+    );
+
+    let mut import = None;
+    let mut export = None;
+
+    match type_id {
+        0 => import = Some(import_tx_parser(_raw_msg, _context)?),
+        1 => export = Some(export_tx_parser(_raw_msg, _context)?),
+        _ => {
+            return Err(format!(
+                "This atomic tx type is incorrect or not yet supported {}",
+                type_id
+            )
+            .into())
+        }
+    }
+
+    let unsigned_tx_offset = *_context.offset;
+
+    // Number of credentials
+    let number_of_credentials: u32 =
+        pop_u32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+    trace!(
+        "SignedTx Parser -- {} \n Credential number : {:?} \n +++++++",
+        _context.tx_id,
+        number_of_credentials
+    );
+    *_context.offset += 4;
+
+    // Credentials
+    let mut index = 0;
+    let mut credentials = Vec::new();
+    while index < number_of_credentials {
+        trace!(
+            "SignedTx Parser -- {} \n Credential number {} \n +++++++",
+            _context.tx_id,
+            index
+        );
+        credentials.push(credential_parser(_raw_msg, _context)?);
+        index += 1;
+    }
+
+    Ok(SignedTx {
+        codec_id,
+        unsigned_tx_offset,
+        type_id,
+        tx_id: generate_id(_raw_msg),
+        import_tx: import,
+        export_tx: export,
+        credentials,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+
+    #[test]
+    fn decode_export_tx_01() {
+        let raw_bytes: Vec<u8> = Vec::from([
+            0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 4, 39, 212, 178, 42, 42, 120, 188, 221, 212, 86, 116, 44,
+            175, 145, 181, 107, 173, 191, 249, 133, 238, 25, 174, 241, 69, 115, 231, 52, 63, 214,
+            82, 237, 95, 56, 52, 30, 67, 110, 93, 70, 226, 187, 0, 180, 93, 98, 174, 151, 209, 176,
+            80, 198, 75, 198, 52, 174, 16, 98, 103, 57, 227, 92, 75, 0, 0, 0, 1, 160, 161, 162,
+            163, 164, 165, 166, 167, 168, 169, 170, 171, 172, 173, 174, 175, 176, 177, 178, 179, 0,
+            0, 0, 0, 59, 154, 202, 0, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214,
+            70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255,
+            0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 1, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103,
+            122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168,
+            125, 255, 0, 0, 0, 7, 0, 0, 0, 0, 59, 139, 135, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1, 0, 0, 0, 1, 42, 35, 215, 240, 118, 8, 138, 33, 197, 9, 97, 96, 186, 60, 10, 37, 192,
+            219, 164, 53, 0, 0, 0, 1, 0, 0, 0, 9, 0, 0, 0, 1, 0, 7, 14, 21, 28, 35, 42, 49, 56, 63,
+            70, 77, 84, 91, 98, 105, 112, 119, 126, 133, 140, 147, 154, 161, 168, 175, 182, 189,
+            196, 203, 210, 217, 224, 231, 238, 245, 252, 3, 10, 17, 24, 31, 38, 45, 52, 59, 66, 73,
+            80, 87, 94, 101, 108, 115, 122, 129, 136, 143, 150, 157, 164, 171, 178, 185, 192,
+        ]);
+        let tx = signed_tx_parser(
+            &raw_bytes,
+            &mut Context {
+                tx_id: "a_tx",
+                uuid: Default::default(),
+                offset: &mut 0,
+                parsing_started: SystemTime::now(),
+                network_name: "avax".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            tx.tx_id, "2sSFTe7kxSUcctmBjNPksfM5m5st1bLQojsP765aABwGKECs7c",
+            "Checking if tx_id is correctly set"
+        );
+        assert_eq!(
+            tx.type_id, 1,
+            "Checking if the type of the tx is correctly set"
+        );
+        assert_eq!(
+            tx.unsigned_tx_offset, 230,
+            "Checking if the unsigned tx offset is correctly set"
+        );
+        assert!(tx.import_tx.is_none(), "Checking the tx is not an import");
+
+        let export_tx = tx.export_tx.unwrap();
+        assert_eq!(
+            export_tx.blockchain_id, "2q9e4r6Mu3U68nU1fYjgbR6JvwrRx36CohpAX5UQxse55x1Q5",
+            "Checking if blockchain id is correctly set"
+        );
+        assert_eq!(
+            export_tx.destination_chain, "2oYMBNV4eNHyqk2fjjV5nVQLDbtmNJzq5s3qs3Lo6ftnC6FByM",
+            "Checking if destination chain is correctly set"
+        );
+        assert_eq!(
+            export_tx.evm_inputs[0].address, "0xa0a1a2a3a4a5a6a7a8a9aaabacadaeafb0b1b2b3",
+            "Checking if evm input address is correctly set"
+        );
+        assert_eq!(
+            export_tx.evm_inputs[0].amount,
+            crate::utils::amount::Amount(1_000_000_000),
+            "Checking if evm input amount is correctly set"
+        );
+        assert_eq!(
+            export_tx.evm_inputs[0].nonce, 5,
+            "Checking if evm input nonce is correctly set"
+        );
+        assert_eq!(
+            export_tx.transferable_outputs[0].output.amount,
+            Some(crate::utils::amount::Amount(999_000_000)),
+            "Checking if exported output is correctly set"
+        );
+        assert_eq!(tx.credentials.len(), 1, "Checking credentials are set");
+    }
+
+    #[test]
+    fn decode_import_tx_01() {
+        let raw_bytes: Vec<u8> = Vec::from([
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 4, 39, 212, 178, 42, 42, 120, 188, 221, 212, 86, 116, 44,
+            175, 145, 181, 107, 173, 191, 249, 133, 238, 25, 174, 241, 69, 115, 231, 52, 63, 214,
+            82, 237, 95, 56, 52, 30, 67, 110, 93, 70, 226, 187, 0, 180, 93, 98, 174, 151, 209, 176,
+            80, 198, 75, 198, 52, 174, 16, 98, 103, 57, 227, 92, 75, 0, 0, 0, 1, 1, 2, 3, 4, 5, 6,
+            7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28,
+            29, 30, 31, 32, 0, 0, 0, 0, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214,
+            70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255,
+            0, 0, 0, 5, 0, 0, 0, 0, 29, 205, 101, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 160, 161,
+            162, 163, 164, 165, 166, 167, 168, 169, 170, 171, 172, 173, 174, 175, 176, 177, 178,
+            179, 0, 0, 0, 0, 29, 190, 34, 192, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103,
+            122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168,
+            125, 255, 160, 161, 162, 163, 164, 165, 166, 167, 168, 169, 170, 171, 172, 173, 174,
+            175, 176, 177, 178, 179, 0, 0, 0, 0, 0, 0, 0, 1, 33, 230, 115, 23, 203, 196, 190, 42,
+            235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178,
+            48, 39, 168, 125, 255, 0, 0, 0, 1, 0, 0, 0, 9, 0, 0, 0, 1, 0, 7, 14, 21, 28, 35, 42,
+            49, 56, 63, 70, 77, 84, 91, 98, 105, 112, 119, 126, 133, 140, 147, 154, 161, 168, 175,
+            182, 189, 196, 203, 210, 217, 224, 231, 238, 245, 252, 3, 10, 17, 24, 31, 38, 45, 52,
+            59, 66, 73, 80, 87, 94, 101, 108, 115, 122, 129, 136, 143, 150, 157, 164, 171, 178,
+            185, 192,
+        ]);
+        let tx = signed_tx_parser(
+            &raw_bytes,
+            &mut Context {
+                tx_id: "a_tx",
+                uuid: Default::default(),
+                offset: &mut 0,
+                parsing_started: SystemTime::now(),
+                network_name: "avax".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            tx.tx_id, "2QRh4bEtCEN3TwLJLKDvNBNjwJtCGs4XaVgXgV4NTViHDcSNY4",
+            "Checking if tx_id is correctly set"
+        );
+        assert_eq!(
+            tx.type_id, 0,
+            "Checking if the type of the tx is correctly set"
+        );
+        assert_eq!(
+            tx.unsigned_tx_offset, 290,
+            "Checking if the unsigned tx offset is correctly set"
+        );
+
+        let import_tx = tx.import_tx.unwrap();
+        assert_eq!(
+            import_tx.source_chain, "2oYMBNV4eNHyqk2fjjV5nVQLDbtmNJzq5s3qs3Lo6ftnC6FByM",
+            "Checking if source chain is correctly set"
+        );
+        assert_eq!(
+            import_tx.transferable_inputs[0].input.amount,
+            crate::utils::amount::Amount(500_000_000),
+            "Checking if imported input is correctly set"
+        );
+        assert_eq!(
+            import_tx.evm_outputs.len(),
+            2,
+            "Checking evm outputs are set"
+        );
+        assert_eq!(
+            import_tx.evm_outputs[0].amount,
+            crate::utils::amount::Amount(499_000_000),
+            "Checking if evm output amount is correctly set"
+        );
+        assert_eq!(
+            import_tx.evm_outputs[1].asset_id, "FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z",
+            "Checking if evm output asset id is correctly set"
+        );
+    }
+    #[test]
+    fn decode_unknown_tx_01() {
+        assert!(
+            signed_tx_parser(
+                &[0, 0, 0, 0, 0, 2],
+                &mut Context {
+                    tx_id: "a_tx",
+                    uuid: Default::default(),
+                    offset: &mut 0,
+                    parsing_started: SystemTime::now(),
+                    network_name: "avax".to_string(),
+                },
+            )
+            .is_err(),
+            "Checking if an unknown atomic tx type is an error"
+        );
+    }
+}
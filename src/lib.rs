@@ -2,6 +2,12 @@
 #[macro_use]
 extern crate serde_derive;
 
+pub mod asset;
 pub mod avm;
+pub mod cvm;
+pub mod network;
+pub mod proposervm;
 pub mod pvm;
+pub mod supply;
 pub mod utils;
+pub mod utxo;
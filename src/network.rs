@@ -0,0 +1,44 @@
+/// The parameters of an Avalanche network the parsed txs belong to .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Network {
+    pub network_id: u32,
+    /// The human readable part of the bech32 addresses , also the `Context.network_name`
+    pub hrp: String,
+    pub avax_asset_id: String,
+    pub x_chain_id: String,
+    pub p_chain_id: String,
+    pub c_chain_id: String,
+}
+
+impl Network {
+    pub fn mainnet() -> Network {
+        Network {
+            network_id: 1,
+            hrp: "avax".to_string(),
+            avax_asset_id: "FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z".to_string(),
+            x_chain_id: "2oYMBNV4eNHyqk2fjjV5nVQLDbtmNJzq5s3qs3Lo6ftnC6FByM".to_string(),
+            p_chain_id: "11111111111111111111111111111111LpoYY".to_string(),
+            c_chain_id: "2q9e4r6Mu3U68nU1fYjgbR6JvwrRx36CohpAX5UQxse55x1Q5".to_string(),
+        }
+    }
+
+    pub fn fuji() -> Network {
+        Network {
+            network_id: 5,
+            hrp: "fuji".to_string(),
+            avax_asset_id: "U8iRqJoiJm8xZHAacmvYyZVwqQx6uDNtQeP3CQ6fcgQk3JqnK".to_string(),
+            x_chain_id: "2JVSBoinj9C2J33VntvzYtVJNZdN2NKiwwKjcumHUWEb5DbBrm".to_string(),
+            p_chain_id: "11111111111111111111111111111111LpoYY".to_string(),
+            c_chain_id: "yH8D7ThNJkxmtkuv2jgBa4P1Rn3Qpr4pPr7QYNfcdoS6k6HWp".to_string(),
+        }
+    }
+
+    /// Return the known network matching the network id found in a tx .
+    pub fn from_network_id(_network_id: u32) -> Option<Network> {
+        match _network_id {
+            1 => Some(Network::mainnet()),
+            5 => Some(Network::fuji()),
+            _ => None,
+        }
+    }
+}
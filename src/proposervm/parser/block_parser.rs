@@ -0,0 +1,317 @@
+use ring::digest;
+use ripemd160::{Digest, Ripemd160};
+use rust_base58::ToBase58;
+use tracing::{instrument, trace};
+
+use std::error::Error;
+
+use crate::avm::parser::Context;
+use crate::pvm::parser::block_parser::{block_parser, Block};
+use crate::utils::cb58::encode;
+use crate::utils::conversion::{pop_i32, pop_i64, pop_u16, pop_u32};
+use crate::utils::misc::generate_id;
+
+/// A container as served by a node running the proposervm .
+/// A pre-fork block has neither `post_fork_block` nor `option_block` set, its bytes are the inner block .
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ProposerBlock {
+    pub block_id: String,
+    pub post_fork_block: Option<PostForkBlock>,
+    pub option_block: Option<OptionBlock>,
+    pub inner_block: Vec<u8>,
+}
+
+/// https://github.com/ava-labs/avalanchego/blob/master/vms/proposervm/block/block.go
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PostForkBlock {
+    pub codec_id: u16,
+    pub parent_id: String,
+    pub timestamp: i64,
+    pub p_chain_height: i64,
+    pub certificate: Vec<u8>,
+    /// `None` when the block was built without a proposer window, so without certificate
+    pub proposer_node_id: Option<String>,
+    pub signature: Vec<u8>,
+}
+
+/// https://github.com/ava-labs/avalanchego/blob/master/vms/proposervm/block/option.go
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OptionBlock {
+    pub codec_id: u16,
+    pub parent_id: String,
+}
+
+/// Will parse a Vector of bytes (u8) and return a `ProposerBlock` .
+/// Bytes that are not exactly a post-fork block or an option block are handled as a pre-fork block .
+#[instrument(skip(_raw_msg), fields(block_id = % _context.tx_id))]
+pub fn proposer_block_parser(
+    _raw_msg: &[u8],
+    _context: &mut Context,
+) -> Result<ProposerBlock, Box<dyn Error>> {
+    let block_start = *_context.offset;
+    let block_id = generate_id(&_raw_msg[block_start..]);
+    trace!("Block id : {:?}", block_id);
+
+    match post_fork_block_parser(_raw_msg, _context) {
+        Ok(block) => Ok(block),
+        Err(err) => {
+            trace!("Not a post-fork block ({}), handling it as pre-fork", err);
+            *_context.offset = _raw_msg.len();
+
+            Ok(ProposerBlock {
+                block_id,
+                post_fork_block: None,
+                option_block: None,
+                inner_block: _raw_msg[block_start..].to_vec(),
+            })
+        }
+    }
+}
+
+/// Will parse a post-fork block or an option block , failing if the bytes do not match exactly one of them .
+#[instrument(skip(_raw_msg), fields(block_id = % _context.tx_id))]
+pub fn post_fork_block_parser(
+    _raw_msg: &[u8],
+    _context: &mut Context,
+) -> Result<ProposerBlock, Box<dyn Error>> {
+    let block_start = *_context.offset;
+
+    let codec_id = pop_u16(read_bytes(_raw_msg, _context, 2)?);
+    trace!("Codec_id : {:?}", codec_id);
+    if codec_id != 0 {
+        return Err(format!("Unknown proposervm codec {}", codec_id).into());
+    }
+
+    let type_id = pop_i32(read_bytes(_raw_msg, _context, 4)?);
+    trace!("Type_id : {:?}", type_id);
+
+    let parent_id = encode(read_bytes(_raw_msg, _context, 32)?).to_base58();
+    trace!("Parent block id : {:?}", parent_id);
+
+    let mut post_fork_block = None;
+    let mut option_block = None;
+    let inner_block;
+
+    match type_id {
+        0 => {
+            let timestamp = pop_i64(read_bytes(_raw_msg, _context, 8)?);
+            trace!("Timestamp : {:?}", timestamp);
+
+            let p_chain_height = pop_i64(read_bytes(_raw_msg, _context, 8)?);
+            trace!("P-chain height : {:?}", p_chain_height);
+
+            let certificate = read_sized_bytes(_raw_msg, _context)?.to_vec();
+            trace!("Certificate size : {:?}", certificate.len());
+
+            inner_block = read_sized_bytes(_raw_msg, _context)?.to_vec();
+            trace!("Inner block size : {:?}", inner_block.len());
+
+            let signature = read_sized_bytes(_raw_msg, _context)?.to_vec();
+            trace!("Signature size : {:?}", signature.len());
+
+            let mut proposer_node_id = None;
+            if !certificate.is_empty() {
+                proposer_node_id = Some(node_id_from_certificate(&certificate));
+            }
+
+            post_fork_block = Some(PostForkBlock {
+                codec_id,
+                parent_id,
+                timestamp,
+                p_chain_height,
+                certificate,
+                proposer_node_id,
+                signature,
+            });
+        }
+        1 => {
+            inner_block = read_sized_bytes(_raw_msg, _context)?.to_vec();
+            trace!("Inner block size : {:?}", inner_block.len());
+
+            option_block = Some(OptionBlock {
+                codec_id,
+                parent_id,
+            });
+        }
+        _ => {
+            return Err(format!("Unknown proposervm block type {}", type_id).into());
+        }
+    }
+
+    if *_context.offset != _raw_msg.len() {
+        let remaining = _raw_msg.len() - *_context.offset;
+        return Err(format!("{} bytes left after the proposervm block", remaining).into());
+    }
+
+    Ok(ProposerBlock {
+        block_id: generate_id(&_raw_msg[block_start..]),
+        post_fork_block,
+        option_block,
+        inner_block,
+    })
+}
+
+/// Hand the inner block of a P-chain `ProposerBlock` to the P-chain `block_parser` .
+pub fn inner_pvm_block_parser(
+    _proposer_block: &ProposerBlock,
+    _context: &mut Context,
+) -> Result<Block, Box<dyn Error>> {
+    let mut inner_block = _proposer_block.inner_block.clone();
+
+    block_parser(
+        &mut inner_block,
+        &mut Context {
+            tx_id: _context.tx_id,
+            uuid: _context.uuid,
+            offset: &mut 0,
+            parsing_started: _context.parsing_started,
+            network_name: _context.network_name.clone(),
+        },
+    )
+}
+
+/// The node ID of a staker is the ripemd160 of the sha256 of its DER encoded staking certificate .
+pub fn node_id_from_certificate(_certificate: &[u8]) -> String {
+    let certificate_hash = digest::digest(&digest::SHA256, _certificate);
+    let node_id = Ripemd160::digest(certificate_hash.as_ref());
+
+    encode(&node_id).to_base58()
+}
+
+/// As we do not know up front whether the bytes are a post-fork block , we check bounds instead of panicking .
+fn read_bytes<'a>(
+    _raw_msg: &'a [u8],
+    _context: &mut Context,
+    _size: usize,
+) -> Result<&'a [u8], Box<dyn Error>> {
+    let bytes = _raw_msg
+        .get(*_context.offset..*_context.offset + _size)
+        .ok_or("Unexpected end of the proposervm block")?;
+    *_context.offset += _size;
+
+    Ok(bytes)
+}
+
+fn read_sized_bytes<'a>(
+    _raw_msg: &'a [u8],
+    _context: &mut Context,
+) -> Result<&'a [u8], Box<dyn Error>> {
+    let size = pop_u32(read_bytes(_raw_msg, _context, 4)?) as usize;
+
+    read_bytes(_raw_msg, _context, size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+
+    // The abort block used in `pvm::parser::block_parser::tests::decode_abort_block_01`
+    const ABORT_BLOCK: [u8; 46] = [
+        0, 0, 0, 0, 0, 1, 128, 191, 235, 71, 118, 237, 54, 75, 114, 28, 67, 164, 13, 192, 223, 102,
+        42, 30, 82, 24, 129, 224, 124, 80, 33, 151, 115, 179, 126, 254, 93, 134, 0, 0, 0, 0, 0, 2,
+        237, 249,
+    ];
+
+    fn context(offset: &mut usize) -> Context<'_> {
+        Context {
+            tx_id: "a_block",
+            uuid: Default::default(),
+            offset,
+            parsing_started: SystemTime::now(),
+            network_name: "avax".to_string(),
+        }
+    }
+
+    #[test]
+    fn decode_pre_fork_block_01() {
+        let block = proposer_block_parser(&ABORT_BLOCK, &mut context(&mut 0)).unwrap();
+
+        assert!(
+            block.post_fork_block.is_none(),
+            "Checking if the block is handled as pre-fork"
+        );
+        assert!(
+            block.option_block.is_none(),
+            "Checking if the block is handled as pre-fork"
+        );
+        assert_eq!(
+            block.inner_block,
+            ABORT_BLOCK.to_vec(),
+            "Checking if inner block is the whole container"
+        );
+
+        let inner = inner_pvm_block_parser(&block, &mut context(&mut 0)).unwrap();
+        assert_eq!(inner.type_id, 1, "Checking if inner block is parsed");
+        assert_eq!(
+            inner.block_data.height, 191993,
+            "Checking if inner block is parsed"
+        );
+    }
+
+    #[test]
+    fn decode_post_fork_block_01() {
+        let mut raw_bytes = Vec::new();
+        raw_bytes.extend_from_slice(&[0, 0, 0, 0, 0, 0]);
+        raw_bytes.extend_from_slice(&[7; 32]);
+        raw_bytes.extend_from_slice(&1_620_000_000_i64.to_be_bytes());
+        raw_bytes.extend_from_slice(&1234_u64.to_be_bytes());
+        raw_bytes.extend_from_slice(&4_u32.to_be_bytes());
+        raw_bytes.extend_from_slice(b"cert");
+        raw_bytes.extend_from_slice(&(ABORT_BLOCK.len() as u32).to_be_bytes());
+        raw_bytes.extend_from_slice(&ABORT_BLOCK);
+        raw_bytes.extend_from_slice(&3_u32.to_be_bytes());
+        raw_bytes.extend_from_slice(&[9, 9, 9]);
+
+        let mut offset = 0;
+        let block = proposer_block_parser(&raw_bytes, &mut context(&mut offset)).unwrap();
+        assert_eq!(offset, raw_bytes.len(), "Checking all bytes are consumed");
+        assert_eq!(
+            block.block_id, "2vQi5cdNUfgTD6AirSnpLw8VwE31iNUZAdNjxd7gKgqFvQtktM",
+            "Checking if block id is correctly set"
+        );
+
+        let post_fork_block = block.post_fork_block.as_ref().unwrap();
+        assert_eq!(
+            post_fork_block.timestamp, 1_620_000_000,
+            "Checking if timestamp is correctly set"
+        );
+        assert_eq!(
+            post_fork_block.p_chain_height, 1234,
+            "Checking if P-chain height is correctly set"
+        );
+        assert_eq!(
+            post_fork_block.proposer_node_id,
+            Some("JemSK6MWQdZ8D9LG7n4udBK1FpftczXbK".to_string()),
+            "Checking if proposer node id is derived from the certificate"
+        );
+        assert_eq!(
+            post_fork_block.signature,
+            vec![9, 9, 9],
+            "Checking if signature is correctly set"
+        );
+
+        let inner = inner_pvm_block_parser(&block, &mut context(&mut 0)).unwrap();
+        assert_eq!(inner.type_id, 1, "Checking if inner block is parsed");
+    }
+
+    #[test]
+    fn decode_option_block_01() {
+        let mut raw_bytes = Vec::new();
+        raw_bytes.extend_from_slice(&[0, 0, 0, 0, 0, 1]);
+        raw_bytes.extend_from_slice(&[7; 32]);
+        raw_bytes.extend_from_slice(&(ABORT_BLOCK.len() as u32).to_be_bytes());
+        raw_bytes.extend_from_slice(&ABORT_BLOCK);
+
+        let block = proposer_block_parser(&raw_bytes, &mut context(&mut 0)).unwrap();
+        assert!(
+            block.option_block.is_some(),
+            "Checking if the block is an option"
+        );
+        assert_eq!(
+            block.inner_block,
+            ABORT_BLOCK.to_vec(),
+            "Checking if inner block is correctly set"
+        );
+    }
+}
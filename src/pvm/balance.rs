@@ -0,0 +1,97 @@
+use std::error::Error;
+
+use crate::network::Network;
+use crate::pvm::parser::atomic_block_parser::Transaction;
+use crate::pvm::parser::transferable_input_parser::TransferableInput;
+use crate::pvm::parser::transferable_output_parser::TransferableOutput;
+use crate::utils::balance::BalanceSummary;
+
+impl Transaction {
+    /// Per asset totals of the inputs and outputs of the tx , exported and staked outputs included .
+    pub fn balance_summary(&self, _network: &Network) -> Result<BalanceSummary, Box<dyn Error>> {
+        let mut summary = BalanceSummary::default();
+
+        add_inputs(&mut summary, &self.base_tx.transferable_inputs)?;
+        add_outputs(&mut summary, &self.base_tx.transferable_outputs)?;
+
+        if let Some(import_tx) = &self.import_tx {
+            add_inputs(&mut summary, &import_tx.transferable_inputs)?;
+        }
+        if let Some(export_tx) = &self.export_tx {
+            add_outputs(&mut summary, &export_tx.transferable_outputs)?;
+        }
+        if let Some(add_validator_tx) = &self.add_validator_tx {
+            add_outputs(&mut summary, &add_validator_tx.stake)?;
+        }
+        if let Some(add_delegator_tx) = &self.add_delegator_tx {
+            add_outputs(&mut summary, &add_delegator_tx.stake)?;
+        }
+
+        summary.finish(_network)
+    }
+}
+
+fn add_inputs(
+    _summary: &mut BalanceSummary,
+    _inputs: &[TransferableInput],
+) -> Result<(), Box<dyn Error>> {
+    for transferable_input in _inputs {
+        let input = &transferable_input.input;
+        if let Some(stakeable_locked_input) = &input.stakeable_locked_input {
+            _summary.add_input(
+                &transferable_input.asset_id,
+                stakeable_locked_input.input.amount,
+            )?;
+        }
+        if let Some(secp256k_transfer_input) = &input.secp256k_transfer_input {
+            _summary.add_input(&transferable_input.asset_id, secp256k_transfer_input.amount)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn add_outputs(
+    _summary: &mut BalanceSummary,
+    _outputs: &[TransferableOutput],
+) -> Result<(), Box<dyn Error>> {
+    for transferable_output in _outputs {
+        let output = &transferable_output.output;
+        let transfer_output = match &output.stakeable_locked_output {
+            Some(stakeable_locked_output) => {
+                stakeable_locked_output.secp256k_transfer_output.as_ref()
+            }
+            None => output.secp256k1_transfer_output.as_ref(),
+        };
+        if let Some(amount) = transfer_output.and_then(|transfer_output| transfer_output.amount) {
+            _summary.add_output(&transferable_output.asset_id, amount)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pvm::parser::fixtures::create_subnet_block;
+    use std::collections::BTreeMap;
+
+    const AVAX_ASSET_ID: &str = "FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z";
+
+    #[test]
+    fn balance_summary_01() {
+        let block = create_subnet_block();
+        let transaction = block.block_data.transactions[0].as_ref().unwrap();
+        assert_eq!(
+            transaction.balance_summary(&Network::mainnet()).unwrap(),
+            BalanceSummary {
+                inputs: BTreeMap::from([(AVAX_ASSET_ID.to_string(), 11_040_000)]),
+                outputs: BTreeMap::from([(AVAX_ASSET_ID.to_string(), 1_040_000)]),
+                burned: BTreeMap::from([(AVAX_ASSET_ID.to_string(), 10_000_000)]),
+                fee: 10_000_000,
+            },
+            "Checking if the inputs , outputs and burned fee are summed per asset"
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use crate::avm::parser::credential_parser::Credential;
+use crate::pvm::parser::atomic_block_parser::Transaction;
+use crate::pvm::parser::base_tx_parser::BaseTx;
+use crate::pvm::parser::export_tx_parser::ExportTx;
+use crate::pvm::parser::import_tx::ImportTx;
+use crate::pvm::parser::output_parser::{Output, SECP256KTransferOutput};
+use crate::pvm::parser::transferable_input_parser::TransferableInput;
+use crate::pvm::parser::transferable_output_parser::TransferableOutput;
+use crate::pvm::syntax::output_bytes;
+use crate::utils::canonical::{
+    sort_addresses, sort_signature_indices, sort_with_credentials, split_credentials, utxo_key,
+};
+use crate::utils::cb58::decode;
+
+impl SECP256KTransferOutput {
+    pub fn sort_canonical(&mut self) {
+        sort_addresses(&mut self.addresses);
+    }
+}
+
+impl Output {
+    pub fn sort_canonical(&mut self) {
+        if let Some(stakeable_locked_output) = self.stakeable_locked_output.as_mut() {
+            if let Some(output) = stakeable_locked_output.secp256k_transfer_output.as_mut() {
+                output.sort_canonical();
+            }
+        }
+        if let Some(output) = self.secp256k1_transfer_output.as_mut() {
+            output.sort_canonical();
+        }
+        if let Some(output) = self.secp256k1_owner_output.as_mut() {
+            output.sort_canonical();
+        }
+    }
+}
+
+impl BaseTx {
+    /// Sort outputs , inputs and their addresses and signature indices the way AvalancheGo expects .
+    /// `_credentials` are the credentials of the inputs , in the same order .
+    pub fn sort_canonical(&mut self, _credentials: &mut [Credential]) {
+        sort_outputs(&mut self.transferable_outputs);
+        sort_inputs(&mut self.transferable_inputs, _credentials);
+    }
+}
+
+impl ImportTx {
+    /// `_credentials` are the credentials of the imported inputs , in the same order .
+    pub fn sort_canonical(&mut self, _credentials: &mut [Credential]) {
+        sort_inputs(&mut self.transferable_inputs, _credentials);
+    }
+}
+
+impl ExportTx {
+    pub fn sort_canonical(&mut self) {
+        sort_outputs(&mut self.transferable_outputs);
+    }
+}
+
+impl Transaction {
+    /// Sort the tx and its credentials , a tx built in any order comes out valid .
+    /// The tx id and the unsigned bytes are not updated .
+    pub fn sort_canonical(&mut self) {
+        let (input_credentials, imported_credentials) = split_credentials(
+            &mut self.credentials,
+            self.base_tx.transferable_inputs.len(),
+        );
+        self.base_tx.sort_canonical(input_credentials);
+
+        if let Some(import_tx) = self.import_tx.as_mut() {
+            import_tx.sort_canonical(imported_credentials);
+        }
+        if let Some(export_tx) = self.export_tx.as_mut() {
+            export_tx.sort_canonical();
+        }
+        if let Some(add_validator_tx) = self.add_validator_tx.as_mut() {
+            sort_outputs(&mut add_validator_tx.stake);
+            add_validator_tx.reward_owner.sort_canonical();
+        }
+        if let Some(add_delegator_tx) = self.add_delegator_tx.as_mut() {
+            sort_outputs(&mut add_delegator_tx.stake);
+            add_delegator_tx.reward_owner.sort_canonical();
+        }
+        if let Some(create_subnet_tx) = self.create_subnet_tx.as_mut() {
+            create_subnet_tx.reward_owner.sort_canonical();
+        }
+    }
+}
+
+fn sort_outputs(_outputs: &mut [TransferableOutput]) {
+    for transferable_output in _outputs.iter_mut() {
+        transferable_output.output.sort_canonical();
+    }
+
+    let keys: Vec<Vec<u8>> = _outputs
+        .iter()
+        .map(|transferable_output| {
+            let mut key = decode(&transferable_output.asset_id).unwrap_or_default();
+            key.extend(output_bytes(&transferable_output.output));
+            key
+        })
+        .collect();
+    sort_with_credentials(_outputs, &keys, &mut []);
+}
+
+fn sort_inputs(_inputs: &mut [TransferableInput], _credentials: &mut [Credential]) {
+    for (index, transferable_input) in _inputs.iter_mut().enumerate() {
+        let input = &mut transferable_input.input;
+        let address_indices =
+            if let Some(stakeable_locked_input) = input.stakeable_locked_input.as_mut() {
+                &mut stakeable_locked_input.input.address_indices
+            } else if let Some(secp256k_transfer_input) = input.secp256k_transfer_input.as_mut() {
+                &mut secp256k_transfer_input.address_indices
+            } else {
+                continue;
+            };
+        sort_signature_indices(address_indices, _credentials.get_mut(index));
+    }
+
+    let keys: Vec<Vec<u8>> = _inputs
+        .iter()
+        .map(|input| utxo_key(&input.tx_id, input.utxo_index))
+        .collect();
+    sort_with_credentials(_inputs, &keys, _credentials);
+}
@@ -0,0 +1,148 @@
+use chrono::{DateTime, TimeZone, Utc};
+
+use std::error::Error;
+
+use crate::pvm::parser::block_parser::{
+    Block, ABORT_BLOCK_TYPE_ID, COMMIT_BLOCK_TYPE_ID, PROPOSAL_BLOCK_TYPE_ID,
+};
+
+/// The chain time a block and its txs were executed at .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BlockTime {
+    pub block_id: String,
+    pub height: i64,
+    pub chain_time: DateTime<Utc>,
+    pub tx_ids: Vec<String>,
+}
+
+/// Follow the chain time of the P-chain over parsed blocks , given in height order .
+/// Apricot blocks move it only through committed advance time txs , Banff blocks carry it .
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChainTimeTracker {
+    /// The unix time of the last block applied
+    pub chain_time: i64,
+    /// The proposal block advancing the time , with the time proposed
+    pub proposal: Option<(String, i64)>,
+}
+
+impl ChainTimeTracker {
+    /// `_genesis_time` is the unix time of the genesis , the chain time before the first advance time tx .
+    pub fn new(_genesis_time: i64) -> ChainTimeTracker {
+        ChainTimeTracker {
+            chain_time: _genesis_time,
+            proposal: None,
+        }
+    }
+
+    pub fn apply_block(&mut self, _block: &Block) -> Result<BlockTime, Box<dyn Error>> {
+        let block_data = &_block.block_data;
+
+        if let Some(timestamp) = block_data.timestamp {
+            self.chain_time = timestamp;
+        }
+
+        match block_data.type_id {
+            PROPOSAL_BLOCK_TYPE_ID => {
+                // The proposed time only applies once the proposal is committed
+                self.proposal = block_data
+                    .transactions
+                    .iter()
+                    .flatten()
+                    .filter_map(|transaction| transaction.advance_time_tx.as_ref())
+                    .map(|advance_time_tx| {
+                        (block_data.block_id.clone(), advance_time_tx.time_proposal)
+                    })
+                    .next();
+            }
+            COMMIT_BLOCK_TYPE_ID | ABORT_BLOCK_TYPE_ID => {
+                if let Some((proposal_block_id, time_proposal)) = self.proposal.take() {
+                    if proposal_block_id == block_data.parent_block_id
+                        && block_data.type_id == COMMIT_BLOCK_TYPE_ID
+                        && block_data.timestamp.is_none()
+                    {
+                        self.chain_time = time_proposal;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        Ok(BlockTime {
+            block_id: block_data.block_id.clone(),
+            height: block_data.height,
+            chain_time: datetime(self.chain_time)?,
+            tx_ids: block_data
+                .transactions
+                .iter()
+                .flatten()
+                .map(|transaction| transaction.tx_id.clone())
+                .collect(),
+        })
+    }
+}
+
+/// Turn a unix time as found in txs , e.g. the start time of a validator , into a UTC datetime .
+pub fn datetime(_unix_time: i64) -> Result<DateTime<Utc>, Box<dyn Error>> {
+    Utc.timestamp_opt(_unix_time, 0)
+        .single()
+        .ok_or_else(|| format!("Invalid unix time {}", _unix_time).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pvm::parser::fixtures::{
+        advance_time_proposal_block, banff_commit_block, option_block,
+    };
+
+    #[test]
+    fn apply_block_01() {
+        let proposal = advance_time_proposal_block();
+        let commit = option_block(COMMIT_BLOCK_TYPE_ID, &proposal);
+
+        let mut tracker = ChainTimeTracker::new(1_599_696_000);
+        assert_eq!(
+            tracker.apply_block(&proposal).unwrap().chain_time,
+            datetime(1_599_696_000).unwrap(),
+            "Checking if the proposal does not move the chain time"
+        );
+        assert_eq!(
+            tracker
+                .apply_block(&commit)
+                .unwrap()
+                .chain_time
+                .to_rfc3339(),
+            "2020-11-30T21:00:43+00:00",
+            "Checking if the commit moves the chain time to the proposed time"
+        );
+    }
+
+    #[test]
+    fn apply_block_02() {
+        let proposal = advance_time_proposal_block();
+        let abort = option_block(ABORT_BLOCK_TYPE_ID, &proposal);
+
+        let mut tracker = ChainTimeTracker::new(1_599_696_000);
+        tracker.apply_block(&proposal).unwrap();
+        assert_eq!(
+            tracker.apply_block(&abort).unwrap().chain_time,
+            datetime(1_599_696_000).unwrap(),
+            "Checking if an aborted advance time does not move the chain time"
+        );
+        assert!(
+            tracker.proposal.is_none(),
+            "Checking if the aborted proposal is dropped"
+        );
+    }
+
+    #[test]
+    fn apply_block_03() {
+        let mut tracker = ChainTimeTracker::new(1_599_696_000);
+        let block_time = tracker.apply_block(&banff_commit_block()).unwrap();
+        assert_eq!(
+            block_time.chain_time.to_rfc3339(),
+            "2022-12-14T06:40:00+00:00",
+            "Checking if a Banff block moves the chain time to its timestamp"
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+
+use crate::pvm::parser::atomic_block_parser::Transaction;
+use crate::pvm::parser::block_parser::{
+    Block, ABORT_BLOCK_TYPE_ID, COMMIT_BLOCK_TYPE_ID, PROPOSAL_BLOCK_TYPE_ID,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Decision {
+    Committed,
+    Aborted,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum ProposalKind {
+    AdvanceTime,
+    RewardValidator,
+    AddValidator,
+    AddDelegator,
+    AddSubnetValidator,
+    Other,
+}
+
+/// The tx of a proposal block .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Proposal {
+    pub block_id: String,
+    pub height: i64,
+    pub tx_id: String,
+    pub kind: ProposalKind,
+    /// The staker tx a reward validator tx is about
+    pub staker_tx_id: Option<String>,
+}
+
+/// A proposal with the commit or abort block deciding it .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DecidedProposal {
+    pub proposal: Proposal,
+    pub decision_block_id: String,
+    pub decision: Decision,
+}
+
+impl DecidedProposal {
+    /// For a reward validator tx , the staker tx and whether the staker got paid .
+    pub fn staker_rewarded(&self) -> Option<(&str, bool)> {
+        self.proposal
+            .staker_tx_id
+            .as_deref()
+            .map(|staker_tx_id| (staker_tx_id, self.decision == Decision::Committed))
+    }
+}
+
+/// Link proposal blocks to the commit or abort block deciding them , by parent id .
+/// Blocks can be given in any order , a decision waits for its proposal and the other way around .
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct DecisionTracker {
+    /// The undecided proposals by block id
+    pub proposals: HashMap<String, Proposal>,
+    /// The decisions whose proposal is not known yet , by proposal block id
+    pub decisions: HashMap<String, (String, Decision)>,
+    pub decided: Vec<DecidedProposal>,
+}
+
+impl DecisionTracker {
+    /// Return the proposal decided thanks to this block , if any .
+    pub fn add_block(&mut self, _block: &Block) -> Option<DecidedProposal> {
+        let block_data = &_block.block_data;
+        match block_data.type_id {
+            PROPOSAL_BLOCK_TYPE_ID => {
+                // Banff proposal blocks put their decision txs before the proposal tx
+                let transaction = block_data.proposal_tx()?;
+                let proposal = Proposal {
+                    block_id: block_data.block_id.clone(),
+                    height: block_data.height,
+                    tx_id: transaction.tx_id.clone(),
+                    kind: proposal_kind(transaction),
+                    staker_tx_id: transaction
+                        .reward_validator_tx
+                        .as_ref()
+                        .map(|reward_validator_tx| reward_validator_tx.tx_id.clone()),
+                };
+                match self.decisions.remove(&block_data.block_id) {
+                    Some((decision_block_id, decision)) => {
+                        Some(self.decide(proposal, decision_block_id, decision))
+                    }
+                    None => {
+                        self.proposals.insert(block_data.block_id.clone(), proposal);
+                        None
+                    }
+                }
+            }
+            ABORT_BLOCK_TYPE_ID | COMMIT_BLOCK_TYPE_ID => {
+                let decision = if block_data.type_id == COMMIT_BLOCK_TYPE_ID {
+                    Decision::Committed
+                } else {
+                    Decision::Aborted
+                };
+                match self.proposals.remove(&block_data.parent_block_id) {
+                    Some(proposal) => {
+                        Some(self.decide(proposal, block_data.block_id.clone(), decision))
+                    }
+                    None => {
+                        self.decisions.insert(
+                            block_data.parent_block_id.clone(),
+                            (block_data.block_id.clone(), decision),
+                        );
+                        None
+                    }
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// The decision of the proposal holding this tx .
+    pub fn decision_of(&self, _tx_id: &str) -> Option<&DecidedProposal> {
+        self.decided
+            .iter()
+            .find(|decided| decided.proposal.tx_id == _tx_id)
+    }
+
+    fn decide(
+        &mut self,
+        _proposal: Proposal,
+        _decision_block_id: String,
+        _decision: Decision,
+    ) -> DecidedProposal {
+        let decided = DecidedProposal {
+            proposal: _proposal,
+            decision_block_id: _decision_block_id,
+            decision: _decision,
+        };
+        self.decided.push(decided.clone());
+
+        decided
+    }
+}
+
+pub fn proposal_kind(_transaction: &Transaction) -> ProposalKind {
+    if _transaction.advance_time_tx.is_some() {
+        ProposalKind::AdvanceTime
+    } else if _transaction.reward_validator_tx.is_some() {
+        ProposalKind::RewardValidator
+    } else if _transaction.add_validator_tx.is_some() {
+        ProposalKind::AddValidator
+    } else if _transaction.add_delegator_tx.is_some() {
+        ProposalKind::AddDelegator
+    } else if _transaction.add_subnet_validator_tx.is_some() {
+        ProposalKind::AddSubnetValidator
+    } else {
+        ProposalKind::Other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pvm::parser::fixtures::{
+        banff_proposal_block, option_block, reward_validator_proposal_block,
+    };
+
+    #[test]
+    fn add_block_01() {
+        let proposal = reward_validator_proposal_block();
+        let staker_tx_id = proposal.block_data.transactions[0]
+            .as_ref()
+            .unwrap()
+            .reward_validator_tx
+            .as_ref()
+            .unwrap()
+            .tx_id
+            .clone();
+
+        let mut tracker = DecisionTracker::default();
+        assert!(
+            tracker
+                .add_block(&option_block(ABORT_BLOCK_TYPE_ID, &proposal))
+                .is_none(),
+            "Checking if the decision waits for its proposal"
+        );
+        let decided = tracker.add_block(&proposal).unwrap();
+        assert_eq!(
+            decided.proposal.kind,
+            ProposalKind::RewardValidator,
+            "Checking if the kind of the proposal is set"
+        );
+        assert_eq!(
+            decided.staker_rewarded(),
+            Some((staker_tx_id.as_str(), false)),
+            "Checking if an aborted reward did not pay the staker"
+        );
+
+        let mut tracker = DecisionTracker::default();
+        tracker.add_block(&proposal);
+        tracker.add_block(&option_block(COMMIT_BLOCK_TYPE_ID, &proposal));
+        assert_eq!(
+            tracker
+                .decision_of(&decided.proposal.tx_id)
+                .unwrap()
+                .staker_rewarded(),
+            Some((staker_tx_id.as_str(), true)),
+            "Checking if a committed reward paid the staker"
+        );
+    }
+    #[test]
+    fn add_block_02() {
+        let proposal = banff_proposal_block();
+        let transactions = &proposal.block_data.transactions;
+        let proposal_tx = transactions[1].as_ref().unwrap();
+
+        let mut tracker = DecisionTracker::default();
+        tracker.add_block(&proposal);
+        let decided = tracker
+            .add_block(&option_block(COMMIT_BLOCK_TYPE_ID, &proposal))
+            .unwrap();
+        assert_eq!(
+            (decided.proposal.tx_id.as_str(), decided.proposal.kind),
+            (proposal_tx.tx_id.as_str(), ProposalKind::RewardValidator),
+            "Checking if the proposal tx follows the decision txs of a Banff proposal block"
+        );
+        assert_eq!(
+            decided.staker_rewarded(),
+            Some((
+                proposal_tx
+                    .reward_validator_tx
+                    .as_ref()
+                    .unwrap()
+                    .tx_id
+                    .as_str(),
+                true
+            )),
+            "Checking if the staker of the reward is set"
+        );
+        assert!(
+            tracker
+                .decision_of(&transactions[0].as_ref().unwrap().tx_id)
+                .is_none(),
+            "Checking if the decision tx is not taken for the proposal"
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use std::error::Error;
+
+use crate::network::Network;
+use crate::pvm::parser::atomic_block_parser::Transaction;
+use crate::utils::fee::{EstimateFee, FeeConfig};
+
+impl EstimateFee for Transaction {
+    /// Advance time and reward validator txs are issued by the validators and pay no fee .
+    fn estimate_fee(&self, _config: &FeeConfig) -> u64 {
+        if self.add_validator_tx.is_some() {
+            _config.add_primary_network_validator_fee
+        } else if self.add_delegator_tx.is_some() {
+            _config.add_primary_network_delegator_fee
+        } else if self.add_subnet_validator_tx.is_some() {
+            _config.add_subnet_validator_fee
+        } else if self.create_subnet_tx.is_some() {
+            _config.create_subnet_tx_fee
+        } else if self.create_blockchain_tx.is_some() {
+            _config.create_blockchain_tx_fee
+        } else if self.advance_time_tx.is_some() || self.reward_validator_tx.is_some() {
+            0
+        } else {
+            _config.tx_fee
+        }
+    }
+
+    fn burned_fee(&self, _network: &Network) -> Result<u64, Box<dyn Error>> {
+        Ok(self.balance_summary(_network)?.fee)
+    }
+}
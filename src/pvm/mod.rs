@@ -1 +1,12 @@
+pub mod balance;
+pub mod canonical;
+pub mod chain_time;
+pub mod decision_tracker;
+pub mod fee;
 pub mod parser;
+pub mod reward;
+pub mod signers;
+pub mod staking;
+pub mod subnet_registry;
+pub mod syntax;
+pub mod validator_set;
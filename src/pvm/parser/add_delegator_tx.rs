@@ -12,15 +12,16 @@ use crate::pvm::parser::output_parser::{
 use crate::pvm::parser::transferable_output_parser::{
     transferable_output_parser, TransferableOutput,
 };
+use crate::utils::amount::Amount;
 use crate::utils::cb58::encode;
-use crate::utils::conversion::{pop_i32, pop_i64};
+use crate::utils::conversion::{pop_i32, pop_i64, pop_u64};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct AddDelegatorTx {
     pub node_id: String,
     pub start_time: i64,
     pub end_time: i64,
-    pub weight: i64,
+    pub weight: Amount,
     pub stake: Vec<TransferableOutput>,
     pub reward_owner: SECP256KTransferOutput,
 }
@@ -28,7 +29,6 @@ pub struct AddDelegatorTx {
 #[instrument(fields(block_id = % _context.tx_id, tx_type = "add_delegator"))]
 pub fn add_delegator_tx_parser(
     _raw_msg: &[u8],
-    _tx_id: String,
     _context: &mut Context,
 ) -> Result<Transaction, Box<dyn Error>> {
     let base_tx = base_tx_parser(_raw_msg, _context)?;
@@ -48,7 +48,9 @@ pub fn add_delegator_tx_parser(
 
     *_context.offset += 8;
 
-    let weight = pop_i64(_raw_msg[*_context.offset..=(*_context.offset + 7)].borrow());
+    let weight = Amount(pop_u64(
+        _raw_msg[*_context.offset..=(*_context.offset + 7)].borrow(),
+    ));
     trace!("Weight : {:?}", weight);
 
     *_context.offset += 8;
@@ -69,10 +71,13 @@ pub fn add_delegator_tx_parser(
         index += 1;
     }
 
-    let rewards_owner = secp256k1_output_owner_output_parser(_raw_msg, _context)?;
+    let type_id = pop_i32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+    trace!("Type Id : {:?}", type_id);
 
     *_context.offset += 4;
 
+    let rewards_owner = secp256k1_output_owner_output_parser(_raw_msg, _context)?;
+
     let add_delegator = AddDelegatorTx {
         node_id,
         start_time,
@@ -84,7 +89,7 @@ pub fn add_delegator_tx_parser(
 
     Ok(Transaction {
         base_tx,
-        tx_id: _tx_id,
+        tx_id: String::new(),
         add_validator_tx: None,
         import_tx: None,
         export_tx: None,
@@ -94,6 +99,7 @@ pub fn add_delegator_tx_parser(
         create_subnet_tx: None,
         advance_time_tx: None,
         reward_validator_tx: None,
+        unsigned_tx: vec![],
         credentials: vec![],
     })
 }
@@ -1,8 +1,10 @@
 use crate::avm::parser::Context;
 use crate::pvm::parser::atomic_block_parser::Transaction;
 use crate::pvm::parser::base_tx_parser::base_tx_parser;
+use crate::pvm::parser::subnet_auth_parser::{subnet_auth_parser, SubnetAuth};
+use crate::utils::amount::Amount;
 use crate::utils::cb58::encode;
-use crate::utils::conversion::{pop_i32, pop_i64};
+use crate::utils::conversion::{pop_i64, pop_u64};
 use rust_base58::ToBase58;
 use std::borrow::Borrow;
 use std::error::Error;
@@ -13,15 +15,14 @@ pub struct AddSubnetValidatorTx {
     pub node_id: String,
     pub start_time: i64,
     pub end_time: i64,
-    pub weight: i64,
+    pub weight: Amount,
     pub subnet_id: String,
-    pub sig_indices: Vec<i32>,
+    pub subnet_auth: SubnetAuth,
 }
 
 #[instrument(fields(block_id = % _context.tx_id, tx_type = "add_subnet_validator"))]
 pub fn add_subnet_validator_tx_parser(
     _raw_msg: &[u8],
-    _tx_id: String,
     _context: &mut Context,
 ) -> Result<Transaction, Box<dyn Error>> {
     let base_tx = base_tx_parser(_raw_msg, _context)?;
@@ -41,7 +42,9 @@ pub fn add_subnet_validator_tx_parser(
 
     *_context.offset += 8;
 
-    let weight = pop_i64(_raw_msg[*_context.offset..=(*_context.offset + 7)].borrow());
+    let weight = Amount(pop_u64(
+        _raw_msg[*_context.offset..=(*_context.offset + 7)].borrow(),
+    ));
     trace!("Weight : {:?}", weight);
 
     *_context.offset += 8;
@@ -51,30 +54,7 @@ pub fn add_subnet_validator_tx_parser(
 
     *_context.offset += 32;
 
-    let subnet_auth_type_id = pop_i32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
-    trace!("Subnet Auth TypeId : {:?}", subnet_auth_type_id);
-
-    *_context.offset += 4;
-
-    let number_of_sig_indices =
-        pop_i32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
-    trace!("Number of sig indices : {:?}", number_of_sig_indices);
-
-    *_context.offset += 4;
-
-    // Outputs
-    let mut sig_indices = Vec::new();
-    let mut index = 0;
-
-    while index < number_of_sig_indices {
-        trace!("Sig Indice number {}", index);
-        sig_indices.push(pop_i32(
-            _raw_msg[*_context.offset..=(*_context.offset + 3)].borrow(),
-        ));
-        *_context.offset += 4;
-
-        index += 1;
-    }
+    let subnet_auth = subnet_auth_parser(_raw_msg, _context)?;
 
     let add_subnet_validator = AddSubnetValidatorTx {
         node_id,
@@ -82,12 +62,12 @@ pub fn add_subnet_validator_tx_parser(
         end_time,
         weight,
         subnet_id,
-        sig_indices,
+        subnet_auth,
     };
 
     Ok(Transaction {
         base_tx,
-        tx_id: _tx_id,
+        tx_id: String::new(),
         add_validator_tx: None,
         import_tx: None,
         export_tx: None,
@@ -97,6 +77,7 @@ pub fn add_subnet_validator_tx_parser(
         create_subnet_tx: None,
         advance_time_tx: None,
         reward_validator_tx: None,
+        unsigned_tx: vec![],
         credentials: vec![],
     })
 }
@@ -15,7 +15,6 @@ pub struct AdvanceTimeTx {
 #[instrument(fields(block_id = % _context.tx_id, tx_type = "advance_time"))]
 pub fn advance_time_tx_parser(
     _raw_msg: &[u8],
-    _tx_id: String,
     _context: &mut Context,
 ) -> Result<Transaction, Box<dyn Error>> {
     let codec_id = pop_i16(_raw_msg[*_context.offset..=(*_context.offset + 1)].borrow());
@@ -43,7 +42,7 @@ pub fn advance_time_tx_parser(
             transferable_inputs: vec![],
             memo: vec![],
         },
-        tx_id: _tx_id,
+        tx_id: String::new(),
         add_validator_tx: None,
         import_tx: None,
         export_tx: None,
@@ -53,6 +52,7 @@ pub fn advance_time_tx_parser(
         create_subnet_tx: None,
         advance_time_tx: Some(advance_time),
         reward_validator_tx: None,
+        unsigned_tx: vec![],
         credentials: vec![],
     })
 }
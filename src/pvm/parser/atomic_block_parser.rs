@@ -34,12 +34,15 @@ pub struct Transaction {
     pub create_subnet_tx: Option<CreateSubnetTx>,
     pub advance_time_tx: Option<AdvanceTimeTx>,
     pub reward_validator_tx: Option<RewardValidatorTx>,
+    /// The codec prefixed unsigned tx , the bytes signed by the credentials
+    pub unsigned_tx: Vec<u8>,
     pub credentials: Vec<Credential>,
 }
 
-#[instrument(fields(block_id = % _context.tx_id, block_type = "atomic_block"))]
+#[instrument(fields(block_id = % _block_id, block_type = "atomic_block"))]
 pub fn atomic_block_parser(
     _raw_msg: &mut Vec<u8>,
+    _block_id: String,
     _context: &mut Context,
 ) -> Result<BlockData, Box<dyn Error>> {
     let parent_block_id = encode(&_raw_msg[*_context.offset..=(*_context.offset + 31)]).to_base58();
@@ -52,11 +55,28 @@ pub fn atomic_block_parser(
 
     *_context.offset += 8;
 
+    let transactions = vec![Some(transaction_parser(_raw_msg, _context)?)];
+
+    Ok(BlockData {
+        type_id: 0,
+        block_id: _block_id,
+        height,
+        parent_block_id,
+        timestamp: None,
+        transactions,
+    })
+}
+
+/// Will parse a tx of a block along with its credentials .
+/// Each tx has its own credentials , the tx id is computed over the tx bytes and the credentials .
+#[instrument(skip(_raw_msg), fields(block_id = % _context.tx_id))]
+pub fn transaction_parser(
+    _raw_msg: &mut Vec<u8>,
+    _context: &mut Context,
+) -> Result<Transaction, Box<dyn Error>> {
     let tx_type_id = pop_i32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
     trace!("Tx typeId : {:?}", tx_type_id);
 
-    let transaction;
-
     // Here to get the correct Tx ID we need to perform a small manipulation onto the _raw_msg .
     // In short in the bytes we get out of the socket , we have :
     // - 2B CODEC
@@ -68,28 +88,25 @@ pub fn atomic_block_parser(
     // BUT we need to have the codec version added in between HEIGHT anf TYPE OF TX .
     _raw_msg.insert(*_context.offset, 0);
     _raw_msg.insert(*_context.offset, 0);
-    let tx_id = generate_id(&_raw_msg[*_context.offset..=(_raw_msg.len() - 1)].to_vec());
-
-    trace!("tx_id : {:?}", tx_id);
+    let tx_start = *_context.offset;
 
-    match tx_type_id {
-        12 => transaction = Some(add_validator_tx_parser(_raw_msg, tx_id, _context)?),
-        13 => transaction = Some(add_subnet_validator_tx_parser(_raw_msg, tx_id, _context)?),
-        14 => transaction = Some(add_delegator_tx_parser(_raw_msg, tx_id, _context)?),
-        15 => transaction = Some(create_blockchain_tx_parser(_raw_msg, tx_id, _context)?),
-        16 => transaction = Some(create_subnet_tx_parser(_raw_msg, tx_id, _context)?),
-        17 => transaction = Some(import_tx_parser(_raw_msg, tx_id, _context)?),
-        18 => transaction = Some(export_tx_parser(_raw_msg, tx_id, _context)?),
-        19 => transaction = Some(advance_time_tx_parser(_raw_msg, tx_id, _context)?),
-        20 => transaction = Some(reward_validator_parser(_raw_msg, tx_id, _context)?),
+    let mut transaction = match tx_type_id {
+        12 => add_validator_tx_parser(_raw_msg, _context)?,
+        13 => add_subnet_validator_tx_parser(_raw_msg, _context)?,
+        14 => add_delegator_tx_parser(_raw_msg, _context)?,
+        15 => create_blockchain_tx_parser(_raw_msg, _context)?,
+        16 => create_subnet_tx_parser(_raw_msg, _context)?,
+        17 => import_tx_parser(_raw_msg, _context)?,
+        18 => export_tx_parser(_raw_msg, _context)?,
+        19 => advance_time_tx_parser(_raw_msg, _context)?,
+        20 => reward_validator_parser(_raw_msg, _context)?,
         _ => panic!(
             "This tx type is incorrect or not yet supported {}",
             tx_type_id
         ),
-    }
+    };
 
-    let mut transactions = Vec::new();
-    transactions.push(transaction);
+    transaction.unsigned_tx = _raw_msg[tx_start..*_context.offset].to_vec();
 
     // Number of credentials
     let number_of_credentials: u32 =
@@ -99,19 +116,15 @@ pub fn atomic_block_parser(
 
     // Credentials
     let mut index = 0;
-    let mut credentials = Vec::new();
     while index < number_of_credentials {
         trace!("Credential number {}", index);
-        let credential = credential_parser(&_raw_msg, _context)?;
-        credentials.push(credential);
+        let credential = credential_parser(_raw_msg, _context)?;
+        transaction.credentials.push(credential);
         index += 1;
     }
 
-    Ok(BlockData {
-        type_id: 0,
-        height,
-        parent_block_id,
-        transactions,
-        credentials,
-    })
+    transaction.tx_id = generate_id(&_raw_msg[tx_start..*_context.offset]);
+    trace!("tx_id : {:?}", transaction.tx_id);
+
+    Ok(transaction)
 }
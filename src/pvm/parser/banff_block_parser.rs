@@ -0,0 +1,58 @@
+use crate::avm::parser::Context;
+use crate::pvm::parser::abort_block_parser::abort_block_parser;
+use crate::pvm::parser::atomic_block_parser::transaction_parser;
+use crate::pvm::parser::block_parser::{
+    BlockData, BANFF_ABORT_BLOCK_TYPE_ID, BANFF_COMMIT_BLOCK_TYPE_ID, BANFF_PROPOSAL_BLOCK_TYPE_ID,
+    BANFF_STANDARD_BLOCK_TYPE_ID,
+};
+use crate::pvm::parser::commit_block_parser::commit_block_parser;
+use crate::pvm::parser::proposal_block_parser::proposal_block_parser;
+use crate::pvm::parser::standard_block_parser::standard_block_parser;
+use crate::utils::conversion::{pop_i32, pop_i64};
+use std::borrow::Borrow;
+use std::error::Error;
+use tracing::{instrument, trace};
+
+/// Banff blocks are the Apricot blocks prefixed with their unix time .
+/// The block data keeps the type id of the Apricot block , so both are decided the same way .
+#[instrument(fields(block_id = % _block_id, block_type = "banff"))]
+pub fn banff_block_parser(
+    _raw_msg: &mut Vec<u8>,
+    _block_id: String,
+    _type_id: i32,
+    _context: &mut Context,
+) -> Result<BlockData, Box<dyn Error>> {
+    let timestamp = pop_i64(_raw_msg[*_context.offset..=(*_context.offset + 7)].borrow());
+    trace!("Timestamp : {:?}", timestamp);
+
+    *_context.offset += 8;
+
+    let mut block_data = match _type_id {
+        BANFF_PROPOSAL_BLOCK_TYPE_ID => {
+            // The decision txs executed before the proposal tx
+            let number_of_tx =
+                pop_i32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+            trace!("Number of Tx : {:?}", number_of_tx);
+
+            *_context.offset += 4;
+
+            let mut transactions = Vec::new();
+            for index in 0..number_of_tx {
+                trace!("Tx number {}", index);
+                transactions.push(Some(transaction_parser(_raw_msg, _context)?));
+            }
+
+            let mut block_data = proposal_block_parser(_raw_msg, _block_id, _context)?;
+            transactions.append(&mut block_data.transactions);
+            block_data.transactions = transactions;
+            block_data
+        }
+        BANFF_ABORT_BLOCK_TYPE_ID => abort_block_parser(_raw_msg, _block_id, _context)?,
+        BANFF_COMMIT_BLOCK_TYPE_ID => commit_block_parser(_raw_msg, _block_id, _context)?,
+        BANFF_STANDARD_BLOCK_TYPE_ID => standard_block_parser(_raw_msg, _block_id, _context)?,
+        _ => return Err(format!("Block type {} is not a Banff block", _type_id).into()),
+    };
+    block_data.timestamp = Some(timestamp);
+
+    Ok(block_data)
+}
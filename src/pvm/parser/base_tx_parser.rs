@@ -83,9 +83,9 @@ pub fn base_tx_parser(_raw_msg: &[u8], _context: &mut Context) -> Result<BaseTx,
     } else {
         trace!(
             "Memo content : {:?}",
-            &_raw_msg[*_context.offset..=(*_context.offset + memo_size)]
+            &_raw_msg[*_context.offset..(*_context.offset + memo_size)]
         );
-        memo = _raw_msg[*_context.offset..=(*_context.offset + memo_size)].to_vec();
+        memo = _raw_msg[*_context.offset..(*_context.offset + memo_size)].to_vec();
         *_context.offset += memo_size;
     }
 
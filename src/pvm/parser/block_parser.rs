@@ -1,19 +1,29 @@
-use crate::avm::parser::credential_parser::Credential;
 use crate::avm::parser::Context;
 use crate::pvm::parser::abort_block_parser::abort_block_parser;
 use crate::pvm::parser::atomic_block_parser::{atomic_block_parser, Transaction};
+use crate::pvm::parser::banff_block_parser::banff_block_parser;
 use crate::pvm::parser::commit_block_parser::commit_block_parser;
 use crate::pvm::parser::proposal_block_parser::proposal_block_parser;
 use crate::pvm::parser::standard_block_parser::standard_block_parser;
 use crate::utils::conversion::{pop_i16, pop_i32};
+use crate::utils::misc::generate_id;
 use std::borrow::Borrow;
 use std::error::Error;
-use tracing::{instrument, trace};
+use tracing::{instrument, trace, Span};
+
+pub const PROPOSAL_BLOCK_TYPE_ID: i32 = 0;
+pub const ABORT_BLOCK_TYPE_ID: i32 = 1;
+pub const COMMIT_BLOCK_TYPE_ID: i32 = 2;
+pub const STANDARD_BLOCK_TYPE_ID: i32 = 3;
+pub const ATOMIC_BLOCK_TYPE_ID: i32 = 4;
+pub const BANFF_PROPOSAL_BLOCK_TYPE_ID: i32 = 29;
+pub const BANFF_ABORT_BLOCK_TYPE_ID: i32 = 30;
+pub const BANFF_COMMIT_BLOCK_TYPE_ID: i32 = 31;
+pub const BANFF_STANDARD_BLOCK_TYPE_ID: i32 = 32;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Block {
     pub codec_id: i16,
-    pub unsigned_tx_offset: usize,
     pub type_id: i32,
     pub block_data: BlockData,
 }
@@ -22,9 +32,33 @@ pub struct Block {
 pub struct BlockData {
     pub type_id: i32,
     pub height: i64,
+    /// The sha256 of the block bytes , as shown by explorers
+    pub block_id: String,
     pub parent_block_id: String,
+    /// The unix time of Banff blocks , Apricot blocks have none and follow the advance time txs
+    pub timestamp: Option<i64>,
     pub transactions: Vec<Option<Transaction>>,
-    pub credentials: Vec<Credential>,
+}
+
+impl BlockData {
+    /// The tx a proposal block asks to commit or abort ,
+    /// the last one since Banff proposal blocks put their decision txs first .
+    pub fn proposal_tx(&self) -> Option<&Transaction> {
+        if self.type_id != PROPOSAL_BLOCK_TYPE_ID {
+            return None;
+        }
+        self.transactions.last()?.as_ref()
+    }
+
+    /// The txs applied as soon as the block is accepted , every tx but the proposal tx .
+    pub fn decision_txs(&self) -> impl Iterator<Item = &Transaction> {
+        let count = if self.type_id == PROPOSAL_BLOCK_TYPE_ID {
+            self.transactions.len().saturating_sub(1)
+        } else {
+            self.transactions.len()
+        };
+        self.transactions[..count].iter().flatten()
+    }
 }
 
 #[instrument(fields(block_id = % _context.tx_id))]
@@ -32,6 +66,11 @@ pub fn block_parser(
     _raw_msg: &mut Vec<u8>,
     _context: &mut Context,
 ) -> Result<Block, Box<dyn Error>> {
+    // The block id has to be computed before any block parser modifies the bytes
+    let block_id = generate_id(&_raw_msg[*_context.offset..]);
+    Span::current().record("block_id", block_id.as_str());
+    trace!("Block id : {:?}", block_id);
+
     let codec_id = pop_i16(_raw_msg[*_context.offset..=(*_context.offset + 1)].borrow());
     trace!("Codec_id : {:?}", codec_id);
     *_context.offset += 2;
@@ -46,11 +85,22 @@ pub fn block_parser(
     let block_data;
 
     match type_id {
-        0 => block_data = Some(proposal_block_parser(_raw_msg, _context)?),
-        1 => block_data = Some(abort_block_parser(_raw_msg, _context)?),
-        2 => block_data = Some(commit_block_parser(_raw_msg, _context)?),
-        3 => block_data = Some(standard_block_parser(_raw_msg, _context)?),
-        4 => block_data = Some(atomic_block_parser(_raw_msg, _context)?),
+        PROPOSAL_BLOCK_TYPE_ID => {
+            block_data = Some(proposal_block_parser(_raw_msg, block_id, _context)?)
+        }
+        ABORT_BLOCK_TYPE_ID => block_data = Some(abort_block_parser(_raw_msg, block_id, _context)?),
+        COMMIT_BLOCK_TYPE_ID => {
+            block_data = Some(commit_block_parser(_raw_msg, block_id, _context)?)
+        }
+        STANDARD_BLOCK_TYPE_ID => {
+            block_data = Some(standard_block_parser(_raw_msg, block_id, _context)?)
+        }
+        ATOMIC_BLOCK_TYPE_ID => {
+            block_data = Some(atomic_block_parser(_raw_msg, block_id, _context)?)
+        }
+        BANFF_PROPOSAL_BLOCK_TYPE_ID..=BANFF_STANDARD_BLOCK_TYPE_ID => {
+            block_data = Some(banff_block_parser(_raw_msg, block_id, type_id, _context)?)
+        }
         _ => {
             panic!(
                 "This block type is incorrect or not yet supported {}",
@@ -65,7 +115,6 @@ pub fn block_parser(
 
     Ok(Block {
         codec_id,
-        unsigned_tx_offset: 0,
         type_id,
         block_data: block_data.unwrap(),
     })
@@ -74,6 +123,7 @@ pub fn block_parser(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::pvm::parser::subnet_auth_parser::SubnetAuth;
     use std::time::SystemTime;
 
     #[test]
@@ -101,7 +151,11 @@ mod tests {
             "Checking if tx_id is correctly set"
         );
         assert_eq!(
-            block.block_data.credentials.len(),
+            block.block_data.transactions[0]
+                .as_ref()
+                .unwrap()
+                .credentials
+                .len(),
             0,
             "Checking if tx_id is correctly set"
         );
@@ -171,10 +225,25 @@ mod tests {
             "Checking if tx_id is correctly set"
         );
         assert_eq!(
-            block.block_data.credentials.len(),
+            block.block_data.transactions[0]
+                .as_ref()
+                .unwrap()
+                .credentials
+                .len(),
             1,
             "Checking if tx_id is correctly set"
         );
+        let tx = block.block_data.transactions[0].as_ref().unwrap();
+        assert_eq!(
+            tx.add_delegator_tx.as_ref().unwrap().reward_owner.threshold,
+            1,
+            "Checking if reward owner is correctly set"
+        );
+        assert_eq!(
+            tx.verify_syntax(),
+            vec![],
+            "Checking the tx is syntactically valid"
+        );
         // assert_eq!(
         //     block.block_data.credentials.get(0).unwrap().type_id, 1,
         //     "Checking if tx_id is correctly set"
@@ -244,7 +313,11 @@ mod tests {
             "Checking if tx_id is correctly set"
         );
         assert_eq!(
-            block.block_data.credentials.len(),
+            block.block_data.transactions[0]
+                .as_ref()
+                .unwrap()
+                .credentials
+                .len(),
             2,
             "Checking if tx_id is correctly set"
         );
@@ -409,7 +482,11 @@ mod tests {
             "Checking if tx_id is correctly set"
         );
         assert_eq!(
-            block.block_data.credentials.len(),
+            block.block_data.transactions[0]
+                .as_ref()
+                .unwrap()
+                .credentials
+                .len(),
             11,
             "Checking if tx_id is correctly set"
         );
@@ -459,7 +536,11 @@ mod tests {
             "Checking if tx_id is correctly set"
         );
         assert_eq!(
-            block.block_data.credentials.len(),
+            block.block_data.transactions[0]
+                .as_ref()
+                .unwrap()
+                .credentials
+                .len(),
             0,
             "Checking if tx_id is correctly set"
         );
@@ -500,6 +581,10 @@ mod tests {
             },
         )
         .unwrap();
+        assert_eq!(
+            block.block_data.block_id, "2vn7CEosNRj8dxgqQ7m9TufrCR3JkdMdgQ9mNVhhNaB2HXGE8e",
+            "Checking if block id is correctly set"
+        );
         assert_eq!(block.codec_id, 0, "Checking if tx_id is correctly set");
         assert_eq!(block.type_id, 1, "Checking if tx_id is correctly set");
         assert_eq!(
@@ -507,7 +592,7 @@ mod tests {
             "Checking if tx_id is correctly set"
         );
         assert_eq!(
-            block.block_data.credentials.len(),
+            block.block_data.transactions.len(),
             0,
             "Checking if tx_id is correctly set"
         );
@@ -555,7 +640,7 @@ mod tests {
             "Checking if tx_id is correctly set"
         );
         assert_eq!(
-            block.block_data.credentials.len(),
+            block.block_data.transactions.len(),
             0,
             "Checking if tx_id is correctly set"
         );
@@ -578,6 +663,99 @@ mod tests {
         // );
     }
 
+    #[test]
+    fn decode_banff_proposal_block_01() {
+        // The block of `decode_proposal_block_reward_validator_tx_01` , as a Banff block
+        let mut raw_bytes: Vec<u8> = Vec::from([
+            0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 99, 153, 111, 192, 0, 0, 0, 0, 211, 215, 13, 44, 243,
+            156, 224, 21, 70, 67, 97, 105, 201, 42, 36, 159, 196, 165, 194, 165, 46, 71, 82, 246,
+            238, 220, 112, 29, 78, 63, 105, 146, 0, 0, 0, 3, 80, 44, 0, 0, 0, 0, 0, 20, 255, 228,
+            100, 108, 37, 204, 149, 81, 14, 26, 134, 2, 175, 246, 28, 105, 100, 253, 38, 107, 2,
+            231, 12, 19, 155, 199, 166, 202, 48, 106, 81, 110, 0, 0, 0, 0,
+        ]);
+        let block = block_parser(
+            &mut raw_bytes,
+            &mut Context {
+                tx_id: "a_tx",
+                uuid: Default::default(),
+                offset: &mut 0,
+                parsing_started: SystemTime::now(),
+                network_name: "avax".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            block.type_id, BANFF_PROPOSAL_BLOCK_TYPE_ID,
+            "Checking if the type of the block is correctly set"
+        );
+        assert_eq!(
+            block.block_data.type_id, PROPOSAL_BLOCK_TYPE_ID,
+            "Checking if the block is decided as an Apricot proposal block"
+        );
+        assert_eq!(
+            block.block_data.timestamp,
+            Some(1_671_000_000),
+            "Checking if the timestamp is correctly set"
+        );
+        assert!(
+            block.block_data.transactions.len() == 1
+                && block.block_data.transactions[0]
+                    .as_ref()
+                    .unwrap()
+                    .reward_validator_tx
+                    .is_some(),
+            "Checking if the proposal tx follows the empty list of decision txs"
+        );
+    }
+
+    #[test]
+    fn decode_banff_proposal_block_02() {
+        let block = crate::pvm::parser::fixtures::banff_proposal_block();
+        assert_eq!(
+            block.block_data.timestamp,
+            Some(1_604_000_000),
+            "Checking if the timestamp is correctly set"
+        );
+        assert_eq!(
+            block
+                .block_data
+                .decision_txs()
+                .map(|transaction| transaction.add_validator_tx.is_some())
+                .collect::<Vec<_>>(),
+            vec![true],
+            "Checking if the decision txs come first"
+        );
+        assert!(
+            block
+                .block_data
+                .proposal_tx()
+                .is_some_and(|transaction| transaction.reward_validator_tx.is_some()),
+            "Checking if the proposal tx is the last tx"
+        );
+    }
+
+    #[test]
+    fn decode_banff_commit_block_01() {
+        let block = crate::pvm::parser::fixtures::banff_commit_block();
+        assert_eq!(
+            block.type_id, BANFF_COMMIT_BLOCK_TYPE_ID,
+            "Checking if the type of the block is correctly set"
+        );
+        assert_eq!(
+            block.block_data.type_id, COMMIT_BLOCK_TYPE_ID,
+            "Checking if the block is decided as an Apricot commit block"
+        );
+        assert_eq!(
+            block.block_data.timestamp,
+            Some(1_671_000_000),
+            "Checking if the timestamp is correctly set"
+        );
+        assert_eq!(
+            block.block_data.height, 208_044,
+            "Checking if the height is correctly set"
+        );
+    }
+
     #[test]
     fn decode_standard_block_create_subnet_tx_01() {
         let mut raw_bytes: Vec<u8> = Vec::from([
@@ -627,7 +805,11 @@ mod tests {
             "Checking if tx_id is correctly set"
         );
         assert_eq!(
-            block.block_data.credentials.len(),
+            block.block_data.transactions[0]
+                .as_ref()
+                .unwrap()
+                .credentials
+                .len(),
             2,
             "Checking if tx_id is correctly set"
         );
@@ -649,6 +831,61 @@ mod tests {
         //     "Checking if tx_id is correctly set"
         // );
     }
+
+    #[test]
+    fn decode_standard_block_create_blockchain_tx_01() {
+        let block = crate::pvm::parser::fixtures::c_chain_genesis_block();
+        let transaction = block.block_data.transactions[0].as_ref().unwrap();
+        assert_eq!(
+            transaction.tx_id, "2q9e4r6Mu3U68nU1fYjgbR6JvwrRx36CohpAX5UQxse55x1Q5",
+            "Checking if tx_id is the id of the C-Chain"
+        );
+        let create_blockchain_tx = transaction.create_blockchain_tx.as_ref().unwrap();
+        assert_eq!(
+            create_blockchain_tx.subnet_id, "11111111111111111111111111111111LpoYY",
+            "Checking if subnet_id is correctly set"
+        );
+        assert_eq!(
+            create_blockchain_tx.chain_name, "C-Chain",
+            "Checking if chain_name is decoded as text"
+        );
+        assert_eq!(
+            create_blockchain_tx.vm_id, "mgj786NP7uDwBCcq6YwThhaN8FLyybkCa4zBWTQbNgmK6k9A6",
+            "Checking if vm_id is correctly set"
+        );
+        assert_eq!(
+            create_blockchain_tx.fx_ids,
+            Vec::<String>::new(),
+            "Checking if fx_ids are correctly set"
+        );
+        assert_eq!(
+            create_blockchain_tx.genesis_data.len(),
+            1_352,
+            "Checking if genesis_data is read without its size"
+        );
+        assert!(
+            create_blockchain_tx
+                .genesis_data
+                .starts_with(br#"{"config":{"chainId":43114,"#)
+                && create_blockchain_tx
+                    .genesis_data
+                    .ends_with(br#""parentHash":"0x0000000000000000000000000000000000000000000000000000000000000000"}"#),
+            "Checking if genesis_data is the whole C-Chain genesis"
+        );
+        assert_eq!(
+            create_blockchain_tx.subnet_auth,
+            SubnetAuth {
+                type_id: 10,
+                sig_indices: vec![],
+            },
+            "Checking if subnet_auth is correctly set"
+        );
+        assert!(
+            transaction.credentials.is_empty(),
+            "Checking if the genesis tx has no credentials"
+        );
+    }
+
     #[test]
     fn decode_standard_block_01() {
         let mut raw_bytes: Vec<u8> = Vec::from([
@@ -712,7 +949,11 @@ mod tests {
             "Checking if tx_id is correctly set"
         );
         assert_eq!(
-            block.block_data.credentials.len(),
+            block.block_data.transactions[0]
+                .as_ref()
+                .unwrap()
+                .credentials
+                .len(),
             2,
             "Checking if tx_id is correctly set"
         );
@@ -735,6 +976,126 @@ mod tests {
         // );
     }
 
+    #[test]
+    fn decode_standard_block_two_txs_01() {
+        // The txs of `decode_standard_block_create_subnet_tx_01` and `decode_standard_block_01`
+        let mut raw_bytes: Vec<u8> = Vec::from([
+            0, 0, 0, 0, 0, 3, 135, 136, 121, 63, 185, 169, 36, 253, 59, 122, 131, 56, 255, 62, 143,
+            109, 140, 186, 162, 180, 54, 245, 14, 113, 169, 77, 209, 16, 136, 240, 115, 208, 0, 0,
+            0, 0, 0, 0, 23, 94, 0, 0, 0, 2, 0, 0, 0, 16, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 33, 230,
+            115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185,
+            214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 7, 0, 0, 0, 0, 0, 15, 222,
+            128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 138, 19, 176, 23, 28, 117, 3, 117,
+            99, 58, 242, 121, 4, 183, 72, 88, 95, 215, 126, 126, 0, 0, 0, 2, 28, 243, 243, 194, 77,
+            23, 95, 92, 212, 227, 148, 200, 117, 101, 1, 13, 41, 195, 54, 55, 115, 64, 227, 253,
+            23, 145, 246, 71, 36, 196, 24, 207, 0, 0, 0, 0, 33, 230, 115, 23, 203, 196, 190, 42,
+            235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178,
+            48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0, 0, 0, 0, 137, 240, 128, 0, 0, 0, 1, 0, 0, 0,
+            0, 80, 4, 198, 252, 185, 71, 102, 147, 234, 153, 21, 35, 102, 130, 43, 103, 117, 56,
+            105, 140, 45, 74, 75, 6, 41, 223, 226, 193, 243, 127, 67, 71, 0, 0, 0, 0, 33, 230, 115,
+            23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214,
+            5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0, 0, 0, 0, 30, 132, 128,
+            0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0,
+            0, 0, 2, 79, 56, 181, 252, 149, 85, 157, 235, 182, 127, 118, 41, 1, 171, 96, 207, 100,
+            204, 39, 217, 138, 19, 176, 23, 28, 117, 3, 117, 99, 58, 242, 121, 4, 183, 72, 88, 95,
+            215, 126, 126, 0, 0, 0, 2, 0, 0, 0, 9, 0, 0, 0, 1, 133, 188, 205, 47, 36, 150, 109, 85,
+            40, 53, 184, 103, 207, 214, 19, 42, 64, 90, 245, 42, 42, 62, 15, 214, 81, 12, 76, 24,
+            127, 93, 232, 185, 98, 178, 14, 192, 75, 213, 188, 14, 171, 255, 135, 86, 83, 213, 215,
+            2, 157, 208, 32, 178, 100, 35, 99, 180, 193, 64, 1, 187, 160, 97, 85, 224, 1, 0, 0, 0,
+            9, 0, 0, 0, 1, 231, 140, 52, 143, 43, 177, 9, 139, 82, 198, 100, 161, 242, 194, 88, 49,
+            229, 141, 15, 158, 64, 187, 247, 49, 92, 147, 239, 68, 253, 123, 8, 51, 37, 214, 56,
+            223, 216, 120, 216, 53, 167, 207, 32, 133, 5, 15, 108, 90, 183, 103, 192, 69, 185, 255,
+            215, 142, 64, 235, 79, 5, 246, 219, 71, 122, 0, 0, 0, 0, 15, 0, 0, 0, 1, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 1, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168,
+            245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 7, 0, 0,
+            0, 0, 63, 229, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 248, 155, 99,
+            131, 134, 149, 164, 94, 120, 2, 191, 142, 228, 107, 208, 246, 163, 170, 54, 76, 0, 0,
+            0, 1, 75, 96, 22, 214, 90, 20, 205, 237, 87, 81, 2, 16, 33, 213, 118, 34, 47, 159, 102,
+            250, 205, 158, 152, 77, 202, 240, 184, 163, 107, 128, 6, 83, 0, 0, 0, 0, 33, 230, 115,
+            23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214,
+            5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0, 0, 0, 64, 126, 2, 128,
+            0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 191, 187, 134, 7, 68, 228, 181, 130, 70, 14, 134,
+            129, 238, 242, 188, 167, 94, 169, 125, 12, 91, 59, 111, 47, 173, 76, 197, 202, 148,
+            111, 158, 138, 0, 32, 89, 79, 85, 82, 32, 68, 79, 67, 83, 32, 83, 85, 67, 75, 83, 32,
+            78, 79, 32, 65, 78, 89, 32, 68, 69, 86, 32, 72, 69, 76, 80, 83, 97, 118, 109, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1, 115, 101, 99, 112, 50, 53, 54, 107, 49, 102, 120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 16, 0, 0, 0, 0, 0, 2, 0, 3, 97, 115, 49, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 77, 65, 75, 79, 73, 78, 0, 3,
+            77, 75, 78, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 7, 0, 0, 0, 0, 0, 1, 134,
+            160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 187, 56, 223, 22, 165, 212, 109,
+            141, 110, 188, 134, 109, 43, 16, 189, 122, 64, 45, 180, 232, 0, 3, 97, 115, 50, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 77, 65, 75, 79, 73, 78, 0, 3,
+            77, 75, 78, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1, 0, 0, 0, 2, 23, 44, 99, 213, 251, 43, 40, 77, 135, 204, 216, 194, 176, 242,
+            0, 58, 223, 220, 230, 182, 206, 188, 85, 12, 62, 145, 44, 54, 239, 240, 30, 5, 60, 48,
+            108, 121, 17, 77, 193, 74, 0, 0, 0, 10, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2,
+            0, 0, 0, 9, 0, 0, 0, 1, 195, 238, 10, 225, 34, 178, 226, 153, 73, 28, 148, 141, 26,
+            215, 159, 251, 175, 222, 95, 232, 114, 48, 174, 253, 122, 165, 189, 133, 121, 195, 65,
+            113, 22, 240, 19, 147, 216, 39, 92, 74, 67, 48, 240, 97, 197, 211, 120, 185, 110, 142,
+            218, 210, 122, 249, 151, 227, 96, 169, 144, 246, 76, 42, 88, 143, 1, 0, 0, 0, 9, 0, 0,
+            0, 2, 128, 255, 102, 200, 89, 58, 13, 184, 183, 217, 84, 55, 15, 29, 233, 214, 122,
+            194, 88, 153, 159, 245, 17, 110, 56, 94, 97, 94, 95, 147, 31, 106, 97, 251, 22, 81,
+            203, 178, 218, 215, 219, 187, 60, 139, 130, 78, 130, 133, 159, 111, 115, 210, 62, 109,
+            169, 252, 177, 110, 63, 159, 246, 114, 191, 220, 1, 37, 195, 163, 173, 166, 214, 236,
+            109, 224, 250, 212, 140, 78, 202, 120, 204, 175, 81, 225, 137, 44, 86, 242, 1, 161,
+            209, 71, 51, 155, 227, 248, 211, 10, 117, 12, 60, 45, 39, 102, 72, 245, 83, 17, 24,
+            193, 219, 125, 146, 62, 158, 186, 211, 11, 138, 125, 96, 73, 236, 159, 95, 181, 121,
+            136, 25, 1,
+        ]);
+        let block = block_parser(
+            &mut raw_bytes,
+            &mut Context {
+                tx_id: "a_tx",
+                uuid: Default::default(),
+                offset: &mut 0,
+                parsing_started: SystemTime::now(),
+                network_name: "avax".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            block.block_data.block_id, "esRaFixuwXQFNfyJqqYxyTSpeRUghwTWicvAwDPT71uEVnYNM",
+            "Checking if block id is correctly set"
+        );
+        assert_eq!(
+            block.block_data.transactions.len(),
+            2,
+            "Checking if transactions number is correctly set"
+        );
+
+        let first_tx = block.block_data.transactions[0].as_ref().unwrap();
+        assert_eq!(
+            first_tx.tx_id, "UC4CosZv99LqYRYSCCu9yX5NCGoLjVE2aP6pSwNVsiX48Lxhc",
+            "Checking if first tx id only covers the first tx"
+        );
+        assert_eq!(
+            first_tx.credentials.len(),
+            2,
+            "Checking if first tx credentials are correctly set"
+        );
+        assert_eq!(
+            first_tx.unsigned_tx[..6],
+            [0, 0, 0, 0, 0, 16],
+            "Checking if first unsigned tx starts with the codec and the tx type"
+        );
+
+        let second_tx = block.block_data.transactions[1].as_ref().unwrap();
+        assert_eq!(
+            second_tx.tx_id, "25i636VUB5wvghNqumGRPGawyK5MSpmVTQiyy5s8SY9ya4CtRR",
+            "Checking if second tx id only covers the second tx"
+        );
+        assert_eq!(
+            second_tx.credentials.len(),
+            2,
+            "Checking if second tx credentials are correctly set"
+        );
+    }
+
     #[test]
     fn decode_atomic_bloc_advance_time_tx_01() {
         let mut raw_bytes: Vec<u8> = Vec::from([
@@ -2910,20 +3271,31 @@ mod tests {
             "Checking if block.block_data type id is correctly set"
         );
         assert_eq!(
-            block.block_data.credentials.len(),
+            block.block_data.transactions[0]
+                .as_ref()
+                .unwrap()
+                .credentials
+                .len(),
             1,
             "Checking if block.block_data credentials size is correctly set"
         );
         assert_eq!(
-            block.block_data.credentials.get(0).unwrap().type_id,
+            block.block_data.transactions[0]
+                .as_ref()
+                .unwrap()
+                .credentials
+                .first()
+                .unwrap()
+                .type_id,
             9,
             "Checking if block.block_data credential 0 type id is correctly set"
         );
         assert_eq!(
-            block
-                .block_data
+            block.block_data.transactions[0]
+                .as_ref()
+                .unwrap()
                 .credentials
-                .get(0)
+                .first()
                 .unwrap()
                 .signatures
                 .len(),
@@ -2931,13 +3303,14 @@ mod tests {
             "Checking if  block.block_data credential 0 bytes are correctly set"
         );
         assert_eq!(
-            block
-                .block_data
+            block.block_data.transactions[0]
+                .as_ref()
+                .unwrap()
                 .credentials
-                .get(0)
+                .first()
                 .unwrap()
                 .signatures
-                .get(0)
+                .first()
                 .unwrap(),
             &Vec::<u8>::from([
                 209, 53, 196, 47, 15, 30, 223, 73, 206, 6, 118, 193, 86, 206, 166, 209, 130, 239,
@@ -2999,6 +3372,10 @@ mod tests {
             },
         )
         .unwrap();
+        assert_eq!(
+            block.block_data.block_id, "2PpHLb6QA8grZaGP9nmQmADjXBetSYyjPRmR9eqoSfo4yinxq",
+            "Checking if block id is computed on the original bytes"
+        );
         assert_eq!(
             block.codec_id, 0,
             "Checking if block codec id is correctly set"
@@ -3012,20 +3389,31 @@ mod tests {
             "Checking if block.block_data type id is correctly set"
         );
         assert_eq!(
-            block.block_data.credentials.len(),
+            block.block_data.transactions[0]
+                .as_ref()
+                .unwrap()
+                .credentials
+                .len(),
             1,
             "Checking if block.block_data credentials size is correctly set"
         );
         assert_eq!(
-            block.block_data.credentials.get(0).unwrap().type_id,
+            block.block_data.transactions[0]
+                .as_ref()
+                .unwrap()
+                .credentials
+                .first()
+                .unwrap()
+                .type_id,
             9,
             "Checking if block.block_data credential 0 type id is correctly set"
         );
         assert_eq!(
-            block
-                .block_data
+            block.block_data.transactions[0]
+                .as_ref()
+                .unwrap()
                 .credentials
-                .get(0)
+                .first()
                 .unwrap()
                 .signatures
                 .len(),
@@ -3033,13 +3421,14 @@ mod tests {
             "Checking if  block.block_data credential 0 bytes are correctly set"
         );
         assert_eq!(
-            block
-                .block_data
+            block.block_data.transactions[0]
+                .as_ref()
+                .unwrap()
                 .credentials
-                .get(0)
+                .first()
                 .unwrap()
                 .signatures
-                .get(0)
+                .first()
                 .unwrap(),
             &Vec::<u8>::from([
                 152, 52, 192, 175, 13, 217, 44, 10, 162, 254, 189, 199, 231, 177, 71, 215, 180,
@@ -7,9 +7,10 @@ use std::borrow::Borrow;
 use std::error::Error;
 use tracing::{instrument, trace};
 
-#[instrument(fields(block_id = % _context.tx_id, block_type = "commit_block"))]
+#[instrument(fields(block_id = % _block_id, block_type = "commit_block"))]
 pub fn commit_block_parser(
     _raw_msg: &[u8],
+    _block_id: String,
     _context: &mut Context,
 ) -> Result<BlockData, Box<dyn Error>> {
     let parent_block_id = encode(&_raw_msg[*_context.offset..=(*_context.offset + 31)]).to_base58();
@@ -24,9 +25,10 @@ pub fn commit_block_parser(
 
     Ok(BlockData {
         type_id: 2,
+        block_id: _block_id,
         height,
         parent_block_id,
+        timestamp: None,
         transactions: vec![],
-        credentials: vec![],
     })
 }
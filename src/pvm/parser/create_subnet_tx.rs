@@ -18,7 +18,6 @@ pub struct CreateSubnetTx {
 #[instrument(fields(block_id = % _context.tx_id, tx_type = "create_subnet"))]
 pub fn create_subnet_tx_parser(
     _raw_msg: &[u8],
-    _tx_id: String,
     _context: &mut Context,
 ) -> Result<Transaction, Box<dyn Error>> {
     let base_tx = base_tx_parser(_raw_msg, _context)?;
@@ -36,7 +35,7 @@ pub fn create_subnet_tx_parser(
 
     Ok(Transaction {
         base_tx,
-        tx_id: _tx_id,
+        tx_id: String::new(),
         add_validator_tx: None,
         import_tx: None,
         export_tx: None,
@@ -46,6 +45,7 @@ pub fn create_subnet_tx_parser(
         create_subnet_tx: Some(create_subnet),
         advance_time_tx: None,
         reward_validator_tx: None,
+        unsigned_tx: vec![],
         credentials: vec![],
     })
 }
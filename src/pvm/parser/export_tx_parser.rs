@@ -20,7 +20,6 @@ pub struct ExportTx {
 #[instrument(fields(block_id = % _context.tx_id, tx_type = "export"))]
 pub fn export_tx_parser(
     _raw_msg: &[u8],
-    _tx_id: String,
     _context: &mut Context,
 ) -> Result<Transaction, Box<dyn Error>> {
     let base_tx = base_tx_parser(_raw_msg, _context)?;
@@ -52,7 +51,7 @@ pub fn export_tx_parser(
 
     Ok(Transaction {
         base_tx,
-        tx_id: _tx_id,
+        tx_id: String::new(),
         add_validator_tx: None,
         import_tx: None,
         export_tx: Some(export_tx),
@@ -62,6 +61,7 @@ pub fn export_tx_parser(
         create_subnet_tx: None,
         advance_time_tx: None,
         reward_validator_tx: None,
+        unsigned_tx: vec![],
         credentials: vec![],
     })
 }
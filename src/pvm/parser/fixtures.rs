@@ -0,0 +1,473 @@
+use std::time::SystemTime;
+
+use crate::avm::parser::Context;
+use crate::pvm::parser::block_parser::{block_parser, Block, BlockData};
+
+/// Blocks shared by the tests of the P-chain modules .
+pub fn parse_block(_raw_bytes: &[u8]) -> Block {
+    block_parser(
+        &mut _raw_bytes.to_vec(),
+        &mut Context {
+            tx_id: "a_tx",
+            uuid: Default::default(),
+            offset: &mut 0,
+            parsing_started: SystemTime::now(),
+            network_name: "avax".to_string(),
+        },
+    )
+    .unwrap()
+}
+
+/// The block of `decode_proposal_bloc_advance_time_tx_01` , proposing 1_606_770_043 as chain time .
+pub fn advance_time_proposal_block() -> Block {
+    parse_block(&[
+        0, 0, 0, 0, 0, 0, 197, 236, 212, 165, 6, 90, 82, 30, 95, 197, 7, 247, 214, 156, 88, 84,
+        162, 68, 93, 7, 61, 67, 98, 61, 102, 128, 48, 216, 63, 253, 242, 46, 0, 0, 0, 0, 0, 3, 154,
+        192, 0, 0, 0, 19, 0, 0, 0, 0, 95, 197, 93, 123, 0, 0, 0, 0,
+    ])
+}
+
+/// A proposal block rewarding a validator .
+pub fn reward_validator_proposal_block() -> Block {
+    parse_block(&[
+        0, 0, 0, 0, 0, 0, 211, 215, 13, 44, 243, 156, 224, 21, 70, 67, 97, 105, 201, 42, 36, 159,
+        196, 165, 194, 165, 46, 71, 82, 246, 238, 220, 112, 29, 78, 63, 105, 146, 0, 0, 0, 3, 80,
+        44, 0, 0, 0, 0, 0, 20, 255, 228, 100, 108, 37, 204, 149, 81, 14, 26, 134, 2, 175, 246, 28,
+        105, 100, 253, 38, 107, 2, 231, 12, 19, 155, 199, 166, 202, 48, 106, 81, 110, 0, 0, 0, 0,
+    ])
+}
+
+/// The block of `decode_proposal_block_add_validator_tx_01` , staking 9_000 AVAX of which 6_750 are locked .
+pub fn add_validator_proposal_block() -> Block {
+    parse_block(&[
+        0, 0, 0, 0, 0, 0, 67, 150, 180, 111, 48, 156, 239, 146, 92, 17, 26, 99, 86, 186, 33, 50,
+        193, 27, 159, 161, 33, 79, 0, 159, 169, 27, 199, 136, 228, 105, 167, 243, 0, 0, 0, 0, 0, 3,
+        77, 115, 0, 0, 0, 12, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 33, 230, 115, 23, 203, 196, 190, 42,
+        235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48,
+        39, 168, 125, 255, 0, 0, 0, 7, 0, 0, 4, 81, 44, 31, 80, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 1, 0, 0, 0, 1, 87, 241, 225, 40, 17, 178, 47, 105, 253, 57, 85, 62, 109, 19, 19, 46,
+        226, 31, 245, 103, 0, 0, 0, 11, 31, 2, 127, 99, 125, 84, 68, 230, 34, 240, 249, 228, 107,
+        102, 187, 245, 158, 153, 31, 13, 205, 174, 74, 147, 180, 123, 20, 193, 30, 87, 238, 231, 0,
+        0, 0, 2, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245,
+        34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0, 0, 0,
+        185, 131, 182, 87, 0, 0, 0, 1, 0, 0, 0, 0, 99, 98, 95, 183, 138, 167, 38, 173, 201, 132,
+        67, 112, 83, 239, 228, 56, 21, 150, 147, 240, 238, 246, 140, 103, 193, 165, 49, 86, 68, 46,
+        55, 63, 0, 0, 0, 0, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39,
+        120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5,
+        0, 0, 6, 65, 223, 244, 192, 0, 0, 0, 0, 1, 0, 0, 0, 0, 99, 98, 95, 183, 138, 167, 38, 173,
+        201, 132, 67, 112, 83, 239, 228, 56, 21, 150, 147, 240, 238, 246, 140, 103, 193, 165, 49,
+        86, 68, 46, 55, 63, 0, 0, 0, 1, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214,
+        70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0,
+        0, 0, 5, 0, 0, 0, 7, 183, 129, 133, 157, 0, 0, 0, 1, 0, 0, 0, 0, 99, 98, 95, 183, 138, 167,
+        38, 173, 201, 132, 67, 112, 83, 239, 228, 56, 21, 150, 147, 240, 238, 246, 140, 103, 193,
+        165, 49, 86, 68, 46, 55, 63, 0, 0, 0, 2, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103,
+        122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125,
+        255, 0, 0, 0, 5, 0, 0, 0, 0, 217, 16, 129, 48, 0, 0, 0, 1, 0, 0, 0, 0, 138, 58, 121, 62,
+        193, 161, 93, 50, 87, 36, 198, 221, 96, 7, 217, 34, 166, 76, 121, 206, 192, 102, 155, 162,
+        179, 234, 196, 250, 172, 28, 155, 111, 0, 0, 0, 8, 33, 230, 115, 23, 203, 196, 190, 42,
+        235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48,
+        39, 168, 125, 255, 0, 0, 0, 5, 0, 0, 0, 0, 39, 161, 97, 238, 0, 0, 0, 1, 0, 0, 0, 0, 190,
+        108, 5, 99, 157, 96, 233, 119, 79, 77, 158, 54, 250, 229, 18, 155, 2, 151, 97, 61, 68, 151,
+        128, 201, 145, 212, 149, 37, 247, 71, 93, 117, 0, 0, 0, 6, 33, 230, 115, 23, 203, 196, 190,
+        42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178,
+        48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0, 0, 0, 3, 23, 48, 224, 0, 0, 0, 1, 0, 0, 0, 0, 199,
+        151, 27, 48, 27, 214, 145, 94, 72, 228, 44, 221, 83, 213, 157, 236, 36, 151, 69, 205, 19,
+        0, 164, 80, 232, 121, 252, 62, 184, 103, 107, 70, 0, 0, 0, 2, 33, 230, 115, 23, 203, 196,
+        190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145,
+        178, 48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0, 0, 0, 196, 21, 112, 82, 0, 0, 0, 1, 0, 0, 0,
+        0, 209, 83, 210, 127, 249, 72, 44, 51, 229, 86, 156, 52, 151, 242, 164, 11, 215, 203, 92,
+        11, 151, 55, 179, 45, 80, 106, 206, 113, 52, 58, 56, 145, 0, 0, 0, 0, 33, 230, 115, 23,
+        203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223,
+        37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 21, 0, 0, 0, 0, 95, 208, 19, 128, 0, 0, 0, 5,
+        0, 0, 2, 11, 222, 115, 100, 0, 0, 0, 0, 1, 0, 0, 0, 0, 209, 83, 210, 127, 249, 72, 44, 51,
+        229, 86, 156, 52, 151, 242, 164, 11, 215, 203, 92, 11, 151, 55, 179, 45, 80, 106, 206, 113,
+        52, 58, 56, 145, 0, 0, 0, 2, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214,
+        70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0,
+        0, 0, 21, 0, 0, 0, 0, 96, 189, 97, 128, 0, 0, 0, 5, 0, 0, 2, 11, 222, 115, 100, 0, 0, 0, 0,
+        1, 0, 0, 0, 0, 209, 83, 210, 127, 249, 72, 44, 51, 229, 86, 156, 52, 151, 242, 164, 11,
+        215, 203, 92, 11, 151, 55, 179, 45, 80, 106, 206, 113, 52, 58, 56, 145, 0, 0, 0, 3, 33,
+        230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116,
+        185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 21, 0, 0, 0, 0, 97, 52, 8,
+        128, 0, 0, 0, 5, 0, 0, 2, 11, 222, 115, 100, 0, 0, 0, 0, 1, 0, 0, 0, 0, 209, 83, 210, 127,
+        249, 72, 44, 51, 229, 86, 156, 52, 151, 242, 164, 11, 215, 203, 92, 11, 151, 55, 179, 45,
+        80, 106, 206, 113, 52, 58, 56, 145, 0, 0, 0, 4, 33, 230, 115, 23, 203, 196, 190, 42, 235,
+        0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39,
+        168, 125, 255, 0, 0, 0, 5, 0, 0, 0, 16, 241, 186, 51, 223, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+        4, 0, 0, 0, 0, 154, 15, 11, 24, 5, 72, 183, 198, 165, 196, 255, 180, 38, 167, 81, 200, 139,
+        55, 110, 192, 0, 0, 0, 0, 95, 155, 237, 43, 0, 0, 0, 0, 95, 250, 53, 207, 0, 0, 8, 47, 121,
+        205, 144, 0, 0, 0, 0, 4, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70,
+        39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0,
+        7, 0, 0, 2, 11, 222, 115, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 10, 243,
+        106, 61, 213, 200, 227, 201, 124, 238, 58, 53, 27, 93, 194, 39, 112, 178, 112, 242, 33,
+        230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116,
+        185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 22, 0, 0, 0, 0, 95, 208,
+        19, 128, 0, 0, 0, 7, 0, 0, 2, 11, 222, 115, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+        0, 0, 1, 10, 243, 106, 61, 213, 200, 227, 201, 124, 238, 58, 53, 27, 93, 194, 39, 112, 178,
+        112, 242, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168,
+        245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 22, 0, 0, 0,
+        0, 96, 189, 97, 128, 0, 0, 0, 7, 0, 0, 2, 11, 222, 115, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 0, 0, 0, 1, 10, 243, 106, 61, 213, 200, 227, 201, 124, 238, 58, 53, 27, 93, 194,
+        39, 112, 178, 112, 242, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39,
+        120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 22,
+        0, 0, 0, 0, 97, 52, 8, 128, 0, 0, 0, 7, 0, 0, 2, 11, 222, 115, 100, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 1, 0, 0, 0, 1, 10, 243, 106, 61, 213, 200, 227, 201, 124, 238, 58, 53, 27, 93,
+        194, 39, 112, 178, 112, 242, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1,
+        10, 243, 106, 61, 213, 200, 227, 201, 124, 238, 58, 53, 27, 93, 194, 39, 112, 178, 112,
+        242, 0, 1, 95, 144, 0, 0, 0, 11, 0, 0, 0, 9, 0, 0, 0, 1, 152, 123, 199, 222, 2, 111, 207,
+        63, 63, 210, 130, 247, 175, 148, 74, 58, 226, 167, 107, 139, 159, 198, 13, 107, 71, 149,
+        43, 70, 211, 99, 152, 45, 27, 170, 236, 252, 169, 32, 131, 211, 12, 71, 182, 64, 198, 194,
+        102, 182, 111, 182, 132, 51, 22, 8, 109, 54, 203, 191, 116, 221, 235, 135, 148, 58, 0, 0,
+        0, 0, 9, 0, 0, 0, 1, 152, 123, 199, 222, 2, 111, 207, 63, 63, 210, 130, 247, 175, 148, 74,
+        58, 226, 167, 107, 139, 159, 198, 13, 107, 71, 149, 43, 70, 211, 99, 152, 45, 27, 170, 236,
+        252, 169, 32, 131, 211, 12, 71, 182, 64, 198, 194, 102, 182, 111, 182, 132, 51, 22, 8, 109,
+        54, 203, 191, 116, 221, 235, 135, 148, 58, 0, 0, 0, 0, 9, 0, 0, 0, 1, 152, 123, 199, 222,
+        2, 111, 207, 63, 63, 210, 130, 247, 175, 148, 74, 58, 226, 167, 107, 139, 159, 198, 13,
+        107, 71, 149, 43, 70, 211, 99, 152, 45, 27, 170, 236, 252, 169, 32, 131, 211, 12, 71, 182,
+        64, 198, 194, 102, 182, 111, 182, 132, 51, 22, 8, 109, 54, 203, 191, 116, 221, 235, 135,
+        148, 58, 0, 0, 0, 0, 9, 0, 0, 0, 1, 152, 123, 199, 222, 2, 111, 207, 63, 63, 210, 130, 247,
+        175, 148, 74, 58, 226, 167, 107, 139, 159, 198, 13, 107, 71, 149, 43, 70, 211, 99, 152, 45,
+        27, 170, 236, 252, 169, 32, 131, 211, 12, 71, 182, 64, 198, 194, 102, 182, 111, 182, 132,
+        51, 22, 8, 109, 54, 203, 191, 116, 221, 235, 135, 148, 58, 0, 0, 0, 0, 9, 0, 0, 0, 1, 152,
+        123, 199, 222, 2, 111, 207, 63, 63, 210, 130, 247, 175, 148, 74, 58, 226, 167, 107, 139,
+        159, 198, 13, 107, 71, 149, 43, 70, 211, 99, 152, 45, 27, 170, 236, 252, 169, 32, 131, 211,
+        12, 71, 182, 64, 198, 194, 102, 182, 111, 182, 132, 51, 22, 8, 109, 54, 203, 191, 116, 221,
+        235, 135, 148, 58, 0, 0, 0, 0, 9, 0, 0, 0, 1, 152, 123, 199, 222, 2, 111, 207, 63, 63, 210,
+        130, 247, 175, 148, 74, 58, 226, 167, 107, 139, 159, 198, 13, 107, 71, 149, 43, 70, 211,
+        99, 152, 45, 27, 170, 236, 252, 169, 32, 131, 211, 12, 71, 182, 64, 198, 194, 102, 182,
+        111, 182, 132, 51, 22, 8, 109, 54, 203, 191, 116, 221, 235, 135, 148, 58, 0, 0, 0, 0, 9, 0,
+        0, 0, 1, 152, 123, 199, 222, 2, 111, 207, 63, 63, 210, 130, 247, 175, 148, 74, 58, 226,
+        167, 107, 139, 159, 198, 13, 107, 71, 149, 43, 70, 211, 99, 152, 45, 27, 170, 236, 252,
+        169, 32, 131, 211, 12, 71, 182, 64, 198, 194, 102, 182, 111, 182, 132, 51, 22, 8, 109, 54,
+        203, 191, 116, 221, 235, 135, 148, 58, 0, 0, 0, 0, 9, 0, 0, 0, 1, 152, 123, 199, 222, 2,
+        111, 207, 63, 63, 210, 130, 247, 175, 148, 74, 58, 226, 167, 107, 139, 159, 198, 13, 107,
+        71, 149, 43, 70, 211, 99, 152, 45, 27, 170, 236, 252, 169, 32, 131, 211, 12, 71, 182, 64,
+        198, 194, 102, 182, 111, 182, 132, 51, 22, 8, 109, 54, 203, 191, 116, 221, 235, 135, 148,
+        58, 0, 0, 0, 0, 9, 0, 0, 0, 1, 152, 123, 199, 222, 2, 111, 207, 63, 63, 210, 130, 247, 175,
+        148, 74, 58, 226, 167, 107, 139, 159, 198, 13, 107, 71, 149, 43, 70, 211, 99, 152, 45, 27,
+        170, 236, 252, 169, 32, 131, 211, 12, 71, 182, 64, 198, 194, 102, 182, 111, 182, 132, 51,
+        22, 8, 109, 54, 203, 191, 116, 221, 235, 135, 148, 58, 0, 0, 0, 0, 9, 0, 0, 0, 1, 152, 123,
+        199, 222, 2, 111, 207, 63, 63, 210, 130, 247, 175, 148, 74, 58, 226, 167, 107, 139, 159,
+        198, 13, 107, 71, 149, 43, 70, 211, 99, 152, 45, 27, 170, 236, 252, 169, 32, 131, 211, 12,
+        71, 182, 64, 198, 194, 102, 182, 111, 182, 132, 51, 22, 8, 109, 54, 203, 191, 116, 221,
+        235, 135, 148, 58, 0, 0, 0, 0, 9, 0, 0, 0, 1, 152, 123, 199, 222, 2, 111, 207, 63, 63, 210,
+        130, 247, 175, 148, 74, 58, 226, 167, 107, 139, 159, 198, 13, 107, 71, 149, 43, 70, 211,
+        99, 152, 45, 27, 170, 236, 252, 169, 32, 131, 211, 12, 71, 182, 64, 198, 194, 102, 182,
+        111, 182, 132, 51, 22, 8, 109, 54, 203, 191, 116, 221, 235, 135, 148, 58, 0,
+    ])
+}
+
+/// The block of `decode_commit_block_01` as a Banff block , committed at 1_671_000_000 .
+pub fn banff_commit_block() -> Block {
+    parse_block(&[
+        0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 99, 153, 111, 192, 214, 142, 91, 15, 220, 78, 129, 95, 27,
+        67, 22, 113, 29, 10, 218, 112, 124, 35, 152, 149, 112, 134, 127, 85, 118, 132, 189, 204,
+        25, 190, 252, 71, 0, 0, 0, 0, 0, 3, 44, 172,
+    ])
+}
+
+/// The block of `decode_standard_block_create_subnet_tx_01` .
+pub fn create_subnet_block() -> Block {
+    parse_block(&[
+        0, 0, 0, 0, 0, 3, 135, 136, 121, 63, 185, 169, 36, 253, 59, 122, 131, 56, 255, 62, 143,
+        109, 140, 186, 162, 180, 54, 245, 14, 113, 169, 77, 209, 16, 136, 240, 115, 208, 0, 0, 0,
+        0, 0, 0, 23, 94, 0, 0, 0, 1, 0, 0, 0, 16, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 33, 230, 115, 23,
+        203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223,
+        37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 7, 0, 0, 0, 0, 0, 15, 222, 128, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 138, 19, 176, 23, 28, 117, 3, 117, 99, 58, 242, 121, 4,
+        183, 72, 88, 95, 215, 126, 126, 0, 0, 0, 2, 28, 243, 243, 194, 77, 23, 95, 92, 212, 227,
+        148, 200, 117, 101, 1, 13, 41, 195, 54, 55, 115, 64, 227, 253, 23, 145, 246, 71, 36, 196,
+        24, 207, 0, 0, 0, 0, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39,
+        120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5,
+        0, 0, 0, 0, 0, 137, 240, 128, 0, 0, 0, 1, 0, 0, 0, 0, 80, 4, 198, 252, 185, 71, 102, 147,
+        234, 153, 21, 35, 102, 130, 43, 103, 117, 56, 105, 140, 45, 74, 75, 6, 41, 223, 226, 193,
+        243, 127, 67, 71, 0, 0, 0, 0, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214,
+        70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0,
+        0, 0, 5, 0, 0, 0, 0, 0, 30, 132, 128, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 2, 79, 56, 181, 252, 149, 85, 157, 235, 182, 127,
+        118, 41, 1, 171, 96, 207, 100, 204, 39, 217, 138, 19, 176, 23, 28, 117, 3, 117, 99, 58,
+        242, 121, 4, 183, 72, 88, 95, 215, 126, 126, 0, 0, 0, 2, 0, 0, 0, 9, 0, 0, 0, 1, 133, 188,
+        205, 47, 36, 150, 109, 85, 40, 53, 184, 103, 207, 214, 19, 42, 64, 90, 245, 42, 42, 62, 15,
+        214, 81, 12, 76, 24, 127, 93, 232, 185, 98, 178, 14, 192, 75, 213, 188, 14, 171, 255, 135,
+        86, 83, 213, 215, 2, 157, 208, 32, 178, 100, 35, 99, 180, 193, 64, 1, 187, 160, 97, 85,
+        224, 1, 0, 0, 0, 9, 0, 0, 0, 1, 231, 140, 52, 143, 43, 177, 9, 139, 82, 198, 100, 161, 242,
+        194, 88, 49, 229, 141, 15, 158, 64, 187, 247, 49, 92, 147, 239, 68, 253, 123, 8, 51, 37,
+        214, 56, 223, 216, 120, 216, 53, 167, 207, 32, 133, 5, 15, 108, 90, 183, 103, 192, 69, 185,
+        255, 215, 142, 64, 235, 79, 5, 246, 219, 71, 122, 0,
+    ])
+}
+
+/// A Banff proposal block at 1_604_000_000 , built from the txs of the blocks above :
+/// the add validator tx of `add_validator_proposal_block` as its decision tx ,
+/// then the reward validator tx of `reward_validator_proposal_block` as its proposal tx .
+pub fn banff_proposal_block() -> Block {
+    parse_block(&[
+        0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 95, 155, 25, 0, 0, 0, 0, 1, 0, 0, 0, 12, 0, 0, 0, 1, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 1, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168,
+        245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 7, 0, 0, 4,
+        81, 44, 31, 80, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 87, 241, 225, 40, 17,
+        178, 47, 105, 253, 57, 85, 62, 109, 19, 19, 46, 226, 31, 245, 103, 0, 0, 0, 11, 31, 2, 127,
+        99, 125, 84, 68, 230, 34, 240, 249, 228, 107, 102, 187, 245, 158, 153, 31, 13, 205, 174,
+        74, 147, 180, 123, 20, 193, 30, 87, 238, 231, 0, 0, 0, 2, 33, 230, 115, 23, 203, 196, 190,
+        42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178,
+        48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0, 0, 0, 185, 131, 182, 87, 0, 0, 0, 1, 0, 0, 0, 0,
+        99, 98, 95, 183, 138, 167, 38, 173, 201, 132, 67, 112, 83, 239, 228, 56, 21, 150, 147, 240,
+        238, 246, 140, 103, 193, 165, 49, 86, 68, 46, 55, 63, 0, 0, 0, 0, 33, 230, 115, 23, 203,
+        196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37,
+        145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0, 6, 65, 223, 244, 192, 0, 0, 0, 0, 1, 0,
+        0, 0, 0, 99, 98, 95, 183, 138, 167, 38, 173, 201, 132, 67, 112, 83, 239, 228, 56, 21, 150,
+        147, 240, 238, 246, 140, 103, 193, 165, 49, 86, 68, 46, 55, 63, 0, 0, 0, 1, 33, 230, 115,
+        23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5,
+        223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0, 0, 7, 183, 129, 133, 157, 0, 0,
+        0, 1, 0, 0, 0, 0, 99, 98, 95, 183, 138, 167, 38, 173, 201, 132, 67, 112, 83, 239, 228, 56,
+        21, 150, 147, 240, 238, 246, 140, 103, 193, 165, 49, 86, 68, 46, 55, 63, 0, 0, 0, 2, 33,
+        230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116,
+        185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0, 0, 0, 217, 16,
+        129, 48, 0, 0, 0, 1, 0, 0, 0, 0, 138, 58, 121, 62, 193, 161, 93, 50, 87, 36, 198, 221, 96,
+        7, 217, 34, 166, 76, 121, 206, 192, 102, 155, 162, 179, 234, 196, 250, 172, 28, 155, 111,
+        0, 0, 0, 8, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168,
+        245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0, 0,
+        0, 39, 161, 97, 238, 0, 0, 0, 1, 0, 0, 0, 0, 190, 108, 5, 99, 157, 96, 233, 119, 79, 77,
+        158, 54, 250, 229, 18, 155, 2, 151, 97, 61, 68, 151, 128, 201, 145, 212, 149, 37, 247, 71,
+        93, 117, 0, 0, 0, 6, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39,
+        120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5,
+        0, 0, 0, 0, 3, 23, 48, 224, 0, 0, 0, 1, 0, 0, 0, 0, 199, 151, 27, 48, 27, 214, 145, 94, 72,
+        228, 44, 221, 83, 213, 157, 236, 36, 151, 69, 205, 19, 0, 164, 80, 232, 121, 252, 62, 184,
+        103, 107, 70, 0, 0, 0, 2, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70,
+        39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0,
+        5, 0, 0, 0, 0, 196, 21, 112, 82, 0, 0, 0, 1, 0, 0, 0, 0, 209, 83, 210, 127, 249, 72, 44,
+        51, 229, 86, 156, 52, 151, 242, 164, 11, 215, 203, 92, 11, 151, 55, 179, 45, 80, 106, 206,
+        113, 52, 58, 56, 145, 0, 0, 0, 0, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122,
+        214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255,
+        0, 0, 0, 21, 0, 0, 0, 0, 95, 208, 19, 128, 0, 0, 0, 5, 0, 0, 2, 11, 222, 115, 100, 0, 0, 0,
+        0, 1, 0, 0, 0, 0, 209, 83, 210, 127, 249, 72, 44, 51, 229, 86, 156, 52, 151, 242, 164, 11,
+        215, 203, 92, 11, 151, 55, 179, 45, 80, 106, 206, 113, 52, 58, 56, 145, 0, 0, 0, 2, 33,
+        230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116,
+        185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 21, 0, 0, 0, 0, 96, 189,
+        97, 128, 0, 0, 0, 5, 0, 0, 2, 11, 222, 115, 100, 0, 0, 0, 0, 1, 0, 0, 0, 0, 209, 83, 210,
+        127, 249, 72, 44, 51, 229, 86, 156, 52, 151, 242, 164, 11, 215, 203, 92, 11, 151, 55, 179,
+        45, 80, 106, 206, 113, 52, 58, 56, 145, 0, 0, 0, 3, 33, 230, 115, 23, 203, 196, 190, 42,
+        235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48,
+        39, 168, 125, 255, 0, 0, 0, 21, 0, 0, 0, 0, 97, 52, 8, 128, 0, 0, 0, 5, 0, 0, 2, 11, 222,
+        115, 100, 0, 0, 0, 0, 1, 0, 0, 0, 0, 209, 83, 210, 127, 249, 72, 44, 51, 229, 86, 156, 52,
+        151, 242, 164, 11, 215, 203, 92, 11, 151, 55, 179, 45, 80, 106, 206, 113, 52, 58, 56, 145,
+        0, 0, 0, 4, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168,
+        245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0, 0,
+        16, 241, 186, 51, 223, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 154, 15, 11, 24, 5,
+        72, 183, 198, 165, 196, 255, 180, 38, 167, 81, 200, 139, 55, 110, 192, 0, 0, 0, 0, 95, 155,
+        237, 43, 0, 0, 0, 0, 95, 250, 53, 207, 0, 0, 8, 47, 121, 205, 144, 0, 0, 0, 0, 4, 33, 230,
+        115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185,
+        214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 7, 0, 0, 2, 11, 222, 115, 100,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 10, 243, 106, 61, 213, 200, 227, 201,
+        124, 238, 58, 53, 27, 93, 194, 39, 112, 178, 112, 242, 33, 230, 115, 23, 203, 196, 190, 42,
+        235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48,
+        39, 168, 125, 255, 0, 0, 0, 22, 0, 0, 0, 0, 95, 208, 19, 128, 0, 0, 0, 7, 0, 0, 2, 11, 222,
+        115, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 10, 243, 106, 61, 213, 200,
+        227, 201, 124, 238, 58, 53, 27, 93, 194, 39, 112, 178, 112, 242, 33, 230, 115, 23, 203,
+        196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37,
+        145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 22, 0, 0, 0, 0, 96, 189, 97, 128, 0, 0, 0, 7, 0,
+        0, 2, 11, 222, 115, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 10, 243, 106,
+        61, 213, 200, 227, 201, 124, 238, 58, 53, 27, 93, 194, 39, 112, 178, 112, 242, 33, 230,
+        115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185,
+        214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 22, 0, 0, 0, 0, 97, 52, 8, 128,
+        0, 0, 0, 7, 0, 0, 2, 11, 222, 115, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1,
+        10, 243, 106, 61, 213, 200, 227, 201, 124, 238, 58, 53, 27, 93, 194, 39, 112, 178, 112,
+        242, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 10, 243, 106, 61, 213,
+        200, 227, 201, 124, 238, 58, 53, 27, 93, 194, 39, 112, 178, 112, 242, 0, 1, 95, 144, 0, 0,
+        0, 11, 0, 0, 0, 9, 0, 0, 0, 1, 152, 123, 199, 222, 2, 111, 207, 63, 63, 210, 130, 247, 175,
+        148, 74, 58, 226, 167, 107, 139, 159, 198, 13, 107, 71, 149, 43, 70, 211, 99, 152, 45, 27,
+        170, 236, 252, 169, 32, 131, 211, 12, 71, 182, 64, 198, 194, 102, 182, 111, 182, 132, 51,
+        22, 8, 109, 54, 203, 191, 116, 221, 235, 135, 148, 58, 0, 0, 0, 0, 9, 0, 0, 0, 1, 152, 123,
+        199, 222, 2, 111, 207, 63, 63, 210, 130, 247, 175, 148, 74, 58, 226, 167, 107, 139, 159,
+        198, 13, 107, 71, 149, 43, 70, 211, 99, 152, 45, 27, 170, 236, 252, 169, 32, 131, 211, 12,
+        71, 182, 64, 198, 194, 102, 182, 111, 182, 132, 51, 22, 8, 109, 54, 203, 191, 116, 221,
+        235, 135, 148, 58, 0, 0, 0, 0, 9, 0, 0, 0, 1, 152, 123, 199, 222, 2, 111, 207, 63, 63, 210,
+        130, 247, 175, 148, 74, 58, 226, 167, 107, 139, 159, 198, 13, 107, 71, 149, 43, 70, 211,
+        99, 152, 45, 27, 170, 236, 252, 169, 32, 131, 211, 12, 71, 182, 64, 198, 194, 102, 182,
+        111, 182, 132, 51, 22, 8, 109, 54, 203, 191, 116, 221, 235, 135, 148, 58, 0, 0, 0, 0, 9, 0,
+        0, 0, 1, 152, 123, 199, 222, 2, 111, 207, 63, 63, 210, 130, 247, 175, 148, 74, 58, 226,
+        167, 107, 139, 159, 198, 13, 107, 71, 149, 43, 70, 211, 99, 152, 45, 27, 170, 236, 252,
+        169, 32, 131, 211, 12, 71, 182, 64, 198, 194, 102, 182, 111, 182, 132, 51, 22, 8, 109, 54,
+        203, 191, 116, 221, 235, 135, 148, 58, 0, 0, 0, 0, 9, 0, 0, 0, 1, 152, 123, 199, 222, 2,
+        111, 207, 63, 63, 210, 130, 247, 175, 148, 74, 58, 226, 167, 107, 139, 159, 198, 13, 107,
+        71, 149, 43, 70, 211, 99, 152, 45, 27, 170, 236, 252, 169, 32, 131, 211, 12, 71, 182, 64,
+        198, 194, 102, 182, 111, 182, 132, 51, 22, 8, 109, 54, 203, 191, 116, 221, 235, 135, 148,
+        58, 0, 0, 0, 0, 9, 0, 0, 0, 1, 152, 123, 199, 222, 2, 111, 207, 63, 63, 210, 130, 247, 175,
+        148, 74, 58, 226, 167, 107, 139, 159, 198, 13, 107, 71, 149, 43, 70, 211, 99, 152, 45, 27,
+        170, 236, 252, 169, 32, 131, 211, 12, 71, 182, 64, 198, 194, 102, 182, 111, 182, 132, 51,
+        22, 8, 109, 54, 203, 191, 116, 221, 235, 135, 148, 58, 0, 0, 0, 0, 9, 0, 0, 0, 1, 152, 123,
+        199, 222, 2, 111, 207, 63, 63, 210, 130, 247, 175, 148, 74, 58, 226, 167, 107, 139, 159,
+        198, 13, 107, 71, 149, 43, 70, 211, 99, 152, 45, 27, 170, 236, 252, 169, 32, 131, 211, 12,
+        71, 182, 64, 198, 194, 102, 182, 111, 182, 132, 51, 22, 8, 109, 54, 203, 191, 116, 221,
+        235, 135, 148, 58, 0, 0, 0, 0, 9, 0, 0, 0, 1, 152, 123, 199, 222, 2, 111, 207, 63, 63, 210,
+        130, 247, 175, 148, 74, 58, 226, 167, 107, 139, 159, 198, 13, 107, 71, 149, 43, 70, 211,
+        99, 152, 45, 27, 170, 236, 252, 169, 32, 131, 211, 12, 71, 182, 64, 198, 194, 102, 182,
+        111, 182, 132, 51, 22, 8, 109, 54, 203, 191, 116, 221, 235, 135, 148, 58, 0, 0, 0, 0, 9, 0,
+        0, 0, 1, 152, 123, 199, 222, 2, 111, 207, 63, 63, 210, 130, 247, 175, 148, 74, 58, 226,
+        167, 107, 139, 159, 198, 13, 107, 71, 149, 43, 70, 211, 99, 152, 45, 27, 170, 236, 252,
+        169, 32, 131, 211, 12, 71, 182, 64, 198, 194, 102, 182, 111, 182, 132, 51, 22, 8, 109, 54,
+        203, 191, 116, 221, 235, 135, 148, 58, 0, 0, 0, 0, 9, 0, 0, 0, 1, 152, 123, 199, 222, 2,
+        111, 207, 63, 63, 210, 130, 247, 175, 148, 74, 58, 226, 167, 107, 139, 159, 198, 13, 107,
+        71, 149, 43, 70, 211, 99, 152, 45, 27, 170, 236, 252, 169, 32, 131, 211, 12, 71, 182, 64,
+        198, 194, 102, 182, 111, 182, 132, 51, 22, 8, 109, 54, 203, 191, 116, 221, 235, 135, 148,
+        58, 0, 0, 0, 0, 9, 0, 0, 0, 1, 152, 123, 199, 222, 2, 111, 207, 63, 63, 210, 130, 247, 175,
+        148, 74, 58, 226, 167, 107, 139, 159, 198, 13, 107, 71, 149, 43, 70, 211, 99, 152, 45, 27,
+        170, 236, 252, 169, 32, 131, 211, 12, 71, 182, 64, 198, 194, 102, 182, 111, 182, 132, 51,
+        22, 8, 109, 54, 203, 191, 116, 221, 235, 135, 148, 58, 0, 123, 33, 4, 235, 236, 98, 245,
+        244, 32, 57, 110, 69, 7, 49, 161, 250, 129, 87, 134, 109, 101, 94, 207, 238, 15, 197, 116,
+        97, 106, 247, 67, 73, 0, 0, 0, 0, 0, 30, 132, 128, 0, 0, 0, 20, 255, 228, 100, 108, 37,
+        204, 149, 81, 14, 26, 134, 2, 175, 246, 28, 105, 100, 253, 38, 107, 2, 231, 12, 19, 155,
+        199, 166, 202, 48, 106, 81, 110, 0, 0, 0, 0,
+    ])
+}
+
+/// The commit or abort child of a proposal block .
+pub fn option_block(_type_id: i32, _proposal: &Block) -> Block {
+    Block {
+        codec_id: 0,
+        type_id: _type_id,
+        block_data: BlockData {
+            type_id: _type_id,
+            height: _proposal.block_data.height + 1,
+            block_id: "an_option_block".to_string(),
+            parent_block_id: _proposal.block_data.block_id.clone(),
+            timestamp: None,
+            transactions: vec![],
+        },
+    }
+}
+
+/// The create blockchain tx of the C-Chain in the mainnet genesis , its tx id is the id of the C-Chain .
+/// Genesis txs are in no block , it is put in a standard block at height 0 .
+pub fn c_chain_genesis_block() -> Block {
+    parse_block(&[
+        0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 15, 0, 0, 0, 1, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 67, 45, 67, 104, 97, 105, 110, 101, 118,
+        109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 5, 72, 123, 34, 99, 111, 110, 102, 105, 103, 34, 58, 123, 34, 99, 104,
+        97, 105, 110, 73, 100, 34, 58, 52, 51, 49, 49, 52, 44, 34, 104, 111, 109, 101, 115, 116,
+        101, 97, 100, 66, 108, 111, 99, 107, 34, 58, 48, 44, 34, 100, 97, 111, 70, 111, 114, 107,
+        66, 108, 111, 99, 107, 34, 58, 48, 44, 34, 100, 97, 111, 70, 111, 114, 107, 83, 117, 112,
+        112, 111, 114, 116, 34, 58, 116, 114, 117, 101, 44, 34, 101, 105, 112, 49, 53, 48, 66, 108,
+        111, 99, 107, 34, 58, 48, 44, 34, 101, 105, 112, 49, 53, 48, 72, 97, 115, 104, 34, 58, 34,
+        48, 120, 50, 48, 56, 54, 55, 57, 57, 97, 101, 101, 98, 101, 97, 101, 49, 51, 53, 99, 50,
+        52, 54, 99, 54, 53, 48, 50, 49, 99, 56, 50, 98, 52, 101, 49, 53, 97, 50, 99, 52, 53, 49,
+        51, 52, 48, 57, 57, 51, 97, 97, 99, 102, 100, 50, 55, 53, 49, 56, 56, 54, 53, 49, 52, 102,
+        48, 34, 44, 34, 101, 105, 112, 49, 53, 53, 66, 108, 111, 99, 107, 34, 58, 48, 44, 34, 101,
+        105, 112, 49, 53, 56, 66, 108, 111, 99, 107, 34, 58, 48, 44, 34, 98, 121, 122, 97, 110,
+        116, 105, 117, 109, 66, 108, 111, 99, 107, 34, 58, 48, 44, 34, 99, 111, 110, 115, 116, 97,
+        110, 116, 105, 110, 111, 112, 108, 101, 66, 108, 111, 99, 107, 34, 58, 48, 44, 34, 112,
+        101, 116, 101, 114, 115, 98, 117, 114, 103, 66, 108, 111, 99, 107, 34, 58, 48, 44, 34, 105,
+        115, 116, 97, 110, 98, 117, 108, 66, 108, 111, 99, 107, 34, 58, 48, 44, 34, 109, 117, 105,
+        114, 71, 108, 97, 99, 105, 101, 114, 66, 108, 111, 99, 107, 34, 58, 48, 125, 44, 34, 110,
+        111, 110, 99, 101, 34, 58, 34, 48, 120, 48, 34, 44, 34, 116, 105, 109, 101, 115, 116, 97,
+        109, 112, 34, 58, 34, 48, 120, 48, 34, 44, 34, 101, 120, 116, 114, 97, 68, 97, 116, 97, 34,
+        58, 34, 48, 120, 48, 48, 34, 44, 34, 103, 97, 115, 76, 105, 109, 105, 116, 34, 58, 34, 48,
+        120, 53, 102, 53, 101, 49, 48, 48, 34, 44, 34, 100, 105, 102, 102, 105, 99, 117, 108, 116,
+        121, 34, 58, 34, 48, 120, 48, 34, 44, 34, 109, 105, 120, 72, 97, 115, 104, 34, 58, 34, 48,
+        120, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
+        48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
+        48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 34, 44, 34,
+        99, 111, 105, 110, 98, 97, 115, 101, 34, 58, 34, 48, 120, 48, 48, 48, 48, 48, 48, 48, 48,
+        48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
+        48, 48, 48, 48, 48, 48, 48, 48, 48, 34, 44, 34, 97, 108, 108, 111, 99, 34, 58, 123, 34, 48,
+        49, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
+        48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 34, 58, 123, 34, 99, 111,
+        100, 101, 34, 58, 34, 48, 120, 55, 51, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
+        48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
+        48, 48, 48, 48, 51, 48, 49, 52, 54, 48, 56, 48, 54, 48, 52, 48, 53, 50, 54, 48, 48, 52, 51,
+        54, 49, 48, 54, 48, 51, 100, 53, 55, 54, 48, 48, 48, 51, 53, 54, 48, 101, 48, 49, 99, 56,
+        48, 54, 51, 49, 101, 48, 49, 48, 52, 51, 57, 49, 52, 54, 48, 52, 50, 53, 55, 56, 48, 54,
+        51, 98, 54, 53, 49, 48, 98, 98, 51, 49, 52, 54, 48, 54, 101, 53, 55, 53, 98, 54, 48, 48,
+        48, 56, 48, 102, 100, 53, 98, 54, 48, 53, 99, 54, 48, 48, 52, 56, 48, 51, 54, 48, 51, 54,
+        48, 50, 48, 56, 49, 49, 48, 49, 53, 54, 48, 53, 54, 53, 55, 54, 48, 48, 48, 56, 48, 102,
+        100, 53, 98, 53, 48, 51, 53, 54, 48, 98, 49, 53, 54, 53, 98, 54, 48, 52, 48, 56, 48, 53,
+        49, 57, 49, 56, 50, 53, 50, 53, 49, 57, 48, 56, 49, 57, 48, 48, 51, 54, 48, 50, 48, 48, 49,
+        57, 48, 102, 51, 53, 98, 56, 49, 56, 48, 49, 53, 54, 48, 55, 57, 53, 55, 54, 48, 48, 48,
+        56, 48, 102, 100, 53, 98, 53, 48, 54, 48, 97, 102, 54, 48, 48, 52, 56, 48, 51, 54, 48, 51,
+        54, 48, 56, 48, 56, 49, 49, 48, 49, 53, 54, 48, 56, 101, 53, 55, 54, 48, 48, 48, 56, 48,
+        102, 100, 53, 98, 53, 48, 54, 48, 48, 49, 54, 48, 48, 49, 54, 48, 97, 48, 49, 98, 48, 51,
+        56, 49, 51, 53, 49, 54, 57, 48, 54, 48, 50, 48, 56, 49, 48, 49, 51, 53, 57, 48, 54, 48, 52,
+        48, 56, 49, 48, 49, 51, 53, 57, 48, 54, 48, 54, 48, 48, 49, 51, 53, 54, 48, 98, 54, 53, 54,
+        53, 98, 48, 48, 53, 98, 51, 48, 99, 100, 57, 48, 53, 54, 53, 98, 56, 51, 54, 48, 48, 49,
+        54, 48, 48, 49, 54, 48, 97, 48, 49, 98, 48, 51, 49, 54, 56, 49, 56, 51, 54, 49, 48, 56,
+        102, 99, 56, 54, 57, 48, 56, 49, 49, 53, 48, 50, 57, 48, 54, 48, 52, 48, 53, 49, 54, 48,
+        48, 48, 54, 48, 52, 48, 53, 49, 56, 48, 56, 51, 48, 51, 56, 49, 56, 56, 56, 56, 56, 55, 56,
+        99, 56, 97, 99, 102, 57, 53, 53, 48, 53, 48, 53, 48, 53, 48, 53, 48, 53, 48, 49, 53, 56,
+        48, 49, 53, 54, 48, 102, 52, 53, 55, 51, 100, 54, 48, 48, 48, 56, 48, 51, 101, 51, 100, 54,
+        48, 48, 48, 102, 100, 53, 98, 53, 48, 53, 48, 53, 48, 53, 48, 53, 48, 53, 54, 102, 101, 97,
+        50, 54, 52, 54, 57, 55, 48, 54, 54, 55, 51, 53, 56, 50, 50, 49, 50, 50, 48, 49, 101, 101,
+        98, 99, 101, 57, 55, 48, 102, 101, 51, 102, 53, 99, 98, 57, 54, 98, 102, 56, 97, 99, 54,
+        98, 97, 53, 102, 53, 99, 49, 51, 51, 102, 99, 50, 57, 48, 56, 97, 101, 51, 100, 99, 100,
+        53, 49, 48, 56, 50, 99, 102, 101, 101, 56, 102, 53, 56, 51, 52, 50, 57, 100, 48, 54, 52,
+        55, 51, 54, 102, 54, 99, 54, 51, 52, 51, 48, 48, 48, 54, 48, 97, 48, 48, 51, 51, 34, 44,
+        34, 98, 97, 108, 97, 110, 99, 101, 34, 58, 34, 48, 120, 48, 34, 125, 125, 44, 34, 110, 117,
+        109, 98, 101, 114, 34, 58, 34, 48, 120, 48, 34, 44, 34, 103, 97, 115, 85, 115, 101, 100,
+        34, 58, 34, 48, 120, 48, 34, 44, 34, 112, 97, 114, 101, 110, 116, 72, 97, 115, 104, 34, 58,
+        34, 48, 120, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
+        48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
+        48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 34,
+        125, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0,
+    ])
+}
+
+/// A standard block creating a subnet owned by X-avax10xcqpzrky6eff2g52qdye53xkk9jxkvr5zh4e7 ,
+/// the address of the secret key `[1; 32]` which signs its tx .
+pub fn test_subnet_block() -> Block {
+    parse_block(&[
+        0, 0, 0, 0, 0, 3, 228, 113, 37, 150, 139, 59, 113, 4, 159, 188, 72, 2, 209, 228, 10, 113,
+        234, 19, 89, 222, 207, 171, 172, 247, 11, 52, 88, 128, 55, 212, 255, 12, 0, 0, 0, 0, 0, 0,
+        0, 100, 0, 0, 0, 1, 0, 0, 0, 16, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 33, 230, 115, 23, 203, 196,
+        190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145,
+        178, 48, 39, 168, 125, 255, 0, 0, 0, 7, 0, 0, 0, 0, 65, 144, 171, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 1, 0, 0, 0, 1, 121, 176, 0, 136, 118, 38, 178, 148, 169, 20, 80, 26, 76, 210,
+        38, 181, 139, 35, 89, 131, 0, 0, 0, 1, 37, 20, 225, 71, 90, 221, 255, 179, 120, 253, 176,
+        126, 154, 16, 146, 23, 108, 9, 219, 251, 209, 41, 235, 202, 172, 208, 9, 152, 24, 210, 83,
+        76, 0, 0, 0, 0, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120,
+        168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0,
+        0, 0, 125, 43, 117, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 121, 176, 0, 136, 118, 38, 178, 148, 169, 20, 80, 26, 76,
+        210, 38, 181, 139, 35, 89, 131, 0, 0, 0, 1, 0, 0, 0, 9, 0, 0, 0, 1, 114, 89, 104, 26, 30,
+        120, 194, 130, 90, 212, 39, 10, 130, 66, 33, 247, 242, 124, 157, 149, 84, 192, 7, 232, 57,
+        113, 136, 65, 249, 1, 87, 178, 19, 101, 182, 70, 151, 167, 89, 19, 32, 92, 159, 214, 113,
+        44, 255, 180, 43, 105, 195, 229, 111, 101, 64, 124, 93, 14, 108, 196, 5, 243, 195, 114, 0,
+    ])
+}
+
+/// The child of `test_subnet_block` , creating a blockchain in its subnet .
+/// The key `[1; 32]` signs both its input and its subnet auth .
+pub fn test_blockchain_block() -> Block {
+    parse_block(&[
+        0, 0, 0, 0, 0, 3, 231, 97, 138, 38, 196, 210, 61, 140, 176, 189, 28, 146, 180, 247, 221,
+        122, 103, 160, 100, 89, 79, 244, 155, 204, 250, 192, 41, 207, 7, 96, 174, 215, 0, 0, 0, 0,
+        0, 0, 0, 101, 0, 0, 0, 1, 0, 0, 0, 15, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 33, 230, 115, 23, 203,
+        196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37,
+        145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 7, 0, 0, 0, 0, 5, 245, 225, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 121, 176, 0, 136, 118, 38, 178, 148, 169, 20, 80, 26, 76,
+        210, 38, 181, 139, 35, 89, 131, 0, 0, 0, 1, 184, 33, 28, 58, 37, 43, 4, 158, 22, 114, 140,
+        58, 48, 36, 194, 65, 122, 191, 107, 50, 143, 5, 205, 4, 252, 65, 73, 136, 13, 203, 212,
+        113, 0, 0, 0, 0, 33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120,
+        168, 245, 34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255, 0, 0, 0, 5, 0, 0,
+        0, 0, 65, 144, 171, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 184, 33, 28, 58, 37, 43, 4, 158,
+        22, 114, 140, 58, 48, 36, 194, 65, 122, 191, 107, 50, 143, 5, 205, 4, 252, 65, 73, 136, 13,
+        203, 212, 113, 0, 10, 84, 101, 115, 116, 32, 67, 104, 97, 105, 110, 115, 117, 98, 110, 101,
+        116, 101, 118, 109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 28, 123, 34, 99, 111, 110, 102, 105, 103, 34, 58, 123, 34, 99, 104, 97,
+        105, 110, 73, 100, 34, 58, 57, 57, 57, 57, 57, 125, 125, 0, 0, 0, 10, 0, 0, 0, 1, 0, 0, 0,
+        0, 0, 0, 0, 2, 0, 0, 0, 9, 0, 0, 0, 1, 248, 243, 6, 163, 18, 13, 7, 128, 17, 186, 42, 244,
+        231, 214, 131, 255, 208, 89, 239, 64, 68, 87, 238, 21, 220, 117, 83, 69, 154, 35, 193, 159,
+        95, 217, 170, 188, 137, 169, 71, 243, 232, 195, 36, 34, 213, 125, 70, 229, 29, 109, 12, 62,
+        137, 173, 248, 254, 23, 113, 103, 19, 20, 234, 61, 147, 0, 0, 0, 0, 9, 0, 0, 0, 1, 248,
+        243, 6, 163, 18, 13, 7, 128, 17, 186, 42, 244, 231, 214, 131, 255, 208, 89, 239, 64, 68,
+        87, 238, 21, 220, 117, 83, 69, 154, 35, 193, 159, 95, 217, 170, 188, 137, 169, 71, 243,
+        232, 195, 36, 34, 213, 125, 70, 229, 29, 109, 12, 62, 137, 173, 248, 254, 23, 113, 103, 19,
+        20, 234, 61, 147, 0,
+    ])
+}
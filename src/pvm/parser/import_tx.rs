@@ -18,7 +18,6 @@ pub struct ImportTx {
 #[instrument(fields(block_id = % _context.tx_id, tx_type = "import"))]
 pub fn import_tx_parser(
     _raw_msg: &[u8],
-    _tx_id: String,
     _context: &mut Context,
 ) -> Result<Transaction, Box<dyn Error>> {
     let base_tx = base_tx_parser(_raw_msg, _context)?;
@@ -48,7 +47,7 @@ pub fn import_tx_parser(
 
     Ok(Transaction {
         base_tx,
-        tx_id: _tx_id,
+        tx_id: String::new(),
         add_validator_tx: None,
         import_tx: Some(import_tx),
         export_tx: None,
@@ -58,6 +57,7 @@ pub fn import_tx_parser(
         create_subnet_tx: None,
         advance_time_tx: None,
         reward_validator_tx: None,
+        unsigned_tx: vec![],
         credentials: vec![],
     })
 }
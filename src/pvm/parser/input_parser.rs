@@ -6,7 +6,8 @@ use std::error::Error;
 
 use crate::avm::parser::Context;
 use crate::pvm::parser::output_parser::StakeableLockedInput;
-use crate::utils::conversion::{pop_i32, pop_i64};
+use crate::utils::amount::Amount;
+use crate::utils::conversion::{pop_i32, pop_i64, pop_u64};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Input {
@@ -18,7 +19,7 @@ pub struct Input {
 #[derive(Serialize, Deserialize, Debug)]
 pub struct SECP256KTransferInput {
     pub type_id: i32,
-    pub amount: i64,
+    pub amount: Amount,
     pub address_indices: Vec<i32>,
 }
 
@@ -59,7 +60,9 @@ pub fn secp256k1_transfer_input_parser<'a>(
     _context: &mut Context,
 ) -> Result<SECP256KTransferInput, Box<dyn Error>> {
     // Amount
-    let amount = pop_i64(_raw_msg[*_context.offset..=(*_context.offset + 7)].borrow());
+    let amount = Amount(pop_u64(
+        _raw_msg[*_context.offset..=(*_context.offset + 7)].borrow(),
+    ));
     trace!("Amount : {:?}", amount);
     *_context.offset += 8;
 
@@ -4,17 +4,21 @@ pub mod add_subnet_validator_tx;
 pub mod add_validator_tx;
 pub mod advance_time_tx_parser;
 pub mod atomic_block_parser;
+pub mod banff_block_parser;
 pub mod base_tx_parser;
 pub mod block_parser;
 pub mod commit_block_parser;
 pub mod create_blockchain_tx;
 pub mod create_subnet_tx;
 pub mod export_tx_parser;
+#[cfg(test)]
+pub mod fixtures;
 pub mod import_tx;
 pub mod input_parser;
 pub mod output_parser;
 pub mod proposal_block_parser;
 pub mod reward_validator_tx_parser;
 pub mod standard_block_parser;
+pub mod subnet_auth_parser;
 pub mod transferable_input_parser;
 pub mod transferable_output_parser;
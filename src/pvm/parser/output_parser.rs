@@ -5,10 +5,11 @@ use std::borrow::Borrow;
 
 use crate::avm::parser::Context;
 use crate::pvm::parser::input_parser::SECP256KTransferInput;
-use crate::utils::conversion::{pop_i32, pop_i64};
+use crate::utils::amount::Amount;
+use crate::utils::conversion::{pop_i32, pop_i64, pop_u64};
 use std::error::Error;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Output {
     pub type_id: i32,
     pub stakeable_locked_output: Option<StakeableLockedOutput>,
@@ -23,16 +24,16 @@ pub struct StakeableLockedInput {
     pub input: SECP256KTransferInput,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct StakeableLockedOutput {
     pub locktime: i64,
     pub secp256k_transfer_output: Option<SECP256KTransferOutput>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SECP256KTransferOutput {
     pub type_id: i32,
-    pub amount: Option<i64>,
+    pub amount: Option<Amount>,
     pub locktime: i64,
     pub threshold: i32,
     pub addresses: Vec<String>,
@@ -77,7 +78,9 @@ pub fn secp256k1_transfer_output_parser(
     _context: &mut Context,
 ) -> Result<SECP256KTransferOutput, Box<dyn Error>> {
     // Amount
-    let amount = pop_i64(_raw_msg[*_context.offset..=(*_context.offset + 7)].borrow());
+    let amount = Amount(pop_u64(
+        _raw_msg[*_context.offset..=(*_context.offset + 7)].borrow(),
+    ));
     trace!("Amount : {:?}", amount);
     *_context.offset += 8;
 
@@ -15,7 +15,6 @@ pub struct RewardValidatorTx {
 #[instrument(fields(block_id = % _context.tx_id, tx_type = "reward_validator"))]
 pub fn reward_validator_parser(
     _raw_msg: &[u8],
-    _tx_id: String,
     _context: &mut Context,
 ) -> Result<Transaction, Box<dyn Error>> {
     // For the codec id
@@ -40,7 +39,7 @@ pub fn reward_validator_parser(
             transferable_inputs: vec![],
             memo: vec![],
         },
-        tx_id: _tx_id,
+        tx_id: String::new(),
         add_validator_tx: None,
         import_tx: None,
         export_tx: None,
@@ -50,6 +49,7 @@ pub fn reward_validator_parser(
         create_subnet_tx: None,
         advance_time_tx: None,
         reward_validator_tx: Some(reward_validator),
+        unsigned_tx: vec![],
         credentials: vec![],
     })
 }
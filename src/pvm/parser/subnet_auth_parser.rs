@@ -0,0 +1,50 @@
+use tracing::{instrument, trace};
+
+use std::borrow::Borrow;
+use std::error::Error;
+
+use crate::avm::parser::Context;
+use crate::utils::conversion::pop_i32;
+
+/// The owners of a subnet authorizing a tx , by their index in the subnet's owners .
+/// The matching signatures are in the last credential of the tx .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SubnetAuth {
+    pub type_id: i32,
+    pub sig_indices: Vec<i32>,
+}
+
+#[instrument(skip(_raw_msg), fields(tx_id = % _context.tx_id))]
+pub fn subnet_auth_parser(
+    _raw_msg: &[u8],
+    _context: &mut Context,
+) -> Result<SubnetAuth, Box<dyn Error>> {
+    let type_id = pop_i32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+    trace!("Subnet Auth TypeId : {:?}", type_id);
+
+    *_context.offset += 4;
+
+    let number_of_sig_indices =
+        pop_i32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+    trace!("Number of sig indices : {:?}", number_of_sig_indices);
+
+    *_context.offset += 4;
+
+    let mut sig_indices = Vec::new();
+    let mut index = 0;
+
+    while index < number_of_sig_indices {
+        trace!("Sig Indice number {}", index);
+        sig_indices.push(pop_i32(
+            _raw_msg[*_context.offset..=(*_context.offset + 3)].borrow(),
+        ));
+        *_context.offset += 4;
+
+        index += 1;
+    }
+
+    Ok(SubnetAuth {
+        type_id,
+        sig_indices,
+    })
+}
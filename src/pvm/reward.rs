@@ -0,0 +1,259 @@
+use std::convert::TryFrom;
+use std::error::Error;
+
+use crate::pvm::parser::add_delegator_tx::AddDelegatorTx;
+use crate::pvm::parser::add_validator_tx::AddValidatorTx;
+use crate::utils::amount::Amount;
+
+/// Consumption rates and delegation shares are expressed in millionths .
+pub const PERCENT_DENOMINATOR: u64 = 1_000_000;
+
+/// The parameters of the minting formula of a network .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RewardConfig {
+    /// The rate of the remaining supply minted for a stake locked for the whole minting period
+    pub max_consumption_rate: u64,
+    /// The rate of the remaining supply minted for a stake locked for a very short time
+    pub min_consumption_rate: u64,
+    /// In seconds
+    pub minting_period: u64,
+    pub supply_cap: Amount,
+}
+
+impl RewardConfig {
+    pub fn mainnet() -> RewardConfig {
+        RewardConfig {
+            max_consumption_rate: 120_000,
+            min_consumption_rate: 100_000,
+            minting_period: 365 * 24 * 60 * 60,
+            supply_cap: Amount(720_000_000_000_000_000),
+        }
+    }
+
+    /// Fuji mints with the same parameters as mainnet .
+    pub fn fuji() -> RewardConfig {
+        RewardConfig::mainnet()
+    }
+
+    /// Return the reward config of the known network matching the network id found in a tx .
+    pub fn from_network_id(_network_id: u32) -> Option<RewardConfig> {
+        match _network_id {
+            1 => Some(RewardConfig::mainnet()),
+            5 => Some(RewardConfig::fuji()),
+            _ => None,
+        }
+    }
+
+    /// The reward of `_staked_amount` locked for `_staked_duration` seconds , when `_current_supply` is in circulation .
+    /// It is the share of the remaining supply AvalancheGo mints , with the same rounding .
+    pub fn calculate(
+        &self,
+        _staked_duration: u64,
+        _staked_amount: Amount,
+        _current_supply: Amount,
+    ) -> Result<Amount, Box<dyn Error>> {
+        let remaining_supply = self
+            .supply_cap
+            .checked_sub(_current_supply)
+            .ok_or_else(|| {
+                format!(
+                    "Current supply {} exceeds the supply cap {}",
+                    _current_supply, self.supply_cap
+                )
+            })?;
+        if _current_supply.is_zero() || self.minting_period == 0 {
+            return Err("Current supply and minting period cannot be zero".into());
+        }
+
+        // The consumption rate grows linearly with the staked duration , from min to max
+        let consumption_rate_numerator = u128::from(
+            self.max_consumption_rate
+                .saturating_sub(self.min_consumption_rate),
+        ) * u128::from(_staked_duration)
+            + u128::from(self.min_consumption_rate) * u128::from(self.minting_period);
+        let consumption_rate_numerator =
+            u64::try_from(consumption_rate_numerator).map_err(|_| "Consumption rate overflows")?;
+
+        let mut reward = BigUint::from(remaining_supply.0);
+        reward.mul(consumption_rate_numerator);
+        reward.mul(_staked_amount.0);
+        reward.mul(_staked_duration);
+        reward.div(self.minting_period);
+        reward.div(PERCENT_DENOMINATOR);
+        reward.div(_current_supply.0);
+        reward.div(self.minting_period);
+
+        // Never mint more than what is left
+        Ok(Amount(
+            reward
+                .to_u64()
+                .map_or(remaining_supply.0, |reward| reward.min(remaining_supply.0)),
+        ))
+    }
+}
+
+/// The reward of a delegation , split between the delegator and the validator's fee .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DelegationReward {
+    pub delegator: Amount,
+    pub validator_fee: Amount,
+}
+
+/// Split a delegation reward , the validator keeps `_shares` millionths of it .
+pub fn split(_reward: Amount, _shares: u32) -> Result<DelegationReward, Box<dyn Error>> {
+    let shares = u64::from(_shares);
+    if shares > PERCENT_DENOMINATOR {
+        return Err(format!("Shares {} exceed {}", shares, PERCENT_DENOMINATOR).into());
+    }
+
+    let delegator = u128::from(PERCENT_DENOMINATOR - shares) * u128::from(_reward.0)
+        / u128::from(PERCENT_DENOMINATOR);
+    // Cannot overflow , it is at most the reward
+    let delegator = Amount(delegator as u64);
+
+    Ok(DelegationReward {
+        delegator,
+        validator_fee: Amount(_reward.0 - delegator.0),
+    })
+}
+
+impl AddValidatorTx {
+    pub fn expected_reward(
+        &self,
+        _config: &RewardConfig,
+        _current_supply: Amount,
+    ) -> Result<Amount, Box<dyn Error>> {
+        _config.calculate(
+            staked_duration(self.start_time, self.end_time)?,
+            self.weight,
+            _current_supply,
+        )
+    }
+}
+
+impl AddDelegatorTx {
+    /// `_validator` is the tx which added the validator the stake is delegated to .
+    pub fn expected_reward(
+        &self,
+        _validator: &AddValidatorTx,
+        _config: &RewardConfig,
+        _current_supply: Amount,
+    ) -> Result<DelegationReward, Box<dyn Error>> {
+        let reward = _config.calculate(
+            staked_duration(self.start_time, self.end_time)?,
+            self.weight,
+            _current_supply,
+        )?;
+
+        split(reward, _validator.shares as u32)
+    }
+}
+
+fn staked_duration(_start_time: i64, _end_time: i64) -> Result<u64, Box<dyn Error>> {
+    u64::try_from(_end_time - _start_time).map_err(|_| {
+        format!(
+            "End time {} is before start time {}",
+            _end_time, _start_time
+        )
+        .into()
+    })
+}
+
+/// Just enough of an unsigned big integer for the minting formula , whose products exceed 128 bits .
+struct BigUint {
+    /// Little endian
+    limbs: Vec<u64>,
+}
+
+impl BigUint {
+    fn from(_value: u64) -> BigUint {
+        BigUint {
+            limbs: vec![_value],
+        }
+    }
+
+    fn mul(&mut self, _factor: u64) {
+        let mut carry = 0u128;
+        for limb in self.limbs.iter_mut() {
+            let product = u128::from(*limb) * u128::from(_factor) + carry;
+            *limb = product as u64;
+            carry = product >> 64;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u64);
+        }
+    }
+
+    fn div(&mut self, _divisor: u64) {
+        let mut remainder = 0u128;
+        for limb in self.limbs.iter_mut().rev() {
+            let dividend = (remainder << 64) | u128::from(*limb);
+            *limb = (dividend / u128::from(_divisor)) as u64;
+            remainder = dividend % u128::from(_divisor);
+        }
+        while self.limbs.len() > 1 && self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        match self.limbs.as_slice() {
+            [value] => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calculate_01() {
+        let config = RewardConfig::mainnet();
+        let current_supply = Amount(360_000_000_000_000_000);
+
+        let reward = config
+            .calculate(
+                config.minting_period,
+                Amount(2_000_000_000_000),
+                current_supply,
+            )
+            .unwrap();
+        assert_eq!(
+            reward,
+            Amount(240_000_000_000),
+            "Checking if a year long stake earns the max consumption rate"
+        );
+        assert_eq!(
+            config
+                .calculate(
+                    14 * 24 * 60 * 60,
+                    Amount(25_000_000_000),
+                    Amount(400_000_000_000_000_000)
+                )
+                .unwrap(),
+            Amount(77_300_806),
+            "Checking if the reward is rounded like AvalancheGo"
+        );
+
+        assert_eq!(
+            split(reward, 20_000).unwrap(),
+            DelegationReward {
+                delegator: Amount(235_200_000_000),
+                validator_fee: Amount(4_800_000_000),
+            },
+            "Checking if the validator keeps its shares"
+        );
+        assert!(
+            split(reward, 1_000_001).is_err(),
+            "Checking if shares above 100% are rejected"
+        );
+        assert!(
+            config
+                .calculate(1, Amount(1), Amount(800_000_000_000_000_000))
+                .is_err(),
+            "Checking if a supply above the cap is rejected"
+        );
+    }
+}
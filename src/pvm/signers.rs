@@ -0,0 +1,130 @@
+use std::error::Error;
+
+use crate::avm::parser::credential_parser::Credential;
+use crate::pvm::parser::atomic_block_parser::Transaction;
+use crate::pvm::parser::transferable_input_parser::TransferableInput;
+use crate::utxo::signers::{resolve_input_signers, InputSigners, UtxoLookup};
+use crate::utxo::utxo_id::UtxoId;
+
+impl Transaction {
+    /// Resolve every input of the tx to the addresses which had to sign it .
+    /// Credentials follow the inputs of the base tx , then the imported inputs .
+    /// The subnet auth credential of subnet txs does not spend a UTXO and is left out .
+    pub fn resolve_signers(
+        &self,
+        _utxos: &dyn UtxoLookup,
+    ) -> Result<Vec<InputSigners>, Box<dyn Error>> {
+        let mut resolved = Vec::new();
+
+        resolve_inputs(
+            &mut resolved,
+            _utxos,
+            &self.base_tx.transferable_inputs,
+            &self.credentials,
+        )?;
+        if let Some(import_tx) = &self.import_tx {
+            let offset = self.base_tx.transferable_inputs.len();
+            resolve_inputs(
+                &mut resolved,
+                _utxos,
+                &import_tx.transferable_inputs,
+                self.credentials.get(offset..).unwrap_or_default(),
+            )?;
+        }
+
+        Ok(resolved)
+    }
+}
+
+fn resolve_inputs(
+    _resolved: &mut Vec<InputSigners>,
+    _utxos: &dyn UtxoLookup,
+    _inputs: &[TransferableInput],
+    _credentials: &[Credential],
+) -> Result<(), Box<dyn Error>> {
+    for (index, transferable_input) in _inputs.iter().enumerate() {
+        let input = &transferable_input.input;
+        let address_indices = match &input.stakeable_locked_input {
+            Some(stakeable_locked_input) => &stakeable_locked_input.input.address_indices,
+            None => match &input.secp256k_transfer_input {
+                Some(secp256k_transfer_input) => &secp256k_transfer_input.address_indices,
+                None => continue,
+            },
+        };
+        let address_indices: Vec<u32> = address_indices
+            .iter()
+            .map(|address_index| *address_index as u32)
+            .collect();
+        _resolved.push(resolve_input_signers(
+            _utxos,
+            &UtxoId::from(transferable_input),
+            &address_indices,
+            _credentials.get(index),
+        )?);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pvm::parser::fixtures::create_subnet_block;
+    use crate::pvm::parser::output_parser::{Output, SECP256KTransferOutput};
+    use crate::utxo::utxo_id::UtxoId;
+    use crate::utxo::utxo_parser::Utxo;
+    use std::collections::HashMap;
+
+    #[test]
+    fn resolve_signers_01() {
+        let mut block = create_subnet_block();
+        let transaction = block.block_data.transactions.remove(0).unwrap();
+
+        let mut utxos = HashMap::new();
+        for (input, address) in transaction.base_tx.transferable_inputs.iter().zip(&[
+            "X-avax13gfmq9cuw5ph2ce67fusfd6gtp0awln7px5mh7",
+            "X-avax1fuuttly42kw7hdnlwc5sr2mqeajvcf7ejmscyr",
+        ]) {
+            let utxo_id = UtxoId::from(input);
+            utxos.insert(
+                utxo_id.clone(),
+                Utxo {
+                    codec_id: 0,
+                    utxo_id: utxo_id.input_id().unwrap(),
+                    tx_id: input.tx_id.clone(),
+                    output_index: input.utxo_index,
+                    asset_id: input.asset_id.clone(),
+                    avm_output: None,
+                    pvm_output: Some(Output {
+                        type_id: 7,
+                        stakeable_locked_output: None,
+                        secp256k1_transfer_output: Some(SECP256KTransferOutput {
+                            type_id: 7,
+                            amount: None,
+                            locktime: 0,
+                            threshold: 1,
+                            addresses: vec![address.to_string()],
+                        }),
+                        secp256k1_owner_output: None,
+                    }),
+                },
+            );
+        }
+
+        let resolved = transaction.resolve_signers(&utxos).unwrap();
+        assert_eq!(
+            resolved
+                .iter()
+                .map(|input_signers| input_signers.signers[0].address.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "X-avax13gfmq9cuw5ph2ce67fusfd6gtp0awln7px5mh7",
+                "X-avax1fuuttly42kw7hdnlwc5sr2mqeajvcf7ejmscyr",
+            ],
+            "Checking if each input is resolved to the owner of its UTXO"
+        );
+        assert_eq!(
+            resolved[1].signers[0].signature, transaction.credentials[1].signatures[0],
+            "Checking if each input takes the credential at its index"
+        );
+    }
+}
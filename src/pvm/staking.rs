@@ -0,0 +1,623 @@
+use crate::pvm::parser::add_delegator_tx::AddDelegatorTx;
+use crate::pvm::parser::add_subnet_validator_tx::AddSubnetValidatorTx;
+use crate::pvm::parser::add_validator_tx::AddValidatorTx;
+use crate::pvm::reward::PERCENT_DENOMINATOR;
+use crate::pvm::validator_set::{Staker, StakerKind, ValidatorSet, PRIMARY_NETWORK_ID};
+use crate::utils::amount::Amount;
+use crate::utils::syntax::{violation, SyntaxViolation};
+
+/// The staking parameters a network enforces on the primary network stakers .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StakingRules {
+    pub min_validator_stake: Amount,
+    pub max_validator_stake: Amount,
+    pub min_delegator_stake: Amount,
+    /// In millionths
+    pub min_delegation_fee: u32,
+    /// In seconds
+    pub min_stake_duration: i64,
+    pub max_stake_duration: i64,
+    /// The weight of a validator , delegations included , is capped at this factor of its own stake
+    pub max_validator_weight_factor: u64,
+}
+
+impl StakingRules {
+    pub fn mainnet() -> StakingRules {
+        StakingRules {
+            min_validator_stake: Amount(2_000_000_000_000),
+            max_validator_stake: Amount(3_000_000_000_000_000),
+            min_delegator_stake: Amount(25_000_000_000),
+            min_delegation_fee: 20_000,
+            min_stake_duration: 14 * 24 * 60 * 60,
+            max_stake_duration: 365 * 24 * 60 * 60,
+            max_validator_weight_factor: 5,
+        }
+    }
+
+    pub fn fuji() -> StakingRules {
+        StakingRules {
+            min_validator_stake: Amount(1_000_000_000),
+            min_delegator_stake: Amount(1_000_000_000),
+            min_stake_duration: 24 * 60 * 60,
+            ..StakingRules::mainnet()
+        }
+    }
+
+    /// Return the staking rules of the known network matching the network id found in a tx .
+    pub fn from_network_id(_network_id: u32) -> Option<StakingRules> {
+        match _network_id {
+            1 => Some(StakingRules::mainnet()),
+            5 => Some(StakingRules::fuji()),
+            _ => None,
+        }
+    }
+
+    fn verify_duration(
+        &self,
+        _violations: &mut Vec<SyntaxViolation>,
+        _start_time: i64,
+        _end_time: i64,
+    ) {
+        let duration = _end_time - _start_time;
+        if duration < self.min_stake_duration {
+            violation(
+                _violations,
+                "end_time",
+                &format!(
+                    "staking period is shorter than {} seconds",
+                    self.min_stake_duration
+                ),
+            );
+        }
+        if duration > self.max_stake_duration {
+            violation(
+                _violations,
+                "end_time",
+                &format!(
+                    "staking period is longer than {} seconds",
+                    self.max_stake_duration
+                ),
+            );
+        }
+    }
+}
+
+impl AddValidatorTx {
+    /// Check the staking rules , against the validators already known .
+    /// An empty list means the validator can be added .
+    pub fn verify_staking(
+        &self,
+        _rules: &StakingRules,
+        _validators: &ValidatorSet,
+    ) -> Vec<SyntaxViolation> {
+        let mut violations = Vec::new();
+
+        if self.weight < _rules.min_validator_stake {
+            violation(
+                &mut violations,
+                "weight",
+                &format!("stake is lower than {}", _rules.min_validator_stake),
+            );
+        }
+        if self.weight > _rules.max_validator_stake {
+            violation(
+                &mut violations,
+                "weight",
+                &format!("stake is higher than {}", _rules.max_validator_stake),
+            );
+        }
+        if self.shares < 0 || self.shares as u64 > PERCENT_DENOMINATOR {
+            violation(
+                &mut violations,
+                "shares",
+                &format!(
+                    "delegation fee is not between 0 and {}",
+                    PERCENT_DENOMINATOR
+                ),
+            );
+        } else if (self.shares as u32) < _rules.min_delegation_fee {
+            violation(
+                &mut violations,
+                "shares",
+                &format!("delegation fee is lower than {}", _rules.min_delegation_fee),
+            );
+        }
+        _rules.verify_duration(&mut violations, self.start_time, self.end_time);
+        verify_start_time(&mut violations, self.start_time, _validators);
+
+        if primary_validator(_validators, &self.node_id).is_some() {
+            violation(
+                &mut violations,
+                "node_id",
+                "node already validates the primary network",
+            );
+        }
+
+        violations
+    }
+}
+
+impl AddDelegatorTx {
+    /// Check the staking rules , against the validator the stake is delegated to .
+    /// An empty list means the delegator can be added .
+    pub fn verify_staking(
+        &self,
+        _rules: &StakingRules,
+        _validators: &ValidatorSet,
+    ) -> Vec<SyntaxViolation> {
+        let mut violations = Vec::new();
+
+        if self.weight < _rules.min_delegator_stake {
+            violation(
+                &mut violations,
+                "weight",
+                &format!("stake is lower than {}", _rules.min_delegator_stake),
+            );
+        }
+        _rules.verify_duration(&mut violations, self.start_time, self.end_time);
+        verify_start_time(&mut violations, self.start_time, _validators);
+
+        let validator = match primary_validator(_validators, &self.node_id) {
+            Some(validator) => validator,
+            None => {
+                violation(
+                    &mut violations,
+                    "node_id",
+                    "node does not validate the primary network",
+                );
+                return violations;
+            }
+        };
+        verify_within(&mut violations, self.start_time, self.end_time, validator);
+
+        // The delegations staking at the same time as this one count against the cap
+        let delegations: Vec<&Staker> = _validators
+            .stakers_of(&self.node_id, PRIMARY_NETWORK_ID)
+            .into_iter()
+            .filter(|staker| staker.kind == StakerKind::Delegator)
+            .collect();
+        let delegated = peak_delegated_weight(&delegations, self.start_time, self.end_time)
+            + u128::from(self.weight.0);
+        let max_weight = (u128::from(validator.weight.0)
+            * u128::from(_rules.max_validator_weight_factor))
+        .min(u128::from(_rules.max_validator_stake.0));
+        if u128::from(validator.weight.0) + delegated > max_weight {
+            violation(
+                &mut violations,
+                "weight",
+                &format!(
+                    "validator weight would exceed {} times its stake",
+                    _rules.max_validator_weight_factor
+                ),
+            );
+        }
+
+        violations
+    }
+}
+
+impl AddSubnetValidatorTx {
+    /// Check the node validates the primary network for the whole period .
+    /// An empty list means the subnet validator can be added .
+    pub fn verify_staking(
+        &self,
+        _rules: &StakingRules,
+        _validators: &ValidatorSet,
+    ) -> Vec<SyntaxViolation> {
+        let mut violations = Vec::new();
+
+        if self.weight.is_zero() {
+            violation(&mut violations, "weight", "weight cannot be zero");
+        }
+        _rules.verify_duration(&mut violations, self.start_time, self.end_time);
+        verify_start_time(&mut violations, self.start_time, _validators);
+
+        match primary_validator(_validators, &self.node_id) {
+            Some(validator) => {
+                verify_within(&mut violations, self.start_time, self.end_time, validator)
+            }
+            None => violation(
+                &mut violations,
+                "node_id",
+                "node does not validate the primary network",
+            ),
+        }
+        if _validators
+            .stakers_of(&self.node_id, &self.subnet_id)
+            .iter()
+            .any(|staker| staker.kind == StakerKind::SubnetValidator)
+        {
+            violation(
+                &mut violations,
+                "node_id",
+                "node already validates the subnet",
+            );
+        }
+
+        violations
+    }
+}
+
+fn primary_validator<'a>(_validators: &'a ValidatorSet, _node_id: &str) -> Option<&'a Staker> {
+    _validators
+        .stakers_of(_node_id, PRIMARY_NETWORK_ID)
+        .into_iter()
+        .find(|staker| staker.kind == StakerKind::Validator)
+}
+
+fn verify_start_time(
+    _violations: &mut Vec<SyntaxViolation>,
+    _start_time: i64,
+    _validators: &ValidatorSet,
+) {
+    if _start_time <= _validators.chain_time {
+        violation(
+            _violations,
+            "start_time",
+            &format!(
+                "start time is not after chain time {}",
+                _validators.chain_time
+            ),
+        );
+    }
+}
+
+/// The highest weight the delegations reach at once between `_start_time` and `_end_time` .
+fn peak_delegated_weight(_delegations: &[&Staker], _start_time: i64, _end_time: i64) -> u128 {
+    // A delegation adds its weight at its start and removes it at its end , removals come first
+    let mut events: Vec<(i64, bool, u128)> = Vec::new();
+    for delegation in _delegations {
+        if delegation.start_time < _end_time && delegation.end_time > _start_time {
+            let weight = u128::from(delegation.weight.0);
+            events.push((delegation.start_time.max(_start_time), true, weight));
+            events.push((delegation.end_time, false, weight));
+        }
+    }
+    events.sort_by_key(|(time, added, _)| (*time, *added));
+
+    let mut delegated = 0;
+    let mut peak = 0;
+    for (_, added, weight) in events {
+        if added {
+            delegated += weight;
+            peak = peak.max(delegated);
+        } else {
+            delegated -= weight;
+        }
+    }
+
+    peak
+}
+
+/// Delegators and subnet validators must stake inside the period of their primary network validator .
+fn verify_within(
+    _violations: &mut Vec<SyntaxViolation>,
+    _start_time: i64,
+    _end_time: i64,
+    _validator: &Staker,
+) {
+    let message = format!(
+        "staking period is not within the validator period {} - {}",
+        _validator.start_time, _validator.end_time
+    );
+    if _start_time < _validator.start_time {
+        violation(_violations, "start_time", &message);
+    }
+    if _end_time > _validator.end_time {
+        violation(_violations, "end_time", &message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pvm::parser::output_parser::SECP256KTransferOutput;
+    use crate::pvm::parser::subnet_auth_parser::SubnetAuth;
+    use crate::pvm::validator_set::StakerChange;
+
+    const AVAX: u64 = 1_000_000_000;
+    const DAY: i64 = 24 * 60 * 60;
+    const VALIDATOR_START: i64 = 1_606_770_000;
+    const VALIDATOR_END: i64 = VALIDATOR_START + 200 * DAY;
+
+    fn reward_owner() -> SECP256KTransferOutput {
+        SECP256KTransferOutput {
+            type_id: 11,
+            amount: None,
+            locktime: 0,
+            threshold: 1,
+            addresses: vec!["P-avax1ptek50w4urnujl8w8g63hhwzyacty0jjxcx4sh".to_string()],
+        }
+    }
+
+    /// NodeA validates 2_000 AVAX , so it can take up to 8_000 AVAX of delegations at once .
+    fn with_validator() -> ValidatorSet {
+        let mut validators = ValidatorSet::default();
+        validators
+            .apply_change(StakerChange::Add(Staker {
+                tx_id: "validator_tx".to_string(),
+                node_id: "NodeA".to_string(),
+                subnet_id: PRIMARY_NETWORK_ID.to_string(),
+                kind: StakerKind::Validator,
+                start_time: VALIDATOR_START,
+                end_time: VALIDATOR_END,
+                weight: Amount(2_000 * AVAX),
+                started_at: None,
+            }))
+            .unwrap();
+
+        validators
+    }
+
+    fn paths(_violations: &[SyntaxViolation]) -> Vec<&str> {
+        _violations
+            .iter()
+            .map(|violation| violation.path.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn verify_staking_01() {
+        let rules = StakingRules::mainnet();
+        let validators = with_validator();
+
+        let mut subnet_validator_tx = AddSubnetValidatorTx {
+            node_id: "NodeA".to_string(),
+            start_time: VALIDATOR_START + DAY,
+            end_time: VALIDATOR_END,
+            weight: Amount(20),
+            subnet_id: "subnet".to_string(),
+            subnet_auth: SubnetAuth {
+                type_id: 10,
+                sig_indices: vec![0],
+            },
+        };
+        assert!(
+            subnet_validator_tx
+                .verify_staking(&rules, &validators)
+                .is_empty(),
+            "Checking if a subnet validator inside the validator period is valid"
+        );
+
+        subnet_validator_tx.node_id = "NodeB".to_string();
+        subnet_validator_tx.end_time = VALIDATOR_START + DAY + 1;
+        assert_eq!(
+            paths(&subnet_validator_tx.verify_staking(&rules, &validators)),
+            vec!["end_time", "node_id"],
+            "Checking if each violated rule is reported"
+        );
+
+        subnet_validator_tx.end_time = subnet_validator_tx.start_time + 366 * DAY;
+        assert_eq!(
+            paths(&subnet_validator_tx.verify_staking(&rules, &validators)),
+            vec!["end_time", "node_id"],
+            "Checking if a subnet validator staking for more than a year is rejected"
+        );
+    }
+
+    #[test]
+    fn verify_staking_02() {
+        let rules = StakingRules::mainnet();
+        let validators = with_validator();
+        let validator_tx =
+            |_node_id: &str, _weight: u64, _shares: i32, _duration: i64| AddValidatorTx {
+                node_id: _node_id.to_string(),
+                start_time: VALIDATOR_START,
+                end_time: VALIDATOR_START + _duration,
+                weight: Amount(_weight),
+                stake: vec![],
+                reward_owner: reward_owner(),
+                shares: _shares,
+            };
+
+        assert!(
+            validator_tx("NodeB", 2_000 * AVAX, 20_000, 14 * DAY)
+                .verify_staking(&rules, &validators)
+                .is_empty(),
+            "Checking if a validator at the minimums is valid"
+        );
+        assert!(
+            validator_tx("NodeB", 3_000_000 * AVAX, 1_000_000, 365 * DAY)
+                .verify_staking(&rules, &validators)
+                .is_empty(),
+            "Checking if a validator at the maximums is valid"
+        );
+        assert_eq!(
+            paths(
+                &validator_tx("NodeB", 2_000 * AVAX - 1, 20_000, 14 * DAY)
+                    .verify_staking(&rules, &validators)
+            ),
+            vec!["weight"],
+            "Checking if a stake lower than 2_000 AVAX is rejected"
+        );
+        assert_eq!(
+            paths(
+                &validator_tx("NodeB", 3_000_000 * AVAX + 1, 20_000, 14 * DAY)
+                    .verify_staking(&rules, &validators)
+            ),
+            vec!["weight"],
+            "Checking if a stake higher than 3_000_000 AVAX is rejected"
+        );
+        assert_eq!(
+            paths(
+                &validator_tx("NodeB", 2_000 * AVAX, 19_999, 14 * DAY)
+                    .verify_staking(&rules, &validators)
+            ),
+            vec!["shares"],
+            "Checking if a delegation fee lower than 2 % is rejected"
+        );
+        assert_eq!(
+            paths(
+                &validator_tx("NodeB", 2_000 * AVAX, 1_000_001, 14 * DAY)
+                    .verify_staking(&rules, &validators)
+            ),
+            vec!["shares"],
+            "Checking if a delegation fee higher than 100 % is rejected"
+        );
+        assert_eq!(
+            paths(
+                &validator_tx("NodeB", 2_000 * AVAX, 20_000, 14 * DAY - 1)
+                    .verify_staking(&rules, &validators)
+            ),
+            vec!["end_time"],
+            "Checking if a staking period shorter than 2 weeks is rejected"
+        );
+        assert_eq!(
+            paths(
+                &validator_tx("NodeB", 2_000 * AVAX, 20_000, 365 * DAY + 1)
+                    .verify_staking(&rules, &validators)
+            ),
+            vec!["end_time"],
+            "Checking if a staking period longer than a year is rejected"
+        );
+        assert_eq!(
+            paths(
+                &validator_tx("NodeA", 2_000 * AVAX, 20_000, 14 * DAY)
+                    .verify_staking(&rules, &validators)
+            ),
+            vec!["node_id"],
+            "Checking if a node validating already is rejected"
+        );
+
+        let mut validators = with_validator();
+        validators.chain_time = VALIDATOR_START;
+        assert_eq!(
+            paths(
+                &validator_tx("NodeB", 2_000 * AVAX, 20_000, 14 * DAY)
+                    .verify_staking(&rules, &validators)
+            ),
+            vec!["start_time"],
+            "Checking if a start time not after the chain time is rejected"
+        );
+    }
+
+    #[test]
+    fn verify_staking_03() {
+        let rules = StakingRules::mainnet();
+        let mut validators = with_validator();
+        // Two delegations of 5_000 AVAX , one after the other
+        for (tx_id, start_time, end_time) in [
+            (
+                "delegator_tx_1",
+                VALIDATOR_START,
+                VALIDATOR_START + 50 * DAY,
+            ),
+            ("delegator_tx_2", VALIDATOR_START + 50 * DAY, VALIDATOR_END),
+        ] {
+            validators
+                .apply_change(StakerChange::Add(Staker {
+                    tx_id: tx_id.to_string(),
+                    node_id: "NodeA".to_string(),
+                    subnet_id: PRIMARY_NETWORK_ID.to_string(),
+                    kind: StakerKind::Delegator,
+                    start_time,
+                    end_time,
+                    weight: Amount(5_000 * AVAX),
+                    started_at: None,
+                }))
+                .unwrap();
+        }
+        let delegator_tx =
+            |_node_id: &str, _weight: u64, _start_time: i64, _end_time: i64| AddDelegatorTx {
+                node_id: _node_id.to_string(),
+                start_time: _start_time,
+                end_time: _end_time,
+                weight: Amount(_weight),
+                stake: vec![],
+                reward_owner: reward_owner(),
+            };
+
+        assert!(
+            delegator_tx("NodeA", 3_000 * AVAX, VALIDATOR_START, VALIDATOR_END)
+                .verify_staking(&rules, &validators)
+                .is_empty(),
+            "Checking if the cap only counts the delegations staking at the same time"
+        );
+        assert_eq!(
+            paths(
+                &delegator_tx("NodeA", 3_000 * AVAX + 1, VALIDATOR_START, VALIDATOR_END)
+                    .verify_staking(&rules, &validators)
+            ),
+            vec!["weight"],
+            "Checking if a validator weight above 5 times its stake is rejected"
+        );
+        assert_eq!(
+            paths(
+                &delegator_tx(
+                    "NodeA",
+                    25 * AVAX - 1,
+                    VALIDATOR_START,
+                    VALIDATOR_START + 14 * DAY
+                )
+                .verify_staking(&rules, &validators)
+            ),
+            vec!["weight"],
+            "Checking if a stake lower than 25 AVAX is rejected"
+        );
+        assert_eq!(
+            paths(
+                &delegator_tx(
+                    "NodeA",
+                    25 * AVAX,
+                    VALIDATOR_START,
+                    VALIDATOR_START + 14 * DAY - 1
+                )
+                .verify_staking(&rules, &validators)
+            ),
+            vec!["end_time"],
+            "Checking if a staking period shorter than 2 weeks is rejected"
+        );
+        assert_eq!(
+            paths(
+                &delegator_tx(
+                    "NodeA",
+                    25 * AVAX,
+                    VALIDATOR_START + 100 * DAY,
+                    VALIDATOR_END + DAY
+                )
+                .verify_staking(&rules, &validators)
+            ),
+            vec!["end_time"],
+            "Checking if a delegation ending after its validator is rejected"
+        );
+        assert_eq!(
+            paths(
+                &delegator_tx(
+                    "NodeA",
+                    25 * AVAX,
+                    VALIDATOR_START - 1,
+                    VALIDATOR_START + 14 * DAY
+                )
+                .verify_staking(&rules, &validators)
+            ),
+            vec!["start_time"],
+            "Checking if a delegation starting before its validator is rejected"
+        );
+        assert_eq!(
+            paths(
+                &delegator_tx(
+                    "NodeA",
+                    25 * AVAX,
+                    VALIDATOR_START,
+                    VALIDATOR_START + 365 * DAY + 1
+                )
+                .verify_staking(&rules, &validators)
+            ),
+            vec!["end_time", "end_time"],
+            "Checking if a staking period longer than a year is rejected"
+        );
+        assert_eq!(
+            paths(
+                &delegator_tx(
+                    "NodeB",
+                    25 * AVAX,
+                    VALIDATOR_START,
+                    VALIDATOR_START + 14 * DAY
+                )
+                .verify_staking(&rules, &validators)
+            ),
+            vec!["node_id"],
+            "Checking if a delegation to a node not validating is rejected"
+        );
+    }
+}
@@ -0,0 +1,308 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::error::Error;
+
+use crate::avm::parser::credential_parser::Credential;
+use crate::pvm::parser::atomic_block_parser::Transaction;
+use crate::pvm::validator_set::{Staker, ValidatorSet};
+use crate::utils::address::address_bytes;
+use crate::utils::signature::recover_address;
+
+/// A subnet , as created by its create subnet tx .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Subnet {
+    /// The id of the create subnet tx
+    pub subnet_id: String,
+    /// The owners which have to authorize the blockchains and validators added to the subnet
+    pub threshold: i32,
+    pub addresses: Vec<String>,
+}
+
+/// A blockchain , as created by its create blockchain tx .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Blockchain {
+    /// The id of the create blockchain tx
+    pub blockchain_id: String,
+    pub subnet_id: String,
+    pub name: String,
+    pub vm_id: String,
+    pub fx_ids: Vec<String>,
+    pub genesis_data: Vec<u8>,
+}
+
+/// The subnets and blockchains created by parsed P-chain txs , given in the order they were accepted .
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SubnetRegistry {
+    pub subnets: BTreeMap<String, Subnet>,
+    pub blockchains: BTreeMap<String, Blockchain>,
+}
+
+impl SubnetRegistry {
+    /// Record the subnet or the blockchain a tx creates , return if the tx created one .
+    /// A blockchain is only recorded when its subnet is known and its owners authorized it ,
+    /// a subnet validator is only checked against the owners of a known subnet .
+    pub fn add_tx(&mut self, _transaction: &Transaction) -> Result<bool, Box<dyn Error>> {
+        if let Some(create_subnet_tx) = &_transaction.create_subnet_tx {
+            let owner = &create_subnet_tx.reward_owner;
+            self.subnets.insert(
+                _transaction.tx_id.clone(),
+                Subnet {
+                    subnet_id: _transaction.tx_id.clone(),
+                    threshold: owner.threshold,
+                    addresses: owner.addresses.clone(),
+                },
+            );
+
+            return Ok(true);
+        }
+
+        if let Some(create_blockchain_tx) = &_transaction.create_blockchain_tx {
+            self.verify_subnet_auth(
+                &create_blockchain_tx.subnet_id,
+                &create_blockchain_tx.subnet_auth.sig_indices,
+                subnet_auth_credential(_transaction),
+                &_transaction.unsigned_tx,
+            )?;
+            self.blockchains.insert(
+                _transaction.tx_id.clone(),
+                Blockchain {
+                    blockchain_id: _transaction.tx_id.clone(),
+                    subnet_id: create_blockchain_tx.subnet_id.clone(),
+                    name: create_blockchain_tx.chain_name.clone(),
+                    vm_id: create_blockchain_tx.vm_id.clone(),
+                    fx_ids: create_blockchain_tx.fx_ids.clone(),
+                    genesis_data: create_blockchain_tx.genesis_data.clone(),
+                },
+            );
+
+            return Ok(true);
+        }
+
+        if let Some(add_subnet_validator_tx) = &_transaction
+            .add_subnet_validator_tx
+            .as_ref()
+            .filter(|tx| self.subnets.contains_key(&tx.subnet_id))
+        {
+            self.verify_subnet_auth(
+                &add_subnet_validator_tx.subnet_id,
+                &add_subnet_validator_tx.subnet_auth.sig_indices,
+                subnet_auth_credential(_transaction),
+                &_transaction.unsigned_tx,
+            )?;
+        }
+
+        Ok(false)
+    }
+
+    /// Check the subnet auth of a tx : `_sig_indices` pick exactly `threshold` distinct owners of the subnet ,
+    /// and the signatures of `_credential` over the unsigned tx recover to them .
+    pub fn verify_subnet_auth(
+        &self,
+        _subnet_id: &str,
+        _sig_indices: &[i32],
+        _credential: Option<&Credential>,
+        _unsigned_tx: &[u8],
+    ) -> Result<(), Box<dyn Error>> {
+        let subnet = self
+            .subnets
+            .get(_subnet_id)
+            .ok_or_else(|| format!("Subnet {} is unknown", _subnet_id))?;
+
+        if _sig_indices.len() != subnet.threshold as usize {
+            return Err(format!(
+                "Subnet {} needs {} signatures , got {} sig indices",
+                _subnet_id,
+                subnet.threshold,
+                _sig_indices.len()
+            )
+            .into());
+        }
+        if _sig_indices.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(format!(
+                "Sig indices {:?} of subnet {} are not sorted and unique",
+                _sig_indices, _subnet_id
+            )
+            .into());
+        }
+
+        let credential =
+            _credential.ok_or_else(|| format!("Subnet {} auth has no credential", _subnet_id))?;
+        if credential.signatures.len() != _sig_indices.len() {
+            return Err(format!(
+                "Subnet {} auth has {} signatures for {} sig indices",
+                _subnet_id,
+                credential.signatures.len(),
+                _sig_indices.len()
+            )
+            .into());
+        }
+
+        for (sig_index, signature) in _sig_indices.iter().zip(&credential.signatures) {
+            let address = usize::try_from(*sig_index)
+                .ok()
+                .and_then(|sig_index| subnet.addresses.get(sig_index))
+                .ok_or_else(|| {
+                    format!(
+                        "Sig index {} is out of the {} owners of subnet {}",
+                        sig_index,
+                        subnet.addresses.len(),
+                        _subnet_id
+                    )
+                })?;
+            if recover_address(_unsigned_tx, signature)? != address_bytes(address)? {
+                return Err(format!(
+                    "Signature of sig index {} of subnet {} is not from {}",
+                    sig_index, _subnet_id, address
+                )
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The blockchains of a subnet .
+    pub fn chains_of(&self, _subnet_id: &str) -> Vec<&Blockchain> {
+        self.blockchains
+            .values()
+            .filter(|blockchain| blockchain.subnet_id == _subnet_id)
+            .collect()
+    }
+
+    /// The current validators of a known subnet , as found in `_validators` .
+    pub fn validators_of<'a>(
+        &self,
+        _subnet_id: &str,
+        _validators: &'a ValidatorSet,
+    ) -> Option<Vec<&'a Staker>> {
+        self.subnets
+            .get(_subnet_id)
+            .map(|subnet| _validators.current_validators(&subnet.subnet_id))
+    }
+}
+
+/// The subnet auth credential follows the credentials of the inputs .
+fn subnet_auth_credential(_transaction: &Transaction) -> Option<&Credential> {
+    _transaction
+        .credentials
+        .get(_transaction.base_tx.transferable_inputs.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pvm::parser::add_subnet_validator_tx::AddSubnetValidatorTx;
+    use crate::pvm::parser::fixtures::{
+        create_subnet_block, test_blockchain_block, test_subnet_block,
+    };
+    use crate::pvm::parser::subnet_auth_parser::SubnetAuth;
+    use crate::pvm::validator_set::PRIMARY_NETWORK_ID;
+    use crate::utils::amount::Amount;
+
+    #[test]
+    fn add_tx_01() {
+        let mut block = create_subnet_block();
+        let transaction = block.block_data.transactions.remove(0).unwrap();
+
+        let mut registry = SubnetRegistry::default();
+        assert!(
+            registry.add_tx(&transaction).unwrap(),
+            "Checking if the create subnet tx is recorded"
+        );
+        assert_eq!(
+            registry.subnets.get(&transaction.tx_id),
+            Some(&Subnet {
+                subnet_id: "UC4CosZv99LqYRYSCCu9yX5NCGoLjVE2aP6pSwNVsiX48Lxhc".to_string(),
+                threshold: 2,
+                addresses: vec![
+                    "X-avax1fuuttly42kw7hdnlwc5sr2mqeajvcf7ejmscyr".to_string(),
+                    "X-avax13gfmq9cuw5ph2ce67fusfd6gtp0awln7px5mh7".to_string(),
+                ],
+            }),
+            "Checking if the subnet id and owners are set"
+        );
+    }
+
+    #[test]
+    fn add_tx_02() {
+        let mut subnet_block = test_subnet_block();
+        let subnet_tx = subnet_block.block_data.transactions.remove(0).unwrap();
+        let mut blockchain_block = test_blockchain_block();
+        let mut transaction = blockchain_block.block_data.transactions.remove(0).unwrap();
+        let subnet_id = subnet_tx.tx_id.clone();
+
+        let mut registry = SubnetRegistry::default();
+        assert!(
+            registry.add_tx(&transaction).is_err(),
+            "Checking if a blockchain of an unknown subnet is rejected"
+        );
+        assert!(
+            registry.add_tx(&subnet_tx).unwrap(),
+            "Checking if the create subnet tx is recorded"
+        );
+        assert!(
+            registry.add_tx(&transaction).unwrap(),
+            "Checking if the create blockchain tx signed by the subnet owner is recorded"
+        );
+        assert_eq!(
+            registry
+                .chains_of(&subnet_id)
+                .iter()
+                .map(|blockchain| blockchain.blockchain_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["XvYKcpFdnJHBBAPMCCNPNuVNE5HNSa7BgrJRULMSuXxp6QgAE"],
+            "Checking if the blockchain belongs to its subnet"
+        );
+        assert_eq!(
+            registry
+                .validators_of(&subnet_id, &ValidatorSet::default())
+                .map(|validators| validators.len()),
+            Some(0),
+            "Checking if a known subnet has no validators yet"
+        );
+        assert!(
+            registry
+                .validators_of(PRIMARY_NETWORK_ID, &ValidatorSet::default())
+                .is_none(),
+            "Checking if unknown subnets are not answered"
+        );
+
+        let credential = subnet_auth_credential(&transaction);
+        assert!(
+            registry
+                .verify_subnet_auth(&subnet_id, &[1], credential, &transaction.unsigned_tx)
+                .is_err(),
+            "Checking if a sig index out of the owners is rejected"
+        );
+        assert!(
+            registry
+                .verify_subnet_auth(&subnet_id, &[0], credential, &subnet_tx.unsigned_tx)
+                .is_err(),
+            "Checking if a signature of another tx is rejected"
+        );
+
+        transaction.create_blockchain_tx = None;
+        transaction.add_subnet_validator_tx = Some(AddSubnetValidatorTx {
+            node_id: "NodeID-111111111111111111116DBWJs".to_string(),
+            start_time: 0,
+            end_time: 1,
+            weight: Amount(1),
+            subnet_id: "an_unknown_subnet".to_string(),
+            subnet_auth: SubnetAuth {
+                type_id: 10,
+                sig_indices: vec![0],
+            },
+        });
+        assert!(
+            !registry.add_tx(&transaction).unwrap(),
+            "Checking if a subnet validator of an unknown subnet is skipped"
+        );
+        let add_subnet_validator_tx = transaction.add_subnet_validator_tx.as_mut().unwrap();
+        add_subnet_validator_tx.subnet_id = subnet_id;
+        add_subnet_validator_tx.subnet_auth.sig_indices = vec![1];
+        assert!(
+            registry.add_tx(&transaction).is_err(),
+            "Checking if a subnet validator is checked against the owners of a known subnet"
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use crate::pvm::parser::atomic_block_parser::Transaction;
+use crate::pvm::parser::input_parser::Input;
+use crate::pvm::parser::output_parser::{Output, SECP256KTransferOutput};
+use crate::pvm::parser::transferable_input_parser::TransferableInput;
+use crate::pvm::parser::transferable_output_parser::TransferableOutput;
+use crate::utils::amount::Amount;
+use crate::utils::cb58::decode;
+use crate::utils::syntax::{
+    owners_bytes, verify_amount, verify_inputs_sorted_and_unique, verify_memo,
+    verify_outputs_sorted, verify_owners, violation, SyntaxViolation,
+};
+
+impl Transaction {
+    /// Check the rules AvalancheGo enforces on a tx before looking at the state .
+    /// An empty list means the tx is syntactically valid .
+    pub fn verify_syntax(&self) -> Vec<SyntaxViolation> {
+        let mut violations = Vec::new();
+
+        verify_outputs(
+            &mut violations,
+            "outputs",
+            &self.base_tx.transferable_outputs,
+        );
+        verify_inputs(&mut violations, "inputs", &self.base_tx.transferable_inputs);
+        verify_memo(&mut violations, &self.base_tx.memo);
+
+        if let Some(add_validator_tx) = &self.add_validator_tx {
+            verify_outputs(&mut violations, "stake", &add_validator_tx.stake);
+            verify_transfer_output(
+                &mut violations,
+                "reward_owner",
+                &add_validator_tx.reward_owner,
+            );
+        }
+
+        if let Some(add_delegator_tx) = &self.add_delegator_tx {
+            verify_outputs(&mut violations, "stake", &add_delegator_tx.stake);
+            verify_transfer_output(
+                &mut violations,
+                "reward_owner",
+                &add_delegator_tx.reward_owner,
+            );
+        }
+
+        if let Some(create_subnet_tx) = &self.create_subnet_tx {
+            verify_transfer_output(
+                &mut violations,
+                "reward_owner",
+                &create_subnet_tx.reward_owner,
+            );
+        }
+
+        if let Some(import_tx) = &self.import_tx {
+            verify_inputs(
+                &mut violations,
+                "imported_inputs",
+                &import_tx.transferable_inputs,
+            );
+        }
+
+        if let Some(export_tx) = &self.export_tx {
+            verify_outputs(
+                &mut violations,
+                "exported_outputs",
+                &export_tx.transferable_outputs,
+            );
+        }
+
+        violations
+    }
+}
+
+fn verify_outputs(
+    _violations: &mut Vec<SyntaxViolation>,
+    _path: &str,
+    _outputs: &[TransferableOutput],
+) {
+    let mut keys = Vec::new();
+    for (index, transferable_output) in _outputs.iter().enumerate() {
+        let path = format!("{}[{}]", _path, index);
+        for output in transfer_outputs(&transferable_output.output) {
+            verify_transfer_output(_violations, &path, output);
+        }
+
+        match decode(&transferable_output.asset_id) {
+            Ok(mut key) => {
+                key.extend(output_bytes(&transferable_output.output));
+                keys.push(key);
+            }
+            Err(err) => {
+                violation(_violations, &format!("{}.asset_id", path), &err.to_string());
+                return;
+            }
+        }
+    }
+
+    verify_outputs_sorted(_violations, _path, &keys);
+}
+
+fn verify_transfer_output(
+    _violations: &mut Vec<SyntaxViolation>,
+    _path: &str,
+    _output: &SECP256KTransferOutput,
+) {
+    if let Some(amount) = _output.amount {
+        verify_amount(_violations, _path, amount);
+    }
+    verify_owners(_violations, _path, _output.threshold, &_output.addresses);
+}
+
+fn verify_inputs(
+    _violations: &mut Vec<SyntaxViolation>,
+    _path: &str,
+    _inputs: &[TransferableInput],
+) {
+    for (index, transferable_input) in _inputs.iter().enumerate() {
+        verify_amount(
+            _violations,
+            &format!("{}[{}]", _path, index),
+            input_amount(&transferable_input.input),
+        );
+    }
+
+    let utxos: Vec<(&str, i32)> = _inputs
+        .iter()
+        .map(|input| (input.tx_id.as_str(), input.utxo_index))
+        .collect();
+    verify_inputs_sorted_and_unique(_violations, _path, &utxos);
+}
+
+fn input_amount(_input: &Input) -> Amount {
+    if let Some(stakeable_locked_input) = &_input.stakeable_locked_input {
+        stakeable_locked_input.input.amount
+    } else if let Some(secp256k_transfer_input) = &_input.secp256k_transfer_input {
+        secp256k_transfer_input.amount
+    } else {
+        Amount::default()
+    }
+}
+
+/// The secp256k1 outputs held by an output , at most one but stakeable locked outputs wrap theirs .
+fn transfer_outputs(_output: &Output) -> Vec<&SECP256KTransferOutput> {
+    let mut outputs = Vec::new();
+    if let Some(stakeable_locked_output) = &_output.stakeable_locked_output {
+        outputs.extend(stakeable_locked_output.secp256k_transfer_output.as_ref());
+    }
+    outputs.extend(_output.secp256k1_transfer_output.as_ref());
+    outputs.extend(_output.secp256k1_owner_output.as_ref());
+
+    outputs
+}
+
+/// The serialized form of an output , type id included , used to sort outputs the way AvalancheGo does .
+pub(crate) fn output_bytes(_output: &Output) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&_output.type_id.to_be_bytes());
+    if let Some(stakeable_locked_output) = &_output.stakeable_locked_output {
+        bytes.extend_from_slice(&stakeable_locked_output.locktime.to_be_bytes());
+    }
+    for output in transfer_outputs(_output) {
+        if _output.stakeable_locked_output.is_some() {
+            bytes.extend_from_slice(&output.type_id.to_be_bytes());
+        }
+        if let Some(amount) = output.amount {
+            bytes.extend_from_slice(&amount.to_be_bytes());
+        }
+        bytes.extend(owners_bytes(
+            output.locktime,
+            output.threshold,
+            &output.addresses,
+        ));
+    }
+
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pvm::parser::base_tx_parser::BaseTx;
+    use crate::pvm::parser::output_parser::StakeableLockedOutput;
+
+    fn transfer_output(_amount: u64, _threshold: i32) -> SECP256KTransferOutput {
+        SECP256KTransferOutput {
+            type_id: 7,
+            amount: Some(Amount(_amount)),
+            locktime: 0,
+            threshold: _threshold,
+            addresses: vec!["X-avax1d3qnae4dej47yd0pd28th7nxelwu6fp5mncjnd".to_string()],
+        }
+    }
+
+    #[test]
+    fn verify_syntax_01() {
+        let tx = Transaction {
+            base_tx: BaseTx {
+                type_id: 16,
+                network_id: 1,
+                blockchain_id: "11111111111111111111111111111111LpoYY".to_string(),
+                transferable_outputs: vec![TransferableOutput {
+                    asset_id: "FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z".to_string(),
+                    output: Output {
+                        type_id: 22,
+                        stakeable_locked_output: Some(StakeableLockedOutput {
+                            locktime: 1_700_000_000,
+                            secp256k_transfer_output: Some(transfer_output(0, 2)),
+                        }),
+                        secp256k1_transfer_output: None,
+                        secp256k1_owner_output: None,
+                    },
+                }],
+                transferable_inputs: vec![],
+                memo: vec![],
+            },
+            tx_id: "a_tx".to_string(),
+            add_validator_tx: None,
+            import_tx: None,
+            export_tx: None,
+            add_subnet_validator_tx: None,
+            add_delegator_tx: None,
+            create_blockchain_tx: None,
+            create_subnet_tx: None,
+            advance_time_tx: None,
+            reward_validator_tx: None,
+            unsigned_tx: vec![],
+            credentials: vec![],
+        };
+
+        let violations: Vec<String> = tx
+            .verify_syntax()
+            .iter()
+            .map(|violation| violation.to_string())
+            .collect();
+        assert_eq!(
+            violations,
+            vec![
+                "outputs[0].amount : amount is zero",
+                "outputs[0].threshold : threshold exceeds the number of addresses",
+            ],
+            "Checking the locked output is verified"
+        );
+    }
+}
@@ -0,0 +1,460 @@
+use chrono::{DateTime, Utc};
+
+use std::collections::BTreeMap;
+use std::error::Error;
+
+use crate::pvm::chain_time::datetime;
+use crate::pvm::parser::atomic_block_parser::Transaction;
+use crate::pvm::parser::block_parser::{
+    Block, ABORT_BLOCK_TYPE_ID, COMMIT_BLOCK_TYPE_ID, PROPOSAL_BLOCK_TYPE_ID,
+};
+use crate::utils::amount::Amount;
+
+/// The id of the primary network , the subnet every validator of Avalanche belongs to .
+pub const PRIMARY_NETWORK_ID: &str = "11111111111111111111111111111111LpoYY";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum StakerKind {
+    Validator,
+    Delegator,
+    SubnetValidator,
+}
+
+/// A validator or a delegator , as added by its staker tx .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Staker {
+    /// The id of the tx which added the staker , used by the reward validator tx
+    pub tx_id: String,
+    pub node_id: String,
+    pub subnet_id: String,
+    pub kind: StakerKind,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub weight: Amount,
+    /// The chain time the staker was promoted at , which can be later than its start time
+    pub started_at: Option<i64>,
+}
+
+impl Staker {
+    /// The chain time the staker was promoted at , as a UTC datetime .
+    pub fn started_at_datetime(&self) -> Result<Option<DateTime<Utc>>, Box<dyn Error>> {
+        self.started_at.map(datetime).transpose()
+    }
+}
+
+/// What a tx changes in the validator set .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum StakerChange {
+    Add(Staker),
+    AdvanceTime(i64),
+    Reward(String),
+}
+
+/// The current and pending stakers of every subnet , replayed from P-chain blocks in height order .
+/// Clone it to keep the state at a given height .
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ValidatorSet {
+    /// The height of the last block applied
+    pub height: Option<i64>,
+    /// The chain time , as set by the last advance time tx or Banff block
+    pub chain_time: i64,
+    /// The stakers by the id of their staker tx
+    pub current: BTreeMap<String, Staker>,
+    pub pending: BTreeMap<String, Staker>,
+    /// The proposal block waiting for its commit or abort child , with its changes
+    pub proposal: Option<(String, Vec<StakerChange>)>,
+}
+
+impl ValidatorSet {
+    pub fn apply_block(&mut self, _block: &Block) -> Result<(), Box<dyn Error>> {
+        let block_data = &_block.block_data;
+        if let Some(height) = self.height {
+            if block_data.height != height + 1 {
+                return Err(format!(
+                    "Block {} is at height {} , expected {}",
+                    block_data.block_id,
+                    block_data.height,
+                    height + 1
+                )
+                .into());
+            }
+        }
+
+        // Banff blocks advance the chain time before their txs , advance time txs are no longer allowed
+        if let Some(timestamp) = block_data.timestamp {
+            self.apply_change(StakerChange::AdvanceTime(timestamp))?;
+        }
+
+        match block_data.type_id {
+            PROPOSAL_BLOCK_TYPE_ID => {
+                // The decision txs of a Banff proposal block do not wait for its commit or abort
+                for transaction in block_data.decision_txs() {
+                    if let Some(change) = staker_change(transaction) {
+                        self.apply_change(change)?;
+                    }
+                }
+                let changes = block_data
+                    .proposal_tx()
+                    .and_then(staker_change)
+                    .into_iter()
+                    .collect();
+                self.proposal = Some((block_data.block_id.clone(), changes));
+            }
+            ABORT_BLOCK_TYPE_ID | COMMIT_BLOCK_TYPE_ID => {
+                let (proposal_block_id, changes) = self.proposal.take().ok_or_else(|| {
+                    format!("Block {} has no proposal to decide", block_data.block_id)
+                })?;
+                if proposal_block_id != block_data.parent_block_id {
+                    return Err(format!(
+                        "Block {} decides {} , the pending proposal is {}",
+                        block_data.block_id, block_data.parent_block_id, proposal_block_id
+                    )
+                    .into());
+                }
+                // An aborted reward still retires the staker , it is only not paid
+                for change in changes {
+                    if block_data.type_id == COMMIT_BLOCK_TYPE_ID
+                        || matches!(change, StakerChange::Reward(_))
+                    {
+                        self.apply_change(change)?;
+                    }
+                }
+            }
+            _ => {
+                for transaction in block_data.transactions.iter().flatten() {
+                    if let Some(change) = staker_change(transaction) {
+                        self.apply_change(change)?;
+                    }
+                }
+            }
+        }
+
+        self.height = Some(block_data.height);
+
+        Ok(())
+    }
+
+    pub fn apply_change(&mut self, _change: StakerChange) -> Result<(), Box<dyn Error>> {
+        match _change {
+            StakerChange::Add(staker) => {
+                self.pending.insert(staker.tx_id.clone(), staker);
+            }
+            StakerChange::AdvanceTime(time) => {
+                if time < self.chain_time {
+                    return Err(format!(
+                        "Chain time cannot go back from {} to {}",
+                        self.chain_time, time
+                    )
+                    .into());
+                }
+                self.chain_time = time;
+
+                let started: Vec<String> = self
+                    .pending
+                    .values()
+                    .filter(|staker| staker.start_time <= time)
+                    .map(|staker| staker.tx_id.clone())
+                    .collect();
+                for tx_id in started {
+                    if let Some(mut staker) = self.pending.remove(&tx_id) {
+                        staker.started_at = Some(time);
+                        self.current.insert(tx_id, staker);
+                    }
+                }
+
+                // Subnet validators are not rewarded , they leave once their end time is reached
+                self.current.retain(|_, staker| {
+                    staker.kind != StakerKind::SubnetValidator || staker.end_time > time
+                });
+            }
+            StakerChange::Reward(tx_id) => {
+                self.current
+                    .remove(&tx_id)
+                    .ok_or_else(|| format!("Staker {} to reward is not current", tx_id))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The current validators of a subnet , delegators excluded .
+    pub fn current_validators(&self, _subnet_id: &str) -> Vec<&Staker> {
+        validators(self.current.values(), _subnet_id)
+    }
+
+    pub fn pending_validators(&self, _subnet_id: &str) -> Vec<&Staker> {
+        validators(self.pending.values(), _subnet_id)
+    }
+
+    /// The current and pending stakers of a node on a subnet , delegators included .
+    pub fn stakers_of(&self, _node_id: &str, _subnet_id: &str) -> Vec<&Staker> {
+        self.current
+            .values()
+            .chain(self.pending.values())
+            .filter(|staker| staker.node_id == _node_id && staker.subnet_id == _subnet_id)
+            .collect()
+    }
+
+    /// The weight of every current validator of a subnet by node id , delegations included .
+    pub fn current_weights(
+        &self,
+        _subnet_id: &str,
+    ) -> Result<BTreeMap<String, Amount>, Box<dyn Error>> {
+        let mut weights = BTreeMap::new();
+        for staker in self.current.values() {
+            if staker.subnet_id != _subnet_id {
+                continue;
+            }
+            let weight = weights
+                .entry(staker.node_id.clone())
+                .or_insert_with(Amount::default);
+            *weight = weight
+                .checked_add(staker.weight)
+                .ok_or_else(|| format!("Weight of node {} overflows", staker.node_id))?;
+        }
+
+        Ok(weights)
+    }
+}
+
+fn validators<'a>(_stakers: impl Iterator<Item = &'a Staker>, _subnet_id: &str) -> Vec<&'a Staker> {
+    _stakers
+        .filter(|staker| staker.subnet_id == _subnet_id && staker.kind != StakerKind::Delegator)
+        .collect()
+}
+
+/// The change a tx makes to the validator set , if any .
+pub fn staker_change(_transaction: &Transaction) -> Option<StakerChange> {
+    let staker = |node_id: &str, subnet_id: &str, kind, start_time, end_time, weight| Staker {
+        tx_id: _transaction.tx_id.clone(),
+        node_id: node_id.to_string(),
+        subnet_id: subnet_id.to_string(),
+        kind,
+        start_time,
+        end_time,
+        weight,
+        started_at: None,
+    };
+
+    if let Some(tx) = &_transaction.add_validator_tx {
+        Some(StakerChange::Add(staker(
+            &tx.node_id,
+            PRIMARY_NETWORK_ID,
+            StakerKind::Validator,
+            tx.start_time,
+            tx.end_time,
+            tx.weight,
+        )))
+    } else if let Some(tx) = &_transaction.add_delegator_tx {
+        Some(StakerChange::Add(staker(
+            &tx.node_id,
+            PRIMARY_NETWORK_ID,
+            StakerKind::Delegator,
+            tx.start_time,
+            tx.end_time,
+            tx.weight,
+        )))
+    } else if let Some(tx) = &_transaction.add_subnet_validator_tx {
+        Some(StakerChange::Add(staker(
+            &tx.node_id,
+            &tx.subnet_id,
+            StakerKind::SubnetValidator,
+            tx.start_time,
+            tx.end_time,
+            tx.weight,
+        )))
+    } else if let Some(tx) = &_transaction.advance_time_tx {
+        Some(StakerChange::AdvanceTime(tx.time_proposal))
+    } else {
+        _transaction
+            .reward_validator_tx
+            .as_ref()
+            .map(|tx| StakerChange::Reward(tx.tx_id.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pvm::parser::fixtures::{
+        advance_time_proposal_block, banff_proposal_block, c_chain_genesis_block, option_block,
+        reward_validator_proposal_block,
+    };
+
+    fn with_stakers() -> ValidatorSet {
+        let mut validator_set = ValidatorSet::default();
+        for (tx_id, kind, weight) in [
+            ("validator_tx", StakerKind::Validator, 2_000),
+            ("delegator_tx", StakerKind::Delegator, 25),
+        ] {
+            validator_set
+                .apply_change(StakerChange::Add(Staker {
+                    tx_id: tx_id.to_string(),
+                    node_id: "NodeID-A".to_string(),
+                    subnet_id: PRIMARY_NETWORK_ID.to_string(),
+                    kind,
+                    start_time: 1_606_770_000,
+                    end_time: 1_608_000_000,
+                    weight: Amount(weight),
+                    started_at: None,
+                }))
+                .unwrap();
+        }
+
+        validator_set
+    }
+
+    #[test]
+    fn apply_block_01() {
+        let proposal = advance_time_proposal_block();
+
+        let mut validator_set = with_stakers();
+        validator_set.apply_block(&proposal).unwrap();
+        assert_eq!(
+            validator_set.pending_validators(PRIMARY_NETWORK_ID).len(),
+            1,
+            "Checking if the proposal waits for its commit"
+        );
+        validator_set
+            .apply_block(&option_block(COMMIT_BLOCK_TYPE_ID, &proposal))
+            .unwrap();
+        assert_eq!(
+            validator_set.chain_time, 1_606_770_043,
+            "Checking if the chain time is advanced"
+        );
+        assert_eq!(
+            validator_set
+                .current_weights(PRIMARY_NETWORK_ID)
+                .unwrap()
+                .get("NodeID-A"),
+            Some(&Amount(2_025)),
+            "Checking if the stakers are promoted with their delegations"
+        );
+        assert_eq!(
+            validator_set.current["validator_tx"].started_at,
+            Some(1_606_770_043),
+            "Checking if the promotion time is kept"
+        );
+        assert_eq!(
+            validator_set.current["validator_tx"]
+                .started_at_datetime()
+                .unwrap()
+                .map(|started_at| started_at.to_rfc3339()),
+            Some("2020-11-30T21:00:43+00:00".to_string()),
+            "Checking if the promotion time is given as a datetime"
+        );
+        validator_set
+            .apply_change(StakerChange::Reward("validator_tx".to_string()))
+            .unwrap();
+        assert!(
+            validator_set
+                .current_validators(PRIMARY_NETWORK_ID)
+                .is_empty(),
+            "Checking if the rewarded validator is retired"
+        );
+
+        let mut validator_set = with_stakers();
+        validator_set.apply_block(&proposal).unwrap();
+        validator_set
+            .apply_block(&option_block(ABORT_BLOCK_TYPE_ID, &proposal))
+            .unwrap();
+        assert!(
+            validator_set.chain_time == 0 && validator_set.current.is_empty(),
+            "Checking if an aborted proposal is dropped"
+        );
+    }
+
+    #[test]
+    fn apply_block_02() {
+        let mut validator_set = with_stakers();
+        validator_set
+            .apply_change(StakerChange::AdvanceTime(1_606_770_043))
+            .unwrap();
+
+        let mut proposal = reward_validator_proposal_block();
+        proposal.block_data.transactions[0]
+            .as_mut()
+            .unwrap()
+            .reward_validator_tx
+            .as_mut()
+            .unwrap()
+            .tx_id = "validator_tx".to_string();
+        validator_set.apply_block(&proposal).unwrap();
+        assert!(
+            validator_set.current.contains_key("validator_tx"),
+            "Checking if the reward waits for its commit or abort"
+        );
+        validator_set
+            .apply_block(&option_block(ABORT_BLOCK_TYPE_ID, &proposal))
+            .unwrap();
+        assert!(
+            validator_set
+                .current_validators(PRIMARY_NETWORK_ID)
+                .is_empty(),
+            "Checking if an aborted reward still retires the validator"
+        );
+        assert!(
+            validator_set.current.contains_key("delegator_tx"),
+            "Checking if the other stakers are kept"
+        );
+    }
+    #[test]
+    fn apply_block_03() {
+        let mut validator_set = ValidatorSet::default();
+        validator_set
+            .apply_change(StakerChange::Add(Staker {
+                tx_id: "validator_tx".to_string(),
+                node_id: "NodeID-A".to_string(),
+                subnet_id: PRIMARY_NETWORK_ID.to_string(),
+                kind: StakerKind::Validator,
+                start_time: 1_603_000_000,
+                end_time: 1_604_000_000,
+                weight: Amount(2_000),
+                started_at: None,
+            }))
+            .unwrap();
+
+        let mut proposal = banff_proposal_block();
+        let decision_tx_id = proposal.block_data.transactions[0]
+            .as_ref()
+            .unwrap()
+            .tx_id
+            .clone();
+        proposal.block_data.transactions[1]
+            .as_mut()
+            .unwrap()
+            .reward_validator_tx
+            .as_mut()
+            .unwrap()
+            .tx_id = "validator_tx".to_string();
+        validator_set.apply_block(&proposal).unwrap();
+        assert!(
+            validator_set.chain_time == 1_604_000_000
+                && validator_set.current.contains_key("validator_tx"),
+            "Checking if the timestamp of the block promotes the pending stakers"
+        );
+        assert!(
+            validator_set.pending.contains_key(&decision_tx_id),
+            "Checking if the decision tx is applied without waiting for the decision"
+        );
+
+        let mut abort = option_block(ABORT_BLOCK_TYPE_ID, &proposal);
+        abort.block_data.timestamp = Some(1_604_000_000);
+        validator_set.apply_block(&abort).unwrap();
+        assert!(
+            !validator_set.current.contains_key("validator_tx")
+                && validator_set.pending.contains_key(&decision_tx_id),
+            "Checking if an abort keeps the decision tx and still retires the validator"
+        );
+
+        let mut standard = c_chain_genesis_block();
+        standard.block_data.height = abort.block_data.height + 1;
+        standard.block_data.timestamp = Some(1_604_054_315);
+        validator_set.apply_block(&standard).unwrap();
+        assert_eq!(
+            validator_set.current[&decision_tx_id].started_at,
+            Some(1_604_054_315),
+            "Checking if a Banff standard block advances the chain time"
+        );
+    }
+}
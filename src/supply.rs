@@ -0,0 +1,819 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::error::Error;
+
+use tracing::trace;
+
+use crate::avm::parser::signed_tx_parser::SignedTx as AvmSignedTx;
+use crate::avm::parser::transferable_input_parser::TransferableInput as AvmTransferableInput;
+use crate::avm::parser::transferable_output_parser::TransferableOutput as AvmTransferableOutput;
+use crate::cvm::parser::signed_tx_parser::SignedTx as CvmSignedTx;
+use crate::network::Network;
+use crate::pvm::chain_time::ChainTimeTracker;
+use crate::pvm::parser::atomic_block_parser::Transaction;
+use crate::pvm::parser::block_parser::{
+    Block, ABORT_BLOCK_TYPE_ID, COMMIT_BLOCK_TYPE_ID, PROPOSAL_BLOCK_TYPE_ID,
+};
+use crate::pvm::parser::transferable_output_parser::TransferableOutput;
+use crate::pvm::reward::RewardConfig;
+use crate::pvm::validator_set::StakerKind;
+use crate::utils::amount::Amount;
+use crate::utils::cb58::decode;
+use crate::utils::fee::EstimateFee;
+
+/// AVAX allocated at genesis , locked until `locktime` when it is in the future .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Allocation {
+    pub amount: Amount,
+    pub locktime: i64,
+}
+
+/// The AVAX supply after a P-chain block .
+/// `total` is `circulating + staked + locked + in_transit` .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SupplyReport {
+    pub height: i64,
+    pub chain_time: i64,
+    pub total: Amount,
+    pub circulating: Amount,
+    pub staked: Amount,
+    pub locked: Amount,
+    /// Exported from a chain and not imported yet
+    pub in_transit: Amount,
+}
+
+/// The AVAX stake of a validator or a delegator , returned when it is rewarded .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StakedAmount {
+    pub kind: StakerKind,
+    pub amount: Amount,
+    /// The stake outputs still locked , by locktime
+    pub locked: BTreeMap<i64, Amount>,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub weight: Amount,
+    /// The reward minted if the staker is rewarded ,
+    /// computed from the current supply when the staker is promoted from pending to current
+    pub potential_reward: Option<Amount>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct SupplyState {
+    pub total: Amount,
+    pub in_transit: Amount,
+    /// The stakeable locked AVAX which is not staked , by locktime
+    pub locked: BTreeMap<i64, Amount>,
+    /// The stakers by the id of their staker tx
+    pub stakers: BTreeMap<String, StakedAmount>,
+}
+
+/// Follow the AVAX supply from the genesis over parsed txs .
+/// P-chain blocks are given in height order , X-chain and C-chain txs in between as they are accepted .
+/// Locked AVAX is only tracked on the P-chain , where stakeable locked outputs live .
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SupplyTracker {
+    pub network: Network,
+    pub reward_config: RewardConfig,
+    pub chain_time: ChainTimeTracker,
+    pub state: SupplyState,
+    /// The proposal block waiting for its commit or abort child , with the state after each
+    pub proposal: Option<(String, SupplyState, SupplyState)>,
+    pub reports: BTreeMap<i64, SupplyReport>,
+}
+
+impl SupplyTracker {
+    pub fn new(
+        _network: Network,
+        _reward_config: RewardConfig,
+        _genesis_time: i64,
+        _allocations: &[Allocation],
+    ) -> Result<SupplyTracker, Box<dyn Error>> {
+        let mut state = SupplyState::default();
+        for allocation in _allocations {
+            state.total = add(state.total, allocation.amount)?;
+            if allocation.locktime > _genesis_time {
+                add_locked(&mut state.locked, allocation.locktime, allocation.amount)?;
+            }
+        }
+
+        Ok(SupplyTracker {
+            network: _network,
+            reward_config: _reward_config,
+            chain_time: ChainTimeTracker::new(_genesis_time),
+            state,
+            proposal: None,
+            reports: BTreeMap::new(),
+        })
+    }
+
+    pub fn apply_pvm_block(&mut self, _block: &Block) -> Result<SupplyReport, Box<dyn Error>> {
+        let block_data = &_block.block_data;
+
+        // Banff blocks promote the pending stakers before their txs
+        if let Some(timestamp) = block_data.timestamp {
+            self.state.advance_time(timestamp, &self.reward_config)?;
+        }
+
+        match block_data.type_id {
+            PROPOSAL_BLOCK_TYPE_ID => {
+                // The decision txs of a Banff proposal block do not wait for its commit or abort
+                for transaction in block_data.decision_txs() {
+                    self.state.apply_pvm_tx(transaction, &self.network, true)?;
+                }
+
+                let mut commit = self.state.clone();
+                let mut abort = self.state.clone();
+                if let Some(transaction) = block_data.proposal_tx() {
+                    if let Some(reward_validator_tx) = &transaction.reward_validator_tx {
+                        commit.unstake(&reward_validator_tx.tx_id, true)?;
+                        abort.unstake(&reward_validator_tx.tx_id, false)?;
+                    } else if let Some(advance_time_tx) = &transaction.advance_time_tx {
+                        commit.advance_time(advance_time_tx.time_proposal, &self.reward_config)?;
+                    } else {
+                        commit.apply_pvm_tx(transaction, &self.network, true)?;
+                        abort.apply_pvm_tx(transaction, &self.network, false)?;
+                    }
+                }
+                self.proposal = Some((block_data.block_id.clone(), commit, abort));
+            }
+            ABORT_BLOCK_TYPE_ID | COMMIT_BLOCK_TYPE_ID => {
+                let (proposal_block_id, commit, abort) = self.proposal.take().ok_or_else(|| {
+                    format!("Block {} has no proposal to decide", block_data.block_id)
+                })?;
+                if proposal_block_id != block_data.parent_block_id {
+                    return Err(format!(
+                        "Block {} decides {} , the pending proposal is {}",
+                        block_data.block_id, block_data.parent_block_id, proposal_block_id
+                    )
+                    .into());
+                }
+                self.state = if block_data.type_id == COMMIT_BLOCK_TYPE_ID {
+                    commit
+                } else {
+                    abort
+                };
+            }
+            _ => {
+                let mut state = self.state.clone();
+                for transaction in block_data.transactions.iter().flatten() {
+                    state.apply_pvm_tx(transaction, &self.network, true)?;
+                }
+                self.state = state;
+            }
+        }
+
+        let chain_time = self.chain_time.apply_block(_block)?.chain_time.timestamp();
+        let report = self.report(block_data.height, chain_time)?;
+        self.reports.insert(report.height, report.clone());
+
+        Ok(report)
+    }
+
+    /// Burn the fee of an X-chain tx and move its exported or imported AVAX in or out of transit .
+    pub fn apply_avm_tx(&mut self, _tx: &AvmSignedTx) -> Result<(), Box<dyn Error>> {
+        let state = &mut self.state;
+        state.burn(_tx.burned_fee(&self.network)?)?;
+
+        if let Some(import_tx) = &_tx.import_tx {
+            state.import(avm_inputs_amount(
+                &import_tx.transferable_inputs,
+                &self.network,
+            )?)?;
+        }
+        if let Some(export_tx) = &_tx.export_tx {
+            state.in_transit = add(
+                state.in_transit,
+                avm_outputs_amount(&export_tx.transferable_outputs, &self.network)?,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Burn the fee of a C-chain atomic tx and move its AVAX in or out of transit .
+    pub fn apply_cvm_tx(&mut self, _tx: &CvmSignedTx) -> Result<(), Box<dyn Error>> {
+        let avax_asset_id = &self.network.avax_asset_id;
+        let state = &mut self.state;
+
+        if let Some(import_tx) = &_tx.import_tx {
+            let imported = avm_inputs_amount(&import_tx.transferable_inputs, &self.network)?;
+            let credited = import_tx
+                .evm_outputs
+                .iter()
+                .filter(|evm_output| &evm_output.asset_id == avax_asset_id)
+                .try_fold(Amount::default(), |total, evm_output| {
+                    add(total, evm_output.amount)
+                })?;
+            state.import(imported)?;
+            state.burn(fee(imported, credited)?.0)?;
+        }
+        if let Some(export_tx) = &_tx.export_tx {
+            let debited = export_tx
+                .evm_inputs
+                .iter()
+                .filter(|evm_input| &evm_input.asset_id == avax_asset_id)
+                .try_fold(Amount::default(), |total, evm_input| {
+                    add(total, evm_input.amount)
+                })?;
+            let exported = avm_outputs_amount(&export_tx.transferable_outputs, &self.network)?;
+            state.in_transit = add(state.in_transit, exported)?;
+            state.burn(fee(debited, exported)?.0)?;
+        }
+
+        Ok(())
+    }
+
+    fn report(&self, _height: i64, _chain_time: i64) -> Result<SupplyReport, Box<dyn Error>> {
+        let state = &self.state;
+        let staked = state
+            .stakers
+            .values()
+            .try_fold(Amount::default(), |total, staker| add(total, staker.amount))?;
+        let locked = state
+            .locked
+            .range(_chain_time + 1..)
+            .try_fold(Amount::default(), |total, (_, amount)| add(total, *amount))?;
+        let circulating = add(add(staked, locked)?, state.in_transit)
+            .ok()
+            .and_then(|not_circulating| state.total.checked_sub(not_circulating))
+            .ok_or_else(|| {
+                format!(
+                    "Staked , locked and in transit AVAX exceed the total supply at height {}",
+                    _height
+                )
+            })?;
+
+        Ok(SupplyReport {
+            height: _height,
+            chain_time: _chain_time,
+            total: state.total,
+            circulating,
+            staked,
+            locked,
+            in_transit: state.in_transit,
+        })
+    }
+}
+
+impl SupplyState {
+    /// The supply AvalancheGo computes rewards from , which includes the rewards not paid yet .
+    pub fn current_supply(&self) -> Result<Amount, Box<dyn Error>> {
+        self.stakers
+            .values()
+            .filter_map(|staker| staker.potential_reward)
+            .try_fold(self.total, add)
+    }
+
+    /// Promote the pending stakers starting by `_time` , as AvalancheGo does :
+    /// by start time , delegators before validators , then by tx id .
+    /// The reward of each staker is computed from the supply left by the ones promoted before it .
+    fn advance_time(
+        &mut self,
+        _time: i64,
+        _reward_config: &RewardConfig,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut promoted = Vec::new();
+        for (tx_id, staker) in &self.stakers {
+            if staker.potential_reward.is_none() && staker.start_time <= _time {
+                let priority = match staker.kind {
+                    StakerKind::Delegator => 0,
+                    _ => 1,
+                };
+                promoted.push((staker.start_time, priority, decode(tx_id)?, tx_id.clone()));
+            }
+        }
+        promoted.sort();
+
+        let mut current_supply = self.current_supply()?;
+        for (_, _, _, tx_id) in promoted {
+            if let Some(staker) = self.stakers.get_mut(&tx_id) {
+                let potential_reward = _reward_config.calculate(
+                    u64::try_from(staker.end_time - staker.start_time)?,
+                    staker.weight,
+                    current_supply,
+                )?;
+                trace!(
+                    "Staker {} is promoted with a potential reward of {}",
+                    tx_id,
+                    potential_reward
+                );
+                staker.potential_reward = Some(potential_reward);
+                current_supply = add(current_supply, potential_reward)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Txs outside of proposal blocks are applied as committed .
+    /// An aborted staker tx still burns its fee and spends its inputs , its stake is returned at once .
+    fn apply_pvm_tx(
+        &mut self,
+        _transaction: &Transaction,
+        _network: &Network,
+        _committed: bool,
+    ) -> Result<(), Box<dyn Error>> {
+        self.burn(_transaction.burned_fee(_network)?)?;
+
+        for transferable_input in &_transaction.base_tx.transferable_inputs {
+            if transferable_input.asset_id != _network.avax_asset_id {
+                continue;
+            }
+            if let Some(stakeable_locked_input) = &transferable_input.input.stakeable_locked_input {
+                let locked = self
+                    .locked
+                    .get_mut(&stakeable_locked_input.locktime)
+                    .and_then(|locked| {
+                        *locked = locked.checked_sub(stakeable_locked_input.input.amount)?;
+                        Some(locked)
+                    });
+                if locked.is_none() {
+                    return Err(format!(
+                        "Tx {} spends more AVAX locked until {} than known",
+                        _transaction.tx_id, stakeable_locked_input.locktime
+                    )
+                    .into());
+                }
+            }
+        }
+        for (locktime, amount) in
+            locked_outputs(&_transaction.base_tx.transferable_outputs, _network)?
+        {
+            add_locked(&mut self.locked, locktime, amount)?;
+        }
+
+        if let Some(import_tx) = &_transaction.import_tx {
+            let imported = import_tx
+                .transferable_inputs
+                .iter()
+                .filter(|transferable_input| transferable_input.asset_id == _network.avax_asset_id)
+                .filter_map(|transferable_input| {
+                    transferable_input.input.secp256k_transfer_input.as_ref()
+                })
+                .try_fold(Amount::default(), |total, input| add(total, input.amount))?;
+            self.import(imported)?;
+        }
+        if let Some(export_tx) = &_transaction.export_tx {
+            let exported = outputs_amount(&export_tx.transferable_outputs, _network)?;
+            self.in_transit = add(self.in_transit, exported)?;
+        }
+
+        let staker = if let Some(add_validator_tx) = &_transaction.add_validator_tx {
+            Some((
+                StakerKind::Validator,
+                &add_validator_tx.stake,
+                add_validator_tx.start_time,
+                add_validator_tx.end_time,
+                add_validator_tx.weight,
+            ))
+        } else {
+            _transaction
+                .add_delegator_tx
+                .as_ref()
+                .map(|add_delegator_tx| {
+                    (
+                        StakerKind::Delegator,
+                        &add_delegator_tx.stake,
+                        add_delegator_tx.start_time,
+                        add_delegator_tx.end_time,
+                        add_delegator_tx.weight,
+                    )
+                })
+        };
+        if let Some((kind, stake, start_time, end_time, weight)) = staker {
+            if !_committed {
+                for (locktime, amount) in locked_outputs(stake, _network)? {
+                    add_locked(&mut self.locked, locktime, amount)?;
+                }
+
+                return Ok(());
+            }
+
+            // The staker is pending , its reward is computed once it is promoted
+            let mut locked = BTreeMap::new();
+            for (locktime, amount) in locked_outputs(stake, _network)? {
+                add_locked(&mut locked, locktime, amount)?;
+            }
+            self.stakers.insert(
+                _transaction.tx_id.clone(),
+                StakedAmount {
+                    kind,
+                    amount: outputs_amount(stake, _network)?,
+                    locked,
+                    start_time,
+                    end_time,
+                    weight,
+                    potential_reward: None,
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Return the stake of a staker , and mint its reward when `_rewarded` .
+    fn unstake(&mut self, _tx_id: &str, _rewarded: bool) -> Result<(), Box<dyn Error>> {
+        let staker = match self.stakers.remove(_tx_id) {
+            Some(staker) => staker,
+            None => {
+                trace!("Staker {} is unknown , its reward is not counted", _tx_id);
+                return Ok(());
+            }
+        };
+
+        for (locktime, amount) in staker.locked {
+            add_locked(&mut self.locked, locktime, amount)?;
+        }
+        if _rewarded {
+            let potential_reward = staker
+                .potential_reward
+                .ok_or_else(|| format!("Staker {} is rewarded before it started", _tx_id))?;
+            self.total = add(self.total, potential_reward)?;
+        }
+
+        Ok(())
+    }
+
+    fn burn(&mut self, _fee: u64) -> Result<(), Box<dyn Error>> {
+        self.total = self
+            .total
+            .checked_sub(Amount(_fee))
+            .ok_or_else(|| format!("Burned fee {} exceeds the total supply", _fee))?;
+
+        Ok(())
+    }
+
+    fn import(&mut self, _imported: Amount) -> Result<(), Box<dyn Error>> {
+        self.in_transit = self.in_transit.checked_sub(_imported).ok_or_else(|| {
+            format!(
+                "Imported {} exceeds the {} AVAX in transit",
+                _imported, self.in_transit
+            )
+        })?;
+
+        Ok(())
+    }
+}
+
+fn add(_total: Amount, _amount: Amount) -> Result<Amount, Box<dyn Error>> {
+    _total
+        .checked_add(_amount)
+        .ok_or_else(|| "AVAX supply overflows".into())
+}
+
+fn add_locked(
+    _locked: &mut BTreeMap<i64, Amount>,
+    _locktime: i64,
+    _amount: Amount,
+) -> Result<(), Box<dyn Error>> {
+    let locked = _locked.entry(_locktime).or_default();
+    *locked = add(*locked, _amount)?;
+
+    Ok(())
+}
+
+fn fee(_debited: Amount, _credited: Amount) -> Result<Amount, Box<dyn Error>> {
+    _debited
+        .checked_sub(_credited)
+        .ok_or_else(|| "Atomic tx credits more AVAX than it debits".into())
+}
+
+/// The AVAX of P-chain outputs , locked or not .
+fn outputs_amount(
+    _outputs: &[TransferableOutput],
+    _network: &Network,
+) -> Result<Amount, Box<dyn Error>> {
+    _outputs
+        .iter()
+        .filter(|transferable_output| transferable_output.asset_id == _network.avax_asset_id)
+        .filter_map(|transferable_output| {
+            let output = &transferable_output.output;
+            match &output.stakeable_locked_output {
+                Some(stakeable_locked_output) => {
+                    stakeable_locked_output.secp256k_transfer_output.as_ref()
+                }
+                None => output.secp256k1_transfer_output.as_ref(),
+            }
+        })
+        .filter_map(|transfer_output| transfer_output.amount)
+        .try_fold(Amount::default(), |total, amount| add(total, amount))
+}
+
+/// The AVAX of stakeable locked P-chain outputs , by locktime .
+fn locked_outputs(
+    _outputs: &[TransferableOutput],
+    _network: &Network,
+) -> Result<Vec<(i64, Amount)>, Box<dyn Error>> {
+    Ok(_outputs
+        .iter()
+        .filter(|transferable_output| transferable_output.asset_id == _network.avax_asset_id)
+        .filter_map(|transferable_output| {
+            let stakeable_locked_output = transferable_output
+                .output
+                .stakeable_locked_output
+                .as_ref()?;
+            let amount = stakeable_locked_output
+                .secp256k_transfer_output
+                .as_ref()?
+                .amount?;
+            Some((stakeable_locked_output.locktime, amount))
+        })
+        .collect())
+}
+
+fn avm_inputs_amount(
+    _inputs: &[AvmTransferableInput],
+    _network: &Network,
+) -> Result<Amount, Box<dyn Error>> {
+    _inputs
+        .iter()
+        .filter(|transferable_input| transferable_input.asset_id == _network.avax_asset_id)
+        .try_fold(Amount::default(), |total, transferable_input| {
+            add(total, transferable_input.input.amount)
+        })
+}
+
+fn avm_outputs_amount(
+    _outputs: &[AvmTransferableOutput],
+    _network: &Network,
+) -> Result<Amount, Box<dyn Error>> {
+    _outputs
+        .iter()
+        .filter(|transferable_output| transferable_output.asset_id == _network.avax_asset_id)
+        .filter_map(|transferable_output| transferable_output.output.amount)
+        .try_fold(Amount::default(), add)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm::parser::fixtures::base_tx;
+    use crate::cvm::parser::fixtures::{export_tx, import_tx};
+    use crate::pvm::parser::fixtures::{
+        add_validator_proposal_block, advance_time_proposal_block, option_block,
+        reward_validator_proposal_block,
+    };
+
+    const AVAX: u64 = 1_000_000_000;
+    const GENESIS_TIME: i64 = 1_599_696_000;
+    /// The locktimes of the stakeable locked inputs and stake of `add_validator_proposal_block`
+    const LOCKTIMES: [i64; 3] = [1_607_472_000, 1_623_024_000, 1_630_800_000];
+
+    /// 360M AVAX , of which 2_250 AVAX are locked until each of `LOCKTIMES` .
+    fn tracker() -> SupplyTracker {
+        let mut allocations = vec![Allocation {
+            amount: Amount(360_000_000 * AVAX - 6_750 * AVAX),
+            locktime: 0,
+        }];
+        allocations.extend(LOCKTIMES.iter().map(|locktime| Allocation {
+            amount: Amount(2_250 * AVAX),
+            locktime: *locktime,
+        }));
+
+        SupplyTracker::new(
+            Network::mainnet(),
+            RewardConfig::mainnet(),
+            GENESIS_TIME,
+            &allocations,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn apply_pvm_block_01() {
+        let proposal = add_validator_proposal_block();
+        let transaction = proposal.block_data.transactions[0].as_ref().unwrap();
+        let fee = transaction.burned_fee(&Network::mainnet()).unwrap();
+
+        let mut tracker = tracker();
+        let report = tracker.apply_pvm_block(&proposal).unwrap();
+        assert_eq!(
+            (report.staked, report.locked),
+            (Amount(0), Amount(6_750 * AVAX)),
+            "Checking if the proposal waits for its commit"
+        );
+
+        let report = tracker
+            .apply_pvm_block(&option_block(COMMIT_BLOCK_TYPE_ID, &proposal))
+            .unwrap();
+        assert_eq!(
+            report,
+            SupplyReport {
+                height: proposal.block_data.height + 1,
+                chain_time: GENESIS_TIME,
+                total: Amount(360_000_000 * AVAX - fee),
+                circulating: Amount(360_000_000 * AVAX - fee - 9_000 * AVAX),
+                staked: Amount(9_000 * AVAX),
+                locked: Amount(0),
+                in_transit: Amount(0),
+            },
+            "Checking if the stake , locked parts included , is staked once committed"
+        );
+        assert_eq!(
+            tracker.state.stakers[&transaction.tx_id].potential_reward, None,
+            "Checking if the reward of a pending staker is not computed yet"
+        );
+
+        let advance_time = advance_time_proposal_block();
+        tracker.apply_pvm_block(&advance_time).unwrap();
+        tracker
+            .apply_pvm_block(&option_block(COMMIT_BLOCK_TYPE_ID, &advance_time))
+            .unwrap();
+        let potential_reward = tracker.state.stakers[&transaction.tx_id]
+            .potential_reward
+            .unwrap();
+
+        let mut reward = reward_validator_proposal_block();
+        reward.block_data.transactions[0]
+            .as_mut()
+            .unwrap()
+            .reward_validator_tx
+            .as_mut()
+            .unwrap()
+            .tx_id = transaction.tx_id.clone();
+        let mut aborted = tracker.clone();
+
+        tracker.apply_pvm_block(&reward).unwrap();
+        let report = tracker
+            .apply_pvm_block(&option_block(COMMIT_BLOCK_TYPE_ID, &reward))
+            .unwrap();
+        assert_eq!(
+            (report.total, report.staked, report.locked),
+            (
+                Amount(360_000_000 * AVAX - fee + potential_reward.0),
+                Amount(0),
+                Amount(6_750 * AVAX)
+            ),
+            "Checking if the reward is minted and the locked stake locked again"
+        );
+        assert_eq!(
+            tracker.reports.len(),
+            6,
+            "Checking if a report is kept per height"
+        );
+
+        aborted.apply_pvm_block(&reward).unwrap();
+        let report = aborted
+            .apply_pvm_block(&option_block(ABORT_BLOCK_TYPE_ID, &reward))
+            .unwrap();
+        assert_eq!(
+            (report.total, report.staked, report.locked),
+            (
+                Amount(360_000_000 * AVAX - fee),
+                Amount(0),
+                Amount(6_750 * AVAX)
+            ),
+            "Checking if an aborted reward returns the stake without minting"
+        );
+    }
+
+    #[test]
+    fn apply_pvm_block_02() {
+        let proposal = add_validator_proposal_block();
+        let fee = proposal.block_data.transactions[0]
+            .as_ref()
+            .unwrap()
+            .burned_fee(&Network::mainnet())
+            .unwrap();
+
+        let mut tracker = tracker();
+        tracker.apply_pvm_block(&proposal).unwrap();
+        let report = tracker
+            .apply_pvm_block(&option_block(ABORT_BLOCK_TYPE_ID, &proposal))
+            .unwrap();
+        assert_eq!(
+            (report.total, report.staked, report.locked),
+            (
+                Amount(360_000_000 * AVAX - fee),
+                Amount(0),
+                Amount(6_750 * AVAX)
+            ),
+            "Checking if an aborted staker tx burns its fee and locks its locked stake again"
+        );
+        assert!(
+            tracker.state.stakers.is_empty(),
+            "Checking if the aborted staker is not added"
+        );
+    }
+
+    #[test]
+    fn apply_avm_tx_01() {
+        let mut tracker = tracker();
+        tracker.apply_avm_tx(&base_tx()).unwrap();
+        assert_eq!(
+            tracker.state.total,
+            Amount(360_000_000 * AVAX - 1_000_000),
+            "Checking if the fee of an X-chain tx is burned"
+        );
+    }
+
+    #[test]
+    fn apply_cvm_tx_01() {
+        let mut tracker = tracker();
+        assert!(
+            tracker.apply_cvm_tx(&import_tx()).is_err(),
+            "Checking if importing more than in transit is rejected"
+        );
+
+        tracker.apply_cvm_tx(&export_tx()).unwrap();
+        assert_eq!(
+            (tracker.state.total, tracker.state.in_transit),
+            (Amount(360_000_000 * AVAX - 1_000_000), Amount(999_000_000)),
+            "Checking if the export burns its fee and puts its outputs in transit"
+        );
+
+        tracker.apply_cvm_tx(&import_tx()).unwrap();
+        assert_eq!(
+            (tracker.state.total, tracker.state.in_transit),
+            (
+                Amount(360_000_000 * AVAX - 1_000_000 - 999_999),
+                Amount(499_000_000)
+            ),
+            "Checking if the import burns its fee and takes its inputs out of transit"
+        );
+    }
+    #[test]
+    fn apply_pvm_block_03() {
+        const START_TIME: i64 = 1_606_000_000;
+        const DURATION: u64 = 365 * 24 * 60 * 60;
+
+        let validator_tx_id = add_validator_proposal_block().block_data.transactions[0]
+            .as_ref()
+            .unwrap()
+            .tx_id
+            .clone();
+        let delegator_tx_id = reward_validator_proposal_block().block_data.transactions[0]
+            .as_ref()
+            .unwrap()
+            .tx_id
+            .clone();
+
+        let mut tracker = tracker();
+        for (tx_id, kind, weight) in [
+            (&validator_tx_id, StakerKind::Validator, 2_000 * AVAX),
+            (&delegator_tx_id, StakerKind::Delegator, 1_000 * AVAX),
+        ] {
+            tracker.state.stakers.insert(
+                tx_id.clone(),
+                StakedAmount {
+                    kind,
+                    amount: Amount(weight),
+                    locked: BTreeMap::new(),
+                    start_time: START_TIME,
+                    end_time: START_TIME + DURATION as i64,
+                    weight: Amount(weight),
+                    potential_reward: None,
+                },
+            );
+        }
+        let supply = tracker.state.current_supply().unwrap();
+
+        let advance_time = advance_time_proposal_block();
+        tracker.apply_pvm_block(&advance_time).unwrap();
+        assert!(
+            tracker
+                .state
+                .stakers
+                .values()
+                .all(|staker| staker.potential_reward.is_none()),
+            "Checking if the stakers wait for the advance time tx to be committed"
+        );
+        tracker
+            .apply_pvm_block(&option_block(COMMIT_BLOCK_TYPE_ID, &advance_time))
+            .unwrap();
+
+        let config = RewardConfig::mainnet();
+        let delegator_reward = config
+            .calculate(DURATION, Amount(1_000 * AVAX), supply)
+            .unwrap();
+        let validator_reward = config
+            .calculate(
+                DURATION,
+                Amount(2_000 * AVAX),
+                add(supply, delegator_reward).unwrap(),
+            )
+            .unwrap();
+        assert_eq!(
+            tracker.state.stakers[&delegator_tx_id].potential_reward,
+            Some(delegator_reward),
+            "Checking if the delegator is promoted first , from the supply before the promotions"
+        );
+        assert_eq!(
+            tracker.state.stakers[&validator_tx_id].potential_reward,
+            Some(validator_reward),
+            "Checking if the validator reward is computed from the supply including the delegator reward"
+        );
+        assert_ne!(
+            validator_reward,
+            config
+                .calculate(DURATION, Amount(2_000 * AVAX), supply)
+                .unwrap(),
+            "Checking if the order of the promotions changes the rewards"
+        );
+        assert_eq!(
+            tracker.state.current_supply().unwrap(),
+            add(add(supply, delegator_reward).unwrap(), validator_reward).unwrap(),
+            "Checking if the potential rewards are added to the current supply"
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use bech32::FromBase32;
+
+use std::error::Error;
+
+/// Take an address as formatted by the parsers , e.g. `X-avax1...` , and return its 20 bytes .
+/// The chain prefix is optional .
+pub fn address_bytes(_address: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    let bech32_address = match _address.split_once('-') {
+        Some((_, bech32_address)) => bech32_address,
+        None => _address,
+    };
+
+    let (_, data) = bech32::decode(bech32_address)?;
+    let bytes = Vec::<u8>::from_base32(&data)?;
+    if bytes.len() != 20 {
+        return Err(format!("Invalid address {} : expected 20 bytes", _address).into());
+    }
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn address_bytes_01() {
+        assert_eq!(
+            address_bytes("X-avax1d3qnae4dej47yd0pd28th7nxelwu6fp5mncjnd").unwrap(),
+            Vec::from([
+                108, 65, 62, 230, 173, 204, 171, 226, 53, 225, 106, 142, 187, 250, 102, 207, 221,
+                205, 36, 52
+            ]),
+            "Testing decoding of a X-chain address"
+        );
+        assert!(
+            address_bytes("X-avax1d3qnae4dej47yd0pd28th7nxelwu6fp5mncjne").is_err(),
+            "Testing a wrong checksum is rejected"
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use std::error::Error;
+use std::fmt;
+
+/// A denomination is at most 32 in AvalancheGo .
+pub const MAX_DENOMINATION: u8 = 32;
+
+/// A raw amount of an asset , in its smallest unit .
+/// Serialized as a raw integer , see the `decimal` module to serialize AVAX amounts as decimal strings .
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub struct Amount(pub u64);
+
+impl Amount {
+    pub fn checked_add(self, _other: Amount) -> Option<Amount> {
+        self.0.checked_add(_other.0).map(Amount)
+    }
+
+    pub fn checked_sub(self, _other: Amount) -> Option<Amount> {
+        self.0.checked_sub(_other.0).map(Amount)
+    }
+
+    pub fn to_be_bytes(self) -> [u8; 8] {
+        self.0.to_be_bytes()
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Write the amount with `_denomination` decimals , without trailing zeros , e.g. `1.5` .
+    pub fn format(self, _denomination: u8) -> String {
+        let denomination = _denomination.min(MAX_DENOMINATION);
+        // 10^32 still fits in a u128
+        let unit = 10u128.pow(u32::from(denomination));
+        let whole = u128::from(self.0) / unit;
+        let fraction = u128::from(self.0) % unit;
+        if fraction == 0 {
+            return whole.to_string();
+        }
+
+        let fraction = format!("{:0width$}", fraction, width = usize::from(denomination));
+        format!("{}.{}", whole, fraction.trim_end_matches('0'))
+    }
+
+    /// Read a decimal string such as `1.5` with `_denomination` decimals .
+    pub fn parse(_value: &str, _denomination: u8) -> Result<Amount, Box<dyn Error>> {
+        let denomination = usize::from(_denomination.min(MAX_DENOMINATION));
+        let (whole, fraction) = match _value.find('.') {
+            Some(index) => (&_value[..index], &_value[index + 1..]),
+            None => (_value, ""),
+        };
+        let is_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+        if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) {
+            return Err(format!("Invalid amount {}", _value).into());
+        }
+        if fraction.len() > denomination {
+            return Err(
+                format!("Amount {} has more than {} decimals", _value, denomination).into(),
+            );
+        }
+
+        let raw = format!("{}{:0<width$}", whole, fraction, width = denomination);
+        let raw = raw
+            .parse::<u64>()
+            .map_err(|_| format!("Amount {} overflows", _value))?;
+
+        Ok(Amount(raw))
+    }
+}
+
+impl From<u64> for Amount {
+    fn from(_amount: u64) -> Amount {
+        Amount(_amount)
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Serialize an AVAX amount as a decimal string , use with `#[serde(with = "crate::utils::amount::decimal")]` .
+pub mod decimal {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::Amount;
+    use crate::asset::AVAX_DENOMINATION;
+
+    pub fn serialize<S: Serializer>(_amount: &Amount, _serializer: S) -> Result<S::Ok, S::Error> {
+        _serializer.serialize_str(&_amount.format(AVAX_DENOMINATION))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(_deserializer: D) -> Result<Amount, D::Error> {
+        let value = String::deserialize(_deserializer)?;
+        Amount::parse(&value, AVAX_DENOMINATION)
+            .map_err(|error| D::Error::custom(error.to_string()))
+    }
+}
+
+/// Serialize an amount as a raw integer , the default , use with `#[serde(with = "crate::utils::amount::raw")]` .
+pub mod raw {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::Amount;
+
+    pub fn serialize<S: Serializer>(_amount: &Amount, _serializer: S) -> Result<S::Ok, S::Error> {
+        _serializer.serialize_u64(_amount.0)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(_deserializer: D) -> Result<Amount, D::Error> {
+        Ok(Amount(u64::deserialize(_deserializer)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_parse_01() {
+        assert_eq!(
+            Amount(1_500_000_000).format(9),
+            "1.5",
+            "Checking if the amount is formatted with its decimals"
+        );
+        assert_eq!(
+            Amount::parse("1.5", 9).unwrap(),
+            Amount(1_500_000_000),
+            "Checking if the amount is parsed with its decimals"
+        );
+        assert_eq!(
+            Amount::parse(&Amount(u64::MAX).format(9), 9).unwrap(),
+            Amount(u64::MAX),
+            "Checking if the largest amount round trips"
+        );
+        assert!(
+            Amount::parse("1.0000000001", 9).is_err(),
+            "Checking if too many decimals are rejected"
+        );
+        assert!(
+            Amount::parse("18446744073.709551616", 9).is_err(),
+            "Checking if overflows are rejected"
+        );
+        assert_eq!(
+            Amount(1).checked_sub(Amount(2)),
+            None,
+            "Checking if underflows are rejected"
+        );
+    }
+
+    #[test]
+    fn serde_01() {
+        #[derive(Serialize, Deserialize)]
+        struct Stake {
+            #[serde(with = "decimal")]
+            weight: Amount,
+            amount: Amount,
+        }
+
+        let json = serde_json::to_string(&Stake {
+            weight: Amount(2_000_000_000_000),
+            amount: Amount(1_000),
+        })
+        .unwrap();
+        assert_eq!(
+            json, r#"{"weight":"2000","amount":1000}"#,
+            "Checking if the serde modes are applied"
+        );
+        let stake: Stake = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            stake.weight,
+            Amount(2_000_000_000_000),
+            "Checking if decimal strings are read back"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+
+use crate::network::Network;
+use crate::utils::amount::Amount;
+
+/// Per asset totals of what a tx consumes and produces , and what it burns .
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct BalanceSummary {
+    pub inputs: BTreeMap<String, u64>,
+    pub outputs: BTreeMap<String, u64>,
+    pub burned: BTreeMap<String, u64>,
+    /// The burned amount of the network's AVAX asset
+    pub fee: u64,
+}
+
+impl BalanceSummary {
+    pub fn add_input(&mut self, _asset_id: &str, _amount: Amount) -> Result<(), Box<dyn Error>> {
+        add_amount(&mut self.inputs, _asset_id, _amount)
+    }
+
+    pub fn add_output(&mut self, _asset_id: &str, _amount: Amount) -> Result<(), Box<dyn Error>> {
+        add_amount(&mut self.outputs, _asset_id, _amount)
+    }
+
+    /// Compute the burned amounts once every input and output has been added .
+    pub fn finish(mut self, _network: &Network) -> Result<BalanceSummary, Box<dyn Error>> {
+        for (asset_id, input) in &self.inputs {
+            let output = self.outputs.get(asset_id).copied().unwrap_or(0);
+            let burned = input
+                .checked_sub(output)
+                .ok_or_else(|| format!("Outputs of asset {} exceed its inputs", asset_id))?;
+            self.burned.insert(asset_id.clone(), burned);
+        }
+
+        for (asset_id, output) in &self.outputs {
+            if *output > 0 && !self.inputs.contains_key(asset_id) {
+                return Err(format!("Outputs of asset {} exceed its inputs", asset_id).into());
+            }
+        }
+
+        self.fee = self
+            .burned
+            .get(&_network.avax_asset_id)
+            .copied()
+            .unwrap_or(0);
+
+        Ok(self)
+    }
+}
+
+fn add_amount(
+    _totals: &mut BTreeMap<String, u64>,
+    _asset_id: &str,
+    _amount: Amount,
+) -> Result<(), Box<dyn Error>> {
+    let total = _totals.entry(_asset_id.to_string()).or_insert(0);
+    *total = total
+        .checked_add(_amount.0)
+        .ok_or_else(|| format!("Total amount of asset {} overflows", _asset_id))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finish_01() {
+        let network = Network::mainnet();
+        let mut summary = BalanceSummary::default();
+        summary
+            .add_input(&network.avax_asset_id, Amount(3_000_000))
+            .unwrap();
+        summary
+            .add_output(&network.avax_asset_id, Amount(2_000_000))
+            .unwrap();
+        assert_eq!(
+            summary.finish(&network).unwrap().fee,
+            1_000_000,
+            "Checking if the fee is the burned AVAX"
+        );
+
+        let mut summary = BalanceSummary::default();
+        summary
+            .add_input(&network.avax_asset_id, Amount(1))
+            .unwrap();
+        summary
+            .add_output(&network.avax_asset_id, Amount(2))
+            .unwrap();
+        assert!(
+            summary.finish(&network).is_err(),
+            "Checking if outputs exceeding inputs are rejected"
+        );
+
+        let mut summary = BalanceSummary::default();
+        summary.add_input("asset", Amount(i64::MAX as u64)).unwrap();
+        summary.add_input("asset", Amount(i64::MAX as u64)).unwrap();
+        assert!(
+            summary.add_input("asset", Amount(i64::MAX as u64)).is_err(),
+            "Checking if overflows are rejected"
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use crate::avm::parser::credential_parser::Credential;
+use crate::utils::address::address_bytes;
+use crate::utils::cb58::decode;
+
+/// The order of indices which sorts `_keys` , equal keys keep their original order .
+pub fn sorted_order<K: Ord>(_keys: &[K]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0.._keys.len()).collect();
+    order.sort_by(|a, b| _keys[*a].cmp(&_keys[*b]));
+
+    order
+}
+
+/// Reorder `_items` in place so that the item at `i` is the one previously at `_order[i]` .
+pub fn permute<T>(_items: &mut [T], _order: &[usize]) {
+    for index in 0.._order.len() {
+        // Items before `index` are already in place , follow the swaps to find where ours went
+        let mut source = _order[index];
+        while source < index {
+            source = _order[source];
+        }
+        _items.swap(index, source);
+    }
+}
+
+/// Sort `_items` by `_keys` and apply the same reordering to the matching credentials .
+/// Credentials are left untouched when there is not exactly one per item , e.g. for an unsigned tx .
+pub fn sort_with_credentials<T>(
+    _items: &mut [T],
+    _keys: &[Vec<u8>],
+    _credentials: &mut [Credential],
+) {
+    let order = sorted_order(_keys);
+    permute(_items, &order);
+    if _credentials.len() == order.len() {
+        permute(_credentials, &order);
+    }
+}
+
+/// Signature indices are sorted ascending , the signatures of the credential follow them .
+pub fn sort_signature_indices<I: Ord>(_indices: &mut [I], _credential: Option<&mut Credential>) {
+    let order = sorted_order(_indices);
+    permute(_indices, &order);
+    if let Some(credential) = _credential {
+        if credential.signatures.len() == order.len() {
+            permute(&mut credential.signatures, &order);
+        }
+    }
+}
+
+/// Addresses are sorted by their bytes , not by their bech32 representation .
+pub fn sort_addresses(_addresses: &mut [String]) {
+    let keys: Vec<Vec<u8>> = _addresses
+        .iter()
+        .map(|address| address_bytes(address).unwrap_or_default())
+        .collect();
+    permute(_addresses, &sorted_order(&keys));
+}
+
+/// The bytes inputs are sorted by : the tx id then the output index of the UTXO they spend .
+pub fn utxo_key(_tx_id: &str, _utxo_index: i32) -> Vec<u8> {
+    let mut key = decode(_tx_id).unwrap_or_default();
+    key.extend_from_slice(&(_utxo_index as u32).to_be_bytes());
+
+    key
+}
+
+/// Split the credentials of a tx between the first `_count` inputs and the following ones .
+pub fn split_credentials(
+    _credentials: &mut [Credential],
+    _count: usize,
+) -> (&mut [Credential], &mut [Credential]) {
+    let at = _count.min(_credentials.len());
+
+    _credentials.split_at_mut(at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn permute_01() {
+        let keys = vec![3, 0, 4, 1, 2];
+        let mut items = vec!["d", "a", "e", "b", "c"];
+        permute(&mut items, &sorted_order(&keys));
+        assert_eq!(
+            items,
+            vec!["a", "b", "c", "d", "e"],
+            "Checking if items are reordered"
+        );
+    }
+}
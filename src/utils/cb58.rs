@@ -1,4 +1,6 @@
 use ring::digest;
+use rust_base58::FromBase58;
+use std::error::Error;
 
 /// Take a Vector of u8 (bytes) as input and encode it as a base58 string .
 pub fn encode(_raw_msg: &[u8]) -> Vec<u8> {
@@ -9,6 +11,23 @@ pub fn encode(_raw_msg: &[u8]) -> Vec<u8> {
     generated
 }
 
+/// Take a cb58 string as input , check its 4 bytes checksum and return the bytes it encodes .
+pub fn decode(_cb58: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut decoded = _cb58
+        .from_base58()
+        .map_err(|err| format!("Invalid cb58 string {} : {}", _cb58, err))?;
+    if decoded.len() < 4 {
+        return Err(format!("Invalid cb58 string {} : too short", _cb58).into());
+    }
+
+    let checksum = decoded.split_off(decoded.len() - 4);
+    if encode(&decoded)[decoded.len()..] != checksum[..] {
+        return Err(format!("Invalid cb58 string {} : wrong checksum", _cb58).into());
+    }
+
+    Ok(decoded)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -28,4 +47,20 @@ mod tests {
             "Testing encoding of 32 bytes into a base58 string"
         );
     }
+
+    #[test]
+    fn decode_01() {
+        assert_eq!(
+            decode("FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z").unwrap(),
+            Vec::from([
+                33, 230, 115, 23, 203, 196, 190, 42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245,
+                34, 116, 185, 214, 5, 223, 37, 145, 178, 48, 39, 168, 125, 255
+            ]),
+            "Testing decoding of the AVAX asset id"
+        );
+        assert!(
+            decode("FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5a").is_err(),
+            "Testing a wrong checksum is rejected"
+        );
+    }
 }
@@ -5,6 +5,11 @@ pub fn pop_i64(barry: &[u8]) -> i64 {
     i64::from_be_bytes(x)
 }
 
+pub fn pop_u64(barry: &[u8]) -> u64 {
+    let x: [u8; 8] = barry.try_into().expect("slice with incorrect length");
+    u64::from_be_bytes(x)
+}
+
 pub fn pop_u32(barry: &[u8]) -> u32 {
     let x: [u8; 4] = barry.try_into().expect("slice with incorrect length");
     u32::from_be_bytes(x)
@@ -165,4 +170,17 @@ mod tests {
             raw_bytes, result
         );
     }
+
+    #[test]
+    fn convert_u64_01() {
+        let raw_bytes: Vec<u8> = Vec::from([255, 255, 255, 255, 255, 255, 255, 255]);
+        let result = pop_u64(&raw_bytes);
+        assert_eq!(
+            result,
+            u64::MAX,
+            "Converting vec {:?} into u64 {:?}",
+            raw_bytes,
+            result
+        );
+    }
 }
@@ -0,0 +1,134 @@
+use std::error::Error;
+
+use crate::network::Network;
+
+/// The static fees , in nAVAX , charged per tx type on a network .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FeeConfig {
+    /// Fee of base , import , export and operation txs
+    pub tx_fee: u64,
+    pub create_asset_tx_fee: u64,
+    pub create_subnet_tx_fee: u64,
+    pub create_blockchain_tx_fee: u64,
+    pub add_primary_network_validator_fee: u64,
+    pub add_primary_network_delegator_fee: u64,
+    pub add_subnet_validator_fee: u64,
+}
+
+impl FeeConfig {
+    pub fn mainnet() -> FeeConfig {
+        FeeConfig {
+            tx_fee: 1_000_000,
+            create_asset_tx_fee: 10_000_000,
+            create_subnet_tx_fee: 1_000_000_000,
+            create_blockchain_tx_fee: 1_000_000_000,
+            add_primary_network_validator_fee: 0,
+            add_primary_network_delegator_fee: 0,
+            add_subnet_validator_fee: 1_000_000,
+        }
+    }
+
+    pub fn fuji() -> FeeConfig {
+        FeeConfig {
+            tx_fee: 1_000_000,
+            create_asset_tx_fee: 10_000_000,
+            create_subnet_tx_fee: 100_000_000,
+            create_blockchain_tx_fee: 100_000_000,
+            add_primary_network_validator_fee: 0,
+            add_primary_network_delegator_fee: 0,
+            add_subnet_validator_fee: 1_000_000,
+        }
+    }
+
+    /// Return the fees of the known network matching the network id found in a tx .
+    pub fn from_network_id(_network_id: u32) -> Option<FeeConfig> {
+        match _network_id {
+            1 => Some(FeeConfig::mainnet()),
+            5 => Some(FeeConfig::fuji()),
+            _ => None,
+        }
+    }
+}
+
+/// Txs whose expected fee can be computed before signing .
+pub trait EstimateFee {
+    /// The static fee the tx type is charged .
+    fn estimate_fee(&self, _config: &FeeConfig) -> u64;
+
+    /// The AVAX the tx actually burns .
+    fn burned_fee(&self, _network: &Network) -> Result<u64, Box<dyn Error>>;
+}
+
+pub fn estimate_fee<T: EstimateFee>(_tx: &T, _config: &FeeConfig) -> u64 {
+    _tx.estimate_fee(_config)
+}
+
+/// Whether the tx burns at least the fee of its type .
+pub fn burns_enough<T: EstimateFee>(
+    _tx: &T,
+    _config: &FeeConfig,
+    _network: &Network,
+) -> Result<bool, Box<dyn Error>> {
+    Ok(_tx.burned_fee(_network)? >= _tx.estimate_fee(_config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm::parser::fixtures::{base_tx, create_asset_tx};
+    use crate::pvm::parser::fixtures::create_subnet_block;
+
+    #[test]
+    fn from_network_id_01() {
+        assert_eq!(
+            FeeConfig::from_network_id(Network::fuji().network_id),
+            Some(FeeConfig::fuji()),
+            "Checking if the fuji fees are found"
+        );
+        assert_eq!(
+            FeeConfig::from_network_id(12345),
+            None,
+            "Checking if unknown networks have no fees"
+        );
+    }
+
+    #[test]
+    fn estimate_fee_01() {
+        let config = FeeConfig::mainnet();
+        assert_eq!(
+            estimate_fee(&base_tx(), &config),
+            1_000_000,
+            "Checking if a base tx is charged the tx fee"
+        );
+        assert_eq!(
+            estimate_fee(&create_asset_tx(), &config),
+            10_000_000,
+            "Checking if a create asset tx is charged the create asset fee"
+        );
+
+        let block = create_subnet_block();
+        let create_subnet_tx = block.block_data.transactions[0].as_ref().unwrap();
+        assert_eq!(
+            estimate_fee(create_subnet_tx, &config),
+            1_000_000_000,
+            "Checking if a create subnet tx is charged the create subnet fee"
+        );
+    }
+
+    #[test]
+    fn burns_enough_01() {
+        let network = Network::mainnet();
+        assert!(
+            burns_enough(&create_asset_tx(), &FeeConfig::mainnet(), &network).unwrap(),
+            "Checking if a create asset tx burning its fee burns enough"
+        );
+
+        // The create subnet tx burned 0.01 AVAX , less than the current fee
+        let block = create_subnet_block();
+        let create_subnet_tx = block.block_data.transactions[0].as_ref().unwrap();
+        assert!(
+            !burns_enough(create_subnet_tx, &FeeConfig::mainnet(), &network).unwrap(),
+            "Checking if a tx burning less than the current fee does not burn enough"
+        );
+    }
+}
@@ -1,3 +1,10 @@
+pub mod address;
+pub mod amount;
+pub mod balance;
+pub mod canonical;
 pub mod cb58;
 pub mod conversion;
+pub mod fee;
 pub mod misc;
+pub mod signature;
+pub mod syntax;
@@ -0,0 +1,35 @@
+use libsecp256k1::{recover, Message, RecoveryId, Signature};
+use ring::digest;
+use ripemd160::{Digest, Ripemd160};
+
+use std::error::Error;
+
+/// A secp256k1 signature as found in credentials : r , s and the recovery id .
+pub const SIGNATURE_SIZE: usize = 65;
+
+/// Recover the 20 bytes address which signed `_message` , i.e. the unsigned tx bytes .
+/// The signed hash is the sha256 of the message , the address the ripemd160 of the sha256 of the compressed public key .
+pub fn recover_address(_message: &[u8], _signature: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+    if _signature.len() != SIGNATURE_SIZE {
+        return Err(format!(
+            "Invalid signature : expected {} bytes , got {}",
+            SIGNATURE_SIZE,
+            _signature.len()
+        )
+        .into());
+    }
+
+    let hash = digest::digest(&digest::SHA256, _message);
+    let message = Message::parse_slice(hash.as_ref())
+        .map_err(|err| format!("Invalid message hash : {:?}", err))?;
+    let signature = Signature::parse_standard_slice(&_signature[..64])
+        .map_err(|err| format!("Invalid signature : {:?}", err))?;
+    let recovery_id = RecoveryId::parse(_signature[64])
+        .map_err(|err| format!("Invalid recovery id : {:?}", err))?;
+    let public_key = recover(&message, &signature, &recovery_id)
+        .map_err(|err| format!("Cannot recover the public key : {:?}", err))?;
+
+    let public_key_hash = digest::digest(&digest::SHA256, &public_key.serialize_compressed());
+
+    Ok(Ripemd160::digest(public_key_hash.as_ref()).to_vec())
+}
@@ -0,0 +1,155 @@
+use std::fmt;
+
+use crate::utils::address::address_bytes;
+use crate::utils::amount::Amount;
+use crate::utils::cb58::decode;
+
+/// The maximum size of a memo accepted by AvalancheGo .
+pub const MAX_MEMO_SIZE: usize = 256;
+
+/// A rule of AvalancheGo's syntactic verification that a tx breaks .
+/// `path` locates the faulty field , e.g. `outputs[2].addresses` .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SyntaxViolation {
+    pub path: String,
+    pub rule: String,
+}
+
+impl fmt::Display for SyntaxViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} : {}", self.path, self.rule)
+    }
+}
+
+pub fn violation(_violations: &mut Vec<SyntaxViolation>, _path: &str, _rule: &str) {
+    _violations.push(SyntaxViolation {
+        path: _path.to_string(),
+        rule: _rule.to_string(),
+    });
+}
+
+pub fn verify_memo(_violations: &mut Vec<SyntaxViolation>, _memo: &[u8]) {
+    if _memo.len() > MAX_MEMO_SIZE {
+        violation(
+            _violations,
+            "memo",
+            &format!("memo is longer than {} bytes", MAX_MEMO_SIZE),
+        );
+    }
+}
+
+/// The owners of an output must be spendable by at most `threshold` sorted and unique addresses .
+pub fn verify_owners(
+    _violations: &mut Vec<SyntaxViolation>,
+    _path: &str,
+    _threshold: i32,
+    _addresses: &[String],
+) {
+    if _threshold as usize > _addresses.len() {
+        violation(
+            _violations,
+            &format!("{}.threshold", _path),
+            "threshold exceeds the number of addresses",
+        );
+    } else if _threshold == 0 && !_addresses.is_empty() {
+        violation(
+            _violations,
+            &format!("{}.threshold", _path),
+            "threshold is zero but addresses are set",
+        );
+    }
+
+    let mut addresses = Vec::new();
+    for (index, address) in _addresses.iter().enumerate() {
+        match address_bytes(address) {
+            Ok(bytes) => addresses.push(bytes),
+            Err(err) => {
+                violation(
+                    _violations,
+                    &format!("{}.addresses[{}]", _path, index),
+                    &err.to_string(),
+                );
+                return;
+            }
+        }
+    }
+    if !is_sorted_and_unique(&addresses) {
+        violation(
+            _violations,
+            &format!("{}.addresses", _path),
+            "addresses are not sorted and unique",
+        );
+    }
+}
+
+pub fn verify_amount(_violations: &mut Vec<SyntaxViolation>, _path: &str, _amount: Amount) {
+    if _amount.is_zero() {
+        violation(_violations, &format!("{}.amount", _path), "amount is zero");
+    }
+}
+
+/// Outputs are sorted by asset id , then by their serialized bytes , `_keys` holds both .
+pub fn verify_outputs_sorted(
+    _violations: &mut Vec<SyntaxViolation>,
+    _path: &str,
+    _keys: &[Vec<u8>],
+) {
+    if let Some(index) = _keys.windows(2).position(|pair| pair[0] > pair[1]) {
+        violation(
+            _violations,
+            &format!("{}[{}]", _path, index + 1),
+            "outputs are not sorted",
+        );
+    }
+}
+
+/// Inputs are sorted by UTXO , tx id then output index , and each UTXO is spent once .
+pub fn verify_inputs_sorted_and_unique(
+    _violations: &mut Vec<SyntaxViolation>,
+    _path: &str,
+    _utxos: &[(&str, i32)],
+) {
+    let mut keys = Vec::new();
+    for (index, (tx_id, utxo_index)) in _utxos.iter().enumerate() {
+        match decode(tx_id) {
+            Ok(mut key) => {
+                key.extend_from_slice(&(*utxo_index as u32).to_be_bytes());
+                keys.push(key);
+            }
+            Err(err) => {
+                violation(
+                    _violations,
+                    &format!("{}[{}].tx_id", _path, index),
+                    &err.to_string(),
+                );
+                return;
+            }
+        }
+    }
+
+    if let Some(index) = keys.windows(2).position(|pair| pair[0] >= pair[1]) {
+        violation(
+            _violations,
+            &format!("{}[{}]", _path, index + 1),
+            "inputs are not sorted and unique",
+        );
+    }
+}
+
+/// The serialized form of the owners of an output : locktime , threshold and addresses .
+pub fn owners_bytes(_locktime: i64, _threshold: i32, _addresses: &[String]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&_locktime.to_be_bytes());
+    bytes.extend_from_slice(&_threshold.to_be_bytes());
+    bytes.extend_from_slice(&(_addresses.len() as u32).to_be_bytes());
+    for address in _addresses {
+        // Invalid addresses are reported by `verify_owners`
+        bytes.extend(address_bytes(address).unwrap_or_default());
+    }
+
+    bytes
+}
+
+fn is_sorted_and_unique(_items: &[Vec<u8>]) -> bool {
+    _items.windows(2).all(|pair| pair[0] < pair[1])
+}
@@ -0,0 +1,4 @@
+pub mod signers;
+pub mod utxo_id;
+pub mod utxo_parser;
+pub mod utxo_set;
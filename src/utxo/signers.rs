@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use crate::avm::parser::credential_parser::Credential;
+use crate::utils::amount::Amount;
+use crate::utxo::utxo_id::UtxoId;
+use crate::utxo::utxo_parser::Utxo;
+
+/// Where the UTXOs spent by a tx are found , implemented by the caller on top of its own storage .
+pub trait UtxoLookup {
+    fn get_utxo(&self, _utxo_id: &UtxoId) -> Option<&Utxo>;
+}
+
+impl UtxoLookup for HashMap<UtxoId, Utxo> {
+    fn get_utxo(&self, _utxo_id: &UtxoId) -> Option<&Utxo> {
+        self.get(_utxo_id)
+    }
+}
+
+/// An owner of a UTXO who had to sign to spend it .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Signer {
+    /// The index of the address in the owners of the UTXO
+    pub address_index: u32,
+    pub address: String,
+    /// The signature matching the index in the credential
+    pub signature: Vec<u8>,
+}
+
+/// The signers of a UTXO spent by an input or an operation .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InputSigners {
+    pub utxo_id: UtxoId,
+    pub threshold: u32,
+    pub signers: Vec<Signer>,
+}
+
+/// The threshold and addresses owning a UTXO , whatever chain it comes from .
+pub fn utxo_owners(_utxo: &Utxo) -> Option<(u32, &[String])> {
+    if let Some(output) = &_utxo.avm_output {
+        return Some((output.threshold as u32, &output.addresses));
+    }
+
+    let output = _utxo.pvm_output.as_ref()?;
+    let transfer_output = match &output.stakeable_locked_output {
+        Some(stakeable_locked_output) => stakeable_locked_output.secp256k_transfer_output.as_ref(),
+        None => output
+            .secp256k1_transfer_output
+            .as_ref()
+            .or(output.secp256k1_owner_output.as_ref()),
+    }?;
+
+    Some((transfer_output.threshold as u32, &transfer_output.addresses))
+}
+
+/// The type id of the output of a UTXO , wrapped in a stakeable lock or not .
+pub fn utxo_type_id(_utxo: &Utxo) -> Option<i32> {
+    if let Some(output) = &_utxo.avm_output {
+        return Some(output.type_id);
+    }
+
+    _utxo.pvm_output.as_ref().map(|output| output.type_id)
+}
+
+/// The amount of a UTXO , if its output holds one .
+pub fn utxo_amount(_utxo: &Utxo) -> Option<Amount> {
+    if let Some(output) = &_utxo.avm_output {
+        return output.amount;
+    }
+
+    let output = _utxo.pvm_output.as_ref()?;
+    let transfer_output = match &output.stakeable_locked_output {
+        Some(stakeable_locked_output) => stakeable_locked_output.secp256k_transfer_output.as_ref(),
+        None => output.secp256k1_transfer_output.as_ref(),
+    }?;
+
+    transfer_output.amount
+}
+
+/// The time until which a UTXO cannot be spent , the latest of its locktime and its stakeable lock .
+pub fn utxo_locktime(_utxo: &Utxo) -> i64 {
+    if let Some(output) = &_utxo.avm_output {
+        return output.locktime;
+    }
+
+    let mut locktime = 0;
+    if let Some(output) = &_utxo.pvm_output {
+        if let Some(stakeable_locked_output) = &output.stakeable_locked_output {
+            locktime = locktime.max(stakeable_locked_output.locktime);
+        }
+        for transfer_output in output
+            .stakeable_locked_output
+            .iter()
+            .filter_map(|stakeable_locked_output| {
+                stakeable_locked_output.secp256k_transfer_output.as_ref()
+            })
+            .chain(output.secp256k1_transfer_output.as_ref())
+            .chain(output.secp256k1_owner_output.as_ref())
+        {
+            locktime = locktime.max(transfer_output.locktime);
+        }
+    }
+
+    locktime
+}
+
+/// Map the address indices of an input to the owners of the UTXO it spends and to the signatures of its credential .
+/// As in AvalancheGo , there must be exactly as many indices as the threshold , and one signature per index .
+pub fn resolve_input_signers(
+    _utxos: &dyn UtxoLookup,
+    _utxo_id: &UtxoId,
+    _address_indices: &[u32],
+    _credential: Option<&Credential>,
+) -> Result<InputSigners, Box<dyn Error>> {
+    let utxo = _utxos
+        .get_utxo(_utxo_id)
+        .ok_or_else(|| format!("UTXO {} not found", _utxo_id))?;
+    let (threshold, addresses) =
+        utxo_owners(utxo).ok_or_else(|| format!("UTXO {} has no owners", _utxo_id))?;
+
+    if _address_indices.len() != threshold as usize {
+        return Err(format!(
+            "UTXO {} needs {} signers , the input has {}",
+            _utxo_id,
+            threshold,
+            _address_indices.len()
+        )
+        .into());
+    }
+
+    let credential = _credential.ok_or_else(|| format!("No credential for UTXO {}", _utxo_id))?;
+    if credential.signatures.len() != _address_indices.len() {
+        return Err(format!(
+            "UTXO {} is spent with {} address indices but {} signatures",
+            _utxo_id,
+            _address_indices.len(),
+            credential.signatures.len()
+        )
+        .into());
+    }
+
+    let mut signers = Vec::new();
+    for (address_index, signature) in _address_indices.iter().zip(&credential.signatures) {
+        let address = addresses.get(*address_index as usize).ok_or_else(|| {
+            format!(
+                "Address index {} of UTXO {} is out of its {} owners",
+                address_index,
+                _utxo_id,
+                addresses.len()
+            )
+        })?;
+        signers.push(Signer {
+            address_index: *address_index,
+            address: address.clone(),
+            signature: signature.clone(),
+        });
+    }
+
+    Ok(InputSigners {
+        utxo_id: _utxo_id.clone(),
+        threshold,
+        signers,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm::parser::output_parser::Output;
+
+    #[test]
+    fn resolve_input_signers_01() {
+        let utxo_id = UtxoId::new("SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2HdvDQU", 0);
+        let mut utxos = HashMap::new();
+        utxos.insert(
+            utxo_id.clone(),
+            Utxo {
+                codec_id: 0,
+                utxo_id: utxo_id.input_id().unwrap(),
+                tx_id: utxo_id.tx_id.clone(),
+                output_index: 0,
+                asset_id: "FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z".to_string(),
+                avm_output: Some(Output {
+                    type_id: 7,
+                    amount: None,
+                    group_id: None,
+                    payload: None,
+                    locktime: 0,
+                    threshold: 1,
+                    addresses: vec![
+                        "X-avax19g3a0urkpz9zr3gfv9st50q2yhqdhfp4jgzn0d".to_string(),
+                        "X-avax1d3qnae4dej47yd0pd28th7nxelwu6fp5mncjnd".to_string(),
+                    ],
+                }),
+                pvm_output: None,
+            },
+        );
+        let credential = Credential {
+            type_id: 9,
+            signatures: vec![vec![1; 65]],
+        };
+
+        let input_signers =
+            resolve_input_signers(&utxos, &utxo_id, &[1], Some(&credential)).unwrap();
+        assert_eq!(
+            input_signers.signers[0].address, "X-avax1d3qnae4dej47yd0pd28th7nxelwu6fp5mncjnd",
+            "Checking if the index is resolved to its address"
+        );
+        assert!(
+            resolve_input_signers(&utxos, &utxo_id, &[0, 1], Some(&credential)).is_err(),
+            "Checking if more indices than the threshold are rejected"
+        );
+        assert!(
+            resolve_input_signers(&utxos, &utxo_id, &[2], Some(&credential)).is_err(),
+            "Checking if indices out of the owners are rejected"
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use ring::digest;
+use rust_base58::ToBase58;
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::avm::parser::base_tx_parser::BaseTx as AvmBaseTx;
+use crate::avm::parser::signed_tx_parser::SignedTx as AvmSignedTx;
+use crate::avm::parser::transfer_op_parser::UtxoIds;
+use crate::avm::parser::transferable_input_parser::TransferableInput as AvmTransferableInput;
+use crate::cvm::parser::signed_tx_parser::SignedTx as CvmSignedTx;
+use crate::pvm::parser::atomic_block_parser::Transaction as PvmTransaction;
+use crate::pvm::parser::transferable_input_parser::TransferableInput as PvmTransferableInput;
+use crate::utils::cb58::{decode, encode};
+
+/// A UTXO is identified by the id of the tx which produced it and the index of the output in this tx .
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UtxoId {
+    pub tx_id: String,
+    pub output_index: u32,
+}
+
+impl UtxoId {
+    pub fn new(_tx_id: &str, _output_index: u32) -> UtxoId {
+        UtxoId {
+            tx_id: _tx_id.to_string(),
+            output_index: _output_index,
+        }
+    }
+
+    /// AvalancheGo's `InputID` , the key under which the UTXO is stored .
+    pub fn input_id(&self) -> Result<String, Box<dyn Error>> {
+        let raw_tx_id = decode(&self.tx_id)?;
+        if raw_tx_id.len() != 32 {
+            return Err(format!("Invalid tx id {} : expected 32 bytes", self.tx_id).into());
+        }
+
+        Ok(generate_utxo_id(&raw_tx_id, self.output_index))
+    }
+}
+
+impl fmt::Display for UtxoId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.tx_id, self.output_index)
+    }
+}
+
+impl FromStr for UtxoId {
+    type Err = Box<dyn Error>;
+
+    /// Parse a `txid:index` string , the tx id has to be a valid cb58 string .
+    fn from_str(_utxo_id: &str) -> Result<UtxoId, Box<dyn Error>> {
+        let (tx_id, output_index) = _utxo_id
+            .rsplit_once(':')
+            .ok_or_else(|| format!("Invalid UTXO id {} : expected txid:index", _utxo_id))?;
+        decode(tx_id)?;
+        let output_index = output_index
+            .parse::<u32>()
+            .map_err(|err| format!("Invalid UTXO id {} : {}", _utxo_id, err))?;
+
+        Ok(UtxoId::new(tx_id, output_index))
+    }
+}
+
+impl From<&AvmTransferableInput> for UtxoId {
+    fn from(_input: &AvmTransferableInput) -> UtxoId {
+        UtxoId::new(&_input.tx_id, _input.utxo_index as u32)
+    }
+}
+
+impl From<&PvmTransferableInput> for UtxoId {
+    fn from(_input: &PvmTransferableInput) -> UtxoId {
+        UtxoId::new(&_input.tx_id, _input.utxo_index as u32)
+    }
+}
+
+impl From<&UtxoIds> for UtxoId {
+    fn from(_utxo_ids: &UtxoIds) -> UtxoId {
+        UtxoId::new(&_utxo_ids.tx_id, _utxo_ids.utxo_index as u32)
+    }
+}
+
+/// The UTXO Id , AvalancheGo's `InputID` , is the sha256 of the output index (8 bytes) followed by the tx id .
+pub fn generate_utxo_id(_raw_tx_id: &[u8], _output_index: u32) -> String {
+    let mut prefixed_tx_id = Vec::new();
+    prefixed_tx_id.extend_from_slice(&u64::from(_output_index).to_be_bytes());
+    prefixed_tx_id.extend_from_slice(_raw_tx_id);
+
+    let utxo_id = digest::digest(&digest::SHA256, &prefixed_tx_id);
+
+    encode(utxo_id.as_ref()).to_base58()
+}
+
+/// List the UTXOs produced by an X-chain tx , in output index order .
+/// The outputs of the base tx come first , followed by the initial states of a create asset tx ,
+/// the outputs of the operations of an operation tx , or the exported outputs of an export tx .
+pub fn avm_produced_utxo_ids(_tx: &AvmSignedTx) -> Vec<UtxoId> {
+    let mut number_of_outputs = 0;
+
+    if let Some(base_tx) = avm_base_tx(_tx) {
+        number_of_outputs += base_tx.transferable_outputs.len();
+    }
+
+    if let Some(create_asset_tx) = &_tx.create_asset_tx {
+        for initial_state in &create_asset_tx.initial_states {
+            number_of_outputs += initial_state.outputs.len();
+        }
+    }
+
+    if let Some(operation_tx) = &_tx.operation_tx {
+        for operation in &operation_tx.transferable_ops {
+            if operation.secp256k1_mint_op.is_some() || operation.property_mint_op.is_some() {
+                number_of_outputs += 2;
+            }
+            if let Some(nft_mint_op) = &operation.nft_mint_op {
+                number_of_outputs += nft_mint_op.outputs.len();
+            }
+            if operation.nft_transfer_op.is_some() {
+                number_of_outputs += 1;
+            }
+        }
+    }
+
+    if let Some(export_tx) = &_tx.export_tx {
+        number_of_outputs += export_tx.transferable_outputs.len();
+    }
+
+    utxo_ids(&_tx.tx_id, number_of_outputs)
+}
+
+/// List the UTXOs produced by a P-chain tx , in output index order .
+/// The stake of a validator or a delegator is only returned when the staking period ends ,
+/// so it is not part of the list .
+pub fn pvm_produced_utxo_ids(_tx: &PvmTransaction) -> Vec<UtxoId> {
+    let mut number_of_outputs = _tx.base_tx.transferable_outputs.len();
+
+    if let Some(export_tx) = &_tx.export_tx {
+        number_of_outputs += export_tx.transferable_outputs.len();
+    }
+
+    utxo_ids(&_tx.tx_id, number_of_outputs)
+}
+
+/// List the UTXOs produced by a C-chain atomic tx , only an export tx produces some .
+pub fn cvm_produced_utxo_ids(_tx: &CvmSignedTx) -> Vec<UtxoId> {
+    let mut number_of_outputs = 0;
+
+    if let Some(export_tx) = &_tx.export_tx {
+        number_of_outputs += export_tx.transferable_outputs.len();
+    }
+
+    utxo_ids(&_tx.tx_id, number_of_outputs)
+}
+
+pub(crate) fn avm_base_tx(_tx: &AvmSignedTx) -> Option<&AvmBaseTx> {
+    if let Some(base_tx) = &_tx.base_tx {
+        Some(base_tx)
+    } else if let Some(create_asset_tx) = &_tx.create_asset_tx {
+        Some(&create_asset_tx.base_tx)
+    } else if let Some(operation_tx) = &_tx.operation_tx {
+        Some(&operation_tx.base_tx)
+    } else if let Some(import_tx) = &_tx.import_tx {
+        Some(&import_tx.base_tx)
+    } else {
+        _tx.export_tx.as_ref().map(|export_tx| &export_tx.base_tx)
+    }
+}
+
+fn utxo_ids(_tx_id: &str, _number_of_outputs: usize) -> Vec<UtxoId> {
+    (0.._number_of_outputs)
+        .map(|output_index| UtxoId::new(_tx_id, output_index as u32))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utxo_id_01() {
+        let utxo_id: UtxoId = "SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2HdvDQU:3"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            utxo_id,
+            UtxoId::new("SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2HdvDQU", 3),
+            "Checking if UTXO id is correctly parsed"
+        );
+        assert_eq!(
+            utxo_id.to_string(),
+            "SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2HdvDQU:3",
+            "Checking if UTXO id is correctly displayed"
+        );
+        assert_eq!(
+            utxo_id.input_id().unwrap(),
+            "RoLdvdWuxAQssS48FZyh8bjeQGfYNCDgfuV3rdSsHcXcVHQQ8",
+            "Checking if input id is correctly computed"
+        );
+
+        assert!(
+            "SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2HdvDQU"
+                .parse::<UtxoId>()
+                .is_err(),
+            "Checking a UTXO id without index is rejected"
+        );
+        assert!(
+            "SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2HdvDQV:3"
+                .parse::<UtxoId>()
+                .is_err(),
+            "Checking a UTXO id with a wrong checksum is rejected"
+        );
+    }
+}
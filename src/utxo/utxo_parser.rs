@@ -0,0 +1,181 @@
+use rust_base58::ToBase58;
+use tracing::{instrument, trace};
+
+use std::borrow::Borrow;
+use std::error::Error;
+
+use crate::avm::parser::output_parser::{output_parser as avm_output_parser, Output as AvmOutput};
+use crate::avm::parser::Context;
+use crate::pvm::parser::output_parser::{output_parser as pvm_output_parser, Output as PvmOutput};
+use crate::utils::cb58::encode;
+use crate::utils::conversion::{pop_i16, pop_i32};
+use crate::utxo::utxo_id::generate_utxo_id;
+
+/// The chain a UTXO comes from , as the X and P chains do not share the same set of outputs .
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Chain {
+    X,
+    P,
+}
+
+/// A UTXO as returned by `avm.getUTXOs` or `platform.getUTXOs` .
+/// Only the output matching the `Chain` it was parsed for is set .
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Utxo {
+    pub codec_id: i16,
+    pub utxo_id: String,
+    pub tx_id: String,
+    pub output_index: i32,
+    pub asset_id: String,
+    pub avm_output: Option<AvmOutput>,
+    pub pvm_output: Option<PvmOutput>,
+}
+
+/// Will parse a Vector of bytes (u8) containing a codec prefixed UTXO and return a `Utxo`
+#[instrument(skip(_raw_msg), fields(tx_id = % _context.tx_id))]
+pub fn parse_utxo(
+    _raw_msg: &[u8],
+    _chain: Chain,
+    _context: &mut Context,
+) -> Result<Utxo, Box<dyn Error>> {
+    let codec_id = pop_i16(_raw_msg[*_context.offset..=(*_context.offset + 1)].borrow());
+    trace!("Codec_id : {:?}", codec_id);
+    *_context.offset += 2;
+
+    // Tx Id
+    let raw_tx_id = &_raw_msg[*_context.offset..=(*_context.offset + 31)];
+    let tx_id = encode(raw_tx_id).to_base58();
+    trace!("TxId : {:?}", tx_id);
+    *_context.offset += 32;
+
+    // Output Index
+    let output_index = pop_i32(_raw_msg[*_context.offset..=(*_context.offset + 3)].borrow());
+    trace!("Output index : {:?}", output_index);
+    *_context.offset += 4;
+
+    let utxo_id = generate_utxo_id(raw_tx_id, output_index as u32);
+    trace!("UTXO Id : {:?}", utxo_id);
+
+    // Asset Id
+    let asset_id = encode(&_raw_msg[*_context.offset..=(*_context.offset + 31)]).to_base58();
+    trace!("AssetID : {:?}", asset_id);
+    *_context.offset += 32;
+
+    let mut avm_output = None;
+    let mut pvm_output = None;
+
+    match _chain {
+        Chain::X => avm_output = Some(avm_output_parser(_raw_msg, _context)?),
+        Chain::P => pvm_output = Some(pvm_output_parser(_raw_msg, _context)?),
+    }
+
+    Ok(Utxo {
+        codec_id,
+        utxo_id,
+        tx_id,
+        output_index,
+        asset_id,
+        avm_output,
+        pvm_output,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+
+    #[test]
+    fn decode_x_chain_utxo_01() {
+        let raw_bytes: Vec<u8> = Vec::from([
+            0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
+            23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 0, 0, 0, 3, 33, 230, 115, 23, 203, 196, 190,
+            42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145,
+            178, 48, 39, 168, 125, 255, 0, 0, 0, 7, 0, 0, 0, 0, 59, 154, 202, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 108, 65, 62, 230, 173, 204, 171, 226, 53, 225, 106, 142,
+            187, 250, 102, 207, 221, 205, 36, 52,
+        ]);
+        let utxo = parse_utxo(
+            &raw_bytes,
+            Chain::X,
+            &mut Context {
+                tx_id: "a_utxo",
+                uuid: Default::default(),
+                offset: &mut 0,
+                parsing_started: SystemTime::now(),
+                network_name: "avax".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            utxo.tx_id, "SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2HdvDQU",
+            "Checking if tx_id is correctly set"
+        );
+        assert_eq!(
+            utxo.output_index, 3,
+            "Checking if output index is correctly set"
+        );
+        assert_eq!(
+            utxo.utxo_id, "RoLdvdWuxAQssS48FZyh8bjeQGfYNCDgfuV3rdSsHcXcVHQQ8",
+            "Checking if utxo id is correctly computed"
+        );
+        assert_eq!(
+            utxo.asset_id, "FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z",
+            "Checking if asset id is correctly set"
+        );
+        assert_eq!(
+            utxo.avm_output.unwrap().amount,
+            Some(crate::utils::amount::Amount(1_000_000_000)),
+            "Checking if output is correctly set"
+        );
+        assert!(
+            utxo.pvm_output.is_none(),
+            "Checking no P-chain output is set"
+        );
+    }
+
+    #[test]
+    fn decode_p_chain_stakeable_locked_utxo_01() {
+        let raw_bytes: Vec<u8> = Vec::from([
+            0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
+            23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 0, 0, 0, 1, 33, 230, 115, 23, 203, 196, 190,
+            42, 235, 0, 103, 122, 214, 70, 39, 120, 168, 245, 34, 116, 185, 214, 5, 223, 37, 145,
+            178, 48, 39, 168, 125, 255, 0, 0, 0, 22, 0, 0, 0, 0, 101, 83, 241, 0, 0, 0, 0, 7, 0, 0,
+            1, 209, 169, 74, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 108, 65, 62,
+            230, 173, 204, 171, 226, 53, 225, 106, 142, 187, 250, 102, 207, 221, 205, 36, 52,
+        ]);
+        let utxo = parse_utxo(
+            &raw_bytes,
+            Chain::P,
+            &mut Context {
+                tx_id: "a_utxo",
+                uuid: Default::default(),
+                offset: &mut 0,
+                parsing_started: SystemTime::now(),
+                network_name: "avax".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            utxo.utxo_id, "oyTrr1FUoQAsmoPquGP8LbGaKZKkPjNpoBUBvrrE6cwew5KJ7",
+            "Checking if utxo id is correctly computed"
+        );
+
+        let output = utxo.pvm_output.unwrap();
+        assert_eq!(output.type_id, 22, "Checking if output is stakeable locked");
+
+        let stakeable_locked_output = output.stakeable_locked_output.unwrap();
+        assert_eq!(
+            stakeable_locked_output.locktime, 1_700_000_000,
+            "Checking if stake locktime is correctly set"
+        );
+        assert_eq!(
+            stakeable_locked_output
+                .secp256k_transfer_output
+                .unwrap()
+                .amount,
+            Some(crate::utils::amount::Amount(2_000_000_000_000)),
+            "Checking if locked amount is correctly set"
+        );
+    }
+}
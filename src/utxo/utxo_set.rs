@@ -0,0 +1,772 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+
+use crate::avm::parser::output_owner_parser::OutputOwner;
+use crate::avm::parser::output_parser::Output as AvmOutput;
+use crate::avm::parser::signed_tx_parser::SignedTx as AvmSignedTx;
+use crate::cvm::parser::signed_tx_parser::SignedTx as CvmSignedTx;
+use crate::pvm::parser::atomic_block_parser::Transaction as PvmTransaction;
+use crate::pvm::parser::block_parser::{
+    Block, ABORT_BLOCK_TYPE_ID, COMMIT_BLOCK_TYPE_ID, PROPOSAL_BLOCK_TYPE_ID,
+};
+use crate::pvm::parser::output_parser::Output as PvmOutput;
+use crate::utxo::signers::UtxoLookup;
+use crate::utxo::utxo_id::{
+    avm_base_tx, avm_produced_utxo_ids, cvm_produced_utxo_ids, pvm_produced_utxo_ids, UtxoId,
+};
+use crate::utxo::utxo_parser::Utxo;
+
+/// The output of an NFT transfer , as produced by NFT mint and transfer operations .
+const NFT_TRANSFER_OUTPUT_TYPE_ID: i32 = 11;
+
+/// What applying a tx or a block changed , to roll it back .
+#[derive(Debug, Default)]
+pub struct UndoLog {
+    /// The UTXOs spent , put back on rollback
+    pub consumed: Vec<Utxo>,
+    /// The UTXOs created , removed on rollback
+    pub produced: Vec<UtxoId>,
+    /// The staker txs whose stake got locked
+    pub staked: Vec<String>,
+    /// The staker txs whose stake got returned , with the returned UTXOs
+    pub unstaked: Vec<(String, Vec<UtxoId>)>,
+}
+
+impl UndoLog {
+    /// Merge the log of a tx applied after the ones already in this log .
+    pub fn extend(&mut self, _other: UndoLog) {
+        self.consumed.extend(_other.consumed);
+        self.produced.extend(_other.produced);
+        self.staked.extend(_other.staked);
+        self.unstaked.extend(_other.unstaked);
+    }
+}
+
+/// What a P-chain tx spends and creates , computed before it is applied .
+#[derive(Debug, Default)]
+pub struct PvmTxChanges {
+    pub tx_id: String,
+    pub consumed: Vec<UtxoId>,
+    pub produced: Vec<Utxo>,
+    /// The stake locked until the staker is rewarded
+    pub staked: Option<Vec<Utxo>>,
+    /// The staker tx whose stake is returned
+    pub unstaked: Option<String>,
+}
+
+/// The UTXOs of the X and P chains and of their shared memory , replayed from parsed txs .
+/// Exported outputs are kept with the others until an import tx spends them .
+#[derive(Debug, Default)]
+pub struct UtxoSet {
+    pub utxos: HashMap<UtxoId, Utxo>,
+    /// The UTXOs already spent , to tell a double spend from an unknown input
+    pub spent: HashSet<UtxoId>,
+    /// The stake of the current validators and delegators , by staker tx id , returned on reward
+    pub staked: HashMap<String, Vec<Utxo>>,
+    /// The proposal block waiting for its commit or abort child , with the changes of each
+    pub proposal: Option<(String, Vec<PvmTxChanges>, Vec<PvmTxChanges>)>,
+}
+
+impl UtxoLookup for UtxoSet {
+    fn get_utxo(&self, _utxo_id: &UtxoId) -> Option<&Utxo> {
+        self.utxos.get(_utxo_id)
+    }
+}
+
+impl UtxoSet {
+    /// Add a UTXO known upfront , e.g. from the genesis or `getUTXOs` .
+    pub fn insert(&mut self, _utxo: Utxo) {
+        let utxo_id = UtxoId::new(&_utxo.tx_id, _utxo.output_index as u32);
+        self.utxos.insert(utxo_id, _utxo);
+    }
+
+    pub fn apply_avm_tx(&mut self, _tx: &AvmSignedTx) -> Result<UndoLog, Box<dyn Error>> {
+        let mut consumed = Vec::new();
+        if let Some(base_tx) = avm_base_tx(_tx) {
+            consumed.extend(base_tx.transferable_inputs.iter().map(UtxoId::from));
+        }
+        if let Some(operation_tx) = &_tx.operation_tx {
+            for operation in &operation_tx.transferable_ops {
+                consumed.extend(operation.utxo_ids.iter().map(UtxoId::from));
+            }
+        }
+        if let Some(import_tx) = &_tx.import_tx {
+            consumed.extend(import_tx.transferable_inputs.iter().map(UtxoId::from));
+        }
+
+        let produced = avm_produced_utxo_ids(_tx)
+            .into_iter()
+            .zip(avm_produced_outputs(_tx))
+            .map(|(utxo_id, (asset_id, output))| avm_utxo(&utxo_id, asset_id, output))
+            .collect::<Result<Vec<Utxo>, Box<dyn Error>>>()?;
+
+        self.apply(&_tx.tx_id, &consumed, produced)
+    }
+
+    /// Apply a tx of a standard or an atomic block .
+    pub fn apply_pvm_tx(&mut self, _tx: &PvmTransaction) -> Result<UndoLog, Box<dyn Error>> {
+        self.apply_pvm_changes(pvm_tx_changes(_tx, true)?)
+    }
+
+    /// Apply the tx of a proposal block once the proposal is committed or aborted .
+    /// The reward of a validator is not produced , its amount is not part of the tx .
+    pub fn apply_decided_pvm_tx(
+        &mut self,
+        _tx: &PvmTransaction,
+        _committed: bool,
+    ) -> Result<UndoLog, Box<dyn Error>> {
+        self.apply_pvm_changes(pvm_tx_changes(_tx, _committed)?)
+    }
+
+    /// Apply an import or an export tx of the C-chain , which only touch the shared memory .
+    pub fn apply_cvm_tx(&mut self, _tx: &CvmSignedTx) -> Result<UndoLog, Box<dyn Error>> {
+        let mut consumed = Vec::new();
+        if let Some(import_tx) = &_tx.import_tx {
+            consumed.extend(import_tx.transferable_inputs.iter().map(UtxoId::from));
+        }
+
+        let mut outputs = Vec::new();
+        if let Some(export_tx) = &_tx.export_tx {
+            outputs.extend(
+                export_tx
+                    .transferable_outputs
+                    .iter()
+                    .map(|output| (output.asset_id.clone(), output.output.clone())),
+            );
+        }
+        let produced = cvm_produced_utxo_ids(_tx)
+            .into_iter()
+            .zip(outputs)
+            .map(|(utxo_id, (asset_id, output))| avm_utxo(&utxo_id, asset_id, output))
+            .collect::<Result<Vec<Utxo>, Box<dyn Error>>>()?;
+
+        self.apply(&_tx.tx_id, &consumed, produced)
+    }
+
+    /// Apply every tx of a P-chain block , nothing is applied if one of them is rejected .
+    /// The txs of a proposal block wait for its commit or abort child , which applies them .
+    pub fn apply_pvm_block(&mut self, _block: &Block) -> Result<UndoLog, Box<dyn Error>> {
+        let block_data = &_block.block_data;
+
+        let changes = match block_data.type_id {
+            PROPOSAL_BLOCK_TYPE_ID => {
+                let mut commit = Vec::new();
+                let mut abort = Vec::new();
+                for transaction in block_data.transactions.iter().flatten() {
+                    commit.push(pvm_tx_changes(transaction, true)?);
+                    abort.push(pvm_tx_changes(transaction, false)?);
+                }
+                self.proposal = Some((block_data.block_id.clone(), commit, abort));
+
+                return Ok(UndoLog::default());
+            }
+            ABORT_BLOCK_TYPE_ID | COMMIT_BLOCK_TYPE_ID => {
+                let (proposal_block_id, commit, abort) = self.proposal.take().ok_or_else(|| {
+                    format!("Block {} has no proposal to decide", block_data.block_id)
+                })?;
+                if proposal_block_id != block_data.parent_block_id {
+                    return Err(format!(
+                        "Block {} decides {} , the pending proposal is {}",
+                        block_data.block_id, block_data.parent_block_id, proposal_block_id
+                    )
+                    .into());
+                }
+                if block_data.type_id == COMMIT_BLOCK_TYPE_ID {
+                    commit
+                } else {
+                    abort
+                }
+            }
+            _ => block_data
+                .transactions
+                .iter()
+                .flatten()
+                .map(|transaction| pvm_tx_changes(transaction, true))
+                .collect::<Result<Vec<PvmTxChanges>, Box<dyn Error>>>()?,
+        };
+
+        let mut undo_log = UndoLog::default();
+        for tx_changes in changes {
+            match self.apply_pvm_changes(tx_changes) {
+                Ok(tx_undo_log) => undo_log.extend(tx_undo_log),
+                Err(error) => {
+                    self.rollback(undo_log);
+                    return Err(format!("Block {} : {}", block_data.block_id, error).into());
+                }
+            }
+        }
+
+        Ok(undo_log)
+    }
+
+    /// Undo what was applied , e.g. the txs of a block which turned out to be aborted .
+    pub fn rollback(&mut self, _undo_log: UndoLog) {
+        for (staker_tx_id, returned) in _undo_log.unstaked.into_iter().rev() {
+            let stake = returned
+                .iter()
+                .filter_map(|utxo_id| self.utxos.remove(utxo_id))
+                .collect();
+            self.staked.insert(staker_tx_id, stake);
+        }
+        for staker_tx_id in &_undo_log.staked {
+            self.staked.remove(staker_tx_id);
+        }
+        for utxo in _undo_log.consumed {
+            let utxo_id = UtxoId::new(&utxo.tx_id, utxo.output_index as u32);
+            self.spent.remove(&utxo_id);
+            self.utxos.insert(utxo_id, utxo);
+        }
+        for utxo_id in &_undo_log.produced {
+            self.utxos.remove(utxo_id);
+        }
+    }
+
+    /// Spend `_consumed` and add `_produced` , only once every input is known to be unspent .
+    fn apply(
+        &mut self,
+        _tx_id: &str,
+        _consumed: &[UtxoId],
+        _produced: Vec<Utxo>,
+    ) -> Result<UndoLog, Box<dyn Error>> {
+        let mut seen = HashSet::new();
+        for utxo_id in _consumed {
+            if !seen.insert(utxo_id) || self.spent.contains(utxo_id) {
+                return Err(format!("Tx {} double spends UTXO {}", _tx_id, utxo_id).into());
+            }
+            if !self.utxos.contains_key(utxo_id) {
+                return Err(format!("Tx {} spends unknown UTXO {}", _tx_id, utxo_id).into());
+            }
+        }
+
+        let mut undo_log = UndoLog::default();
+        for utxo_id in _consumed {
+            if let Some(utxo) = self.utxos.remove(utxo_id) {
+                self.spent.insert(utxo_id.clone());
+                undo_log.consumed.push(utxo);
+            }
+        }
+        for utxo in _produced {
+            let utxo_id = UtxoId::new(&utxo.tx_id, utxo.output_index as u32);
+            undo_log.produced.push(utxo_id.clone());
+            self.utxos.insert(utxo_id, utxo);
+        }
+
+        Ok(undo_log)
+    }
+
+    fn apply_pvm_changes(&mut self, _changes: PvmTxChanges) -> Result<UndoLog, Box<dyn Error>> {
+        if let Some(staker_tx_id) = &_changes.unstaked {
+            return self.return_stake(staker_tx_id);
+        }
+
+        let mut undo_log = self.apply(&_changes.tx_id, &_changes.consumed, _changes.produced)?;
+        if let Some(stake) = _changes.staked {
+            self.staked.insert(_changes.tx_id.clone(), stake);
+            undo_log.staked.push(_changes.tx_id);
+        }
+
+        Ok(undo_log)
+    }
+
+    fn return_stake(&mut self, _staker_tx_id: &str) -> Result<UndoLog, Box<dyn Error>> {
+        let stake = self
+            .staked
+            .remove(_staker_tx_id)
+            .ok_or_else(|| format!("No stake found for staker tx {}", _staker_tx_id))?;
+
+        let mut returned = Vec::new();
+        for utxo in stake {
+            let utxo_id = UtxoId::new(&utxo.tx_id, utxo.output_index as u32);
+            returned.push(utxo_id.clone());
+            self.utxos.insert(utxo_id, utxo);
+        }
+
+        Ok(UndoLog {
+            unstaked: vec![(_staker_tx_id.to_string(), returned)],
+            ..UndoLog::default()
+        })
+    }
+}
+
+/// The changes of a P-chain tx , as its proposal is committed or aborted .
+/// Txs outside of proposal blocks are applied as committed .
+/// An aborted staker tx still spends its inputs and burns its fee , its stake is returned at once .
+/// The stake of a rewarded staker is returned whether the reward is committed or aborted .
+fn pvm_tx_changes(_tx: &PvmTransaction, _committed: bool) -> Result<PvmTxChanges, Box<dyn Error>> {
+    if let Some(reward_validator_tx) = &_tx.reward_validator_tx {
+        return Ok(PvmTxChanges {
+            tx_id: _tx.tx_id.clone(),
+            unstaked: Some(reward_validator_tx.tx_id.clone()),
+            ..PvmTxChanges::default()
+        });
+    }
+    if _tx.advance_time_tx.is_some() {
+        return Ok(PvmTxChanges {
+            tx_id: _tx.tx_id.clone(),
+            ..PvmTxChanges::default()
+        });
+    }
+
+    let mut consumed: Vec<UtxoId> = _tx
+        .base_tx
+        .transferable_inputs
+        .iter()
+        .map(UtxoId::from)
+        .collect();
+    if let Some(import_tx) = &_tx.import_tx {
+        consumed.extend(import_tx.transferable_inputs.iter().map(UtxoId::from));
+    }
+
+    let mut outputs: Vec<(String, PvmOutput)> = _tx
+        .base_tx
+        .transferable_outputs
+        .iter()
+        .map(|output| (output.asset_id.clone(), output.output.clone()))
+        .collect();
+    if let Some(export_tx) = &_tx.export_tx {
+        outputs.extend(
+            export_tx
+                .transferable_outputs
+                .iter()
+                .map(|output| (output.asset_id.clone(), output.output.clone())),
+        );
+    }
+    let mut produced = pvm_produced_utxo_ids(_tx)
+        .into_iter()
+        .zip(outputs)
+        .map(|(utxo_id, (asset_id, output))| pvm_utxo(&utxo_id, asset_id, output))
+        .collect::<Result<Vec<Utxo>, Box<dyn Error>>>()?;
+
+    // The stake follows the outputs of the staker tx , it is returned with these indices
+    let mut staked = None;
+    let stake = match (&_tx.add_validator_tx, &_tx.add_delegator_tx) {
+        (Some(add_validator_tx), _) => Some(&add_validator_tx.stake),
+        (None, Some(add_delegator_tx)) => Some(&add_delegator_tx.stake),
+        (None, None) => None,
+    };
+    if let Some(stake) = stake {
+        let first_index = _tx.base_tx.transferable_outputs.len();
+        let stake = stake
+            .iter()
+            .enumerate()
+            .map(|(index, output)| {
+                pvm_utxo(
+                    &UtxoId::new(&_tx.tx_id, (first_index + index) as u32),
+                    output.asset_id.clone(),
+                    output.output.clone(),
+                )
+            })
+            .collect::<Result<Vec<Utxo>, Box<dyn Error>>>()?;
+        if _committed {
+            staked = Some(stake);
+        } else {
+            produced.extend(stake);
+        }
+    }
+
+    Ok(PvmTxChanges {
+        tx_id: _tx.tx_id.clone(),
+        consumed,
+        produced,
+        staked,
+        unstaked: None,
+    })
+}
+
+/// The outputs of an X-chain tx with their asset id , in the order of `avm_produced_utxo_ids` .
+fn avm_produced_outputs(_tx: &AvmSignedTx) -> Vec<(String, AvmOutput)> {
+    let mut outputs = Vec::new();
+
+    if let Some(base_tx) = avm_base_tx(_tx) {
+        for transferable_output in &base_tx.transferable_outputs {
+            outputs.push((
+                transferable_output.asset_id.clone(),
+                transferable_output.output.clone(),
+            ));
+        }
+    }
+
+    if let Some(create_asset_tx) = &_tx.create_asset_tx {
+        // The asset created is identified by the id of the tx
+        for initial_state in &create_asset_tx.initial_states {
+            for output in &initial_state.outputs {
+                outputs.push((_tx.tx_id.clone(), output.clone()));
+            }
+        }
+    }
+
+    if let Some(operation_tx) = &_tx.operation_tx {
+        for operation in &operation_tx.transferable_ops {
+            let asset_id = &operation.asset_id;
+            if let Some(mint_op) = &operation.secp256k1_mint_op {
+                outputs.push((asset_id.clone(), mint_op.secp256k1_mint_output.clone()));
+                outputs.push((asset_id.clone(), mint_op.secp256k1_transfer_output.clone()));
+            }
+            if let Some(nft_mint_op) = &operation.nft_mint_op {
+                for owner in &nft_mint_op.outputs {
+                    outputs.push((
+                        asset_id.clone(),
+                        nft_transfer_output(nft_mint_op.group_id, &nft_mint_op.payload, owner),
+                    ));
+                }
+            }
+            if let Some(nft_transfer_op) = &operation.nft_transfer_op {
+                outputs.push((
+                    asset_id.clone(),
+                    nft_transfer_output(
+                        nft_transfer_op.group_id,
+                        &nft_transfer_op.payload,
+                        &nft_transfer_op.output_owner,
+                    ),
+                ));
+            }
+            if let Some(property_mint_op) = &operation.property_mint_op {
+                outputs.push((asset_id.clone(), property_mint_op.mint_output.clone()));
+                outputs.push((asset_id.clone(), property_mint_op.owned_output.clone()));
+            }
+        }
+    }
+
+    if let Some(export_tx) = &_tx.export_tx {
+        for transferable_output in &export_tx.transferable_outputs {
+            outputs.push((
+                transferable_output.asset_id.clone(),
+                transferable_output.output.clone(),
+            ));
+        }
+    }
+
+    outputs
+}
+
+fn nft_transfer_output(_group_id: i32, _payload: &[u8], _owner: &OutputOwner) -> AvmOutput {
+    AvmOutput {
+        type_id: NFT_TRANSFER_OUTPUT_TYPE_ID,
+        amount: None,
+        group_id: Some(_group_id),
+        payload: Some(_payload.to_vec()),
+        locktime: _owner.locktime,
+        threshold: _owner.threshold,
+        addresses: _owner.addresses.clone(),
+    }
+}
+
+fn avm_utxo(
+    _utxo_id: &UtxoId,
+    _asset_id: String,
+    _output: AvmOutput,
+) -> Result<Utxo, Box<dyn Error>> {
+    Ok(Utxo {
+        codec_id: 0,
+        utxo_id: _utxo_id.input_id()?,
+        tx_id: _utxo_id.tx_id.clone(),
+        output_index: _utxo_id.output_index as i32,
+        asset_id: _asset_id,
+        avm_output: Some(_output),
+        pvm_output: None,
+    })
+}
+
+fn pvm_utxo(
+    _utxo_id: &UtxoId,
+    _asset_id: String,
+    _output: PvmOutput,
+) -> Result<Utxo, Box<dyn Error>> {
+    Ok(Utxo {
+        codec_id: 0,
+        utxo_id: _utxo_id.input_id()?,
+        tx_id: _utxo_id.tx_id.clone(),
+        output_index: _utxo_id.output_index as i32,
+        asset_id: _asset_id,
+        avm_output: None,
+        pvm_output: Some(_output),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm::parser::base_tx_parser::BaseTx;
+    use crate::avm::parser::input_parser::SECP256KTransferInput;
+    use crate::avm::parser::transferable_input_parser::TransferableInput;
+    use crate::avm::parser::transferable_output_parser::TransferableOutput;
+    use crate::cvm::parser::fixtures::{export_tx, import_tx};
+    use crate::pvm::parser::fixtures::{
+        add_validator_proposal_block, option_block, reward_validator_proposal_block,
+    };
+    use crate::pvm::parser::output_parser::SECP256KTransferOutput;
+    use crate::utils::amount::Amount;
+
+    const AVAX: &str = "FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z";
+    const FUNDING_TX_ID: &str = "SkB92YpWm4Q2ijQHH34cqbKkCZWszsiQgHVjtNeFF2HdvDQU";
+    const TX_ID: &str = "nVDmTRdjb9T83HPsZxd4SMZ1oEGymJ5sUrWnLB4X5MSFBowkP";
+
+    fn output(_amount: u64) -> AvmOutput {
+        AvmOutput {
+            type_id: 7,
+            amount: Some(Amount(_amount)),
+            group_id: None,
+            payload: None,
+            locktime: 0,
+            threshold: 1,
+            addresses: vec!["X-avax1d3qnae4dej47yd0pd28th7nxelwu6fp5mncjnd".to_string()],
+        }
+    }
+
+    fn base_tx(_spent_tx_id: &str) -> AvmSignedTx {
+        AvmSignedTx {
+            codec_id: 0,
+            unsigned_tx_offset: 0,
+            type_id: 0,
+            tx_id: TX_ID.to_string(),
+            base_tx: Some(BaseTx {
+                type_id: 0,
+                network_id: 1,
+                blockchain_id: "2oYMBNV4eNHyqk2fjjV5nVQLDbtmNJzq5s3qs3Lo6ftnC6FByM".to_string(),
+                transferable_outputs: vec![TransferableOutput {
+                    asset_id: AVAX.to_string(),
+                    output: output(1_000),
+                }],
+                transferable_inputs: vec![TransferableInput {
+                    tx_id: _spent_tx_id.to_string(),
+                    utxo_index: 0,
+                    asset_id: AVAX.to_string(),
+                    input: SECP256KTransferInput {
+                        type_id: 5,
+                        amount: Amount(2_000),
+                        address_indices: vec![0],
+                    },
+                }],
+                memo: vec![],
+            }),
+            create_asset_tx: None,
+            operation_tx: None,
+            import_tx: None,
+            export_tx: None,
+            unsigned_tx: vec![],
+            credentials: vec![],
+        }
+    }
+
+    #[test]
+    fn apply_rollback_01() {
+        let mut utxo_set = UtxoSet::default();
+        utxo_set.insert(
+            avm_utxo(
+                &UtxoId::new(FUNDING_TX_ID, 0),
+                AVAX.to_string(),
+                output(2_000),
+            )
+            .unwrap(),
+        );
+
+        let undo_log = utxo_set.apply_avm_tx(&base_tx(FUNDING_TX_ID)).unwrap();
+        assert!(
+            utxo_set.get_utxo(&UtxoId::new(TX_ID, 0)).is_some(),
+            "Checking if the output is added"
+        );
+        assert!(
+            utxo_set.get_utxo(&UtxoId::new(FUNDING_TX_ID, 0)).is_none(),
+            "Checking if the input is spent"
+        );
+        assert!(
+            utxo_set
+                .apply_avm_tx(&base_tx(FUNDING_TX_ID))
+                .unwrap_err()
+                .to_string()
+                .contains("double spends"),
+            "Checking if double spends are rejected"
+        );
+        assert!(
+            utxo_set
+                .apply_avm_tx(&base_tx(
+                    "2vk4HgVnKRuJio5C4xUMmk8D6c74dkM9GtBhLRSp1mXrjnJfg"
+                ))
+                .unwrap_err()
+                .to_string()
+                .contains("unknown"),
+            "Checking if unknown inputs are rejected"
+        );
+
+        utxo_set.rollback(undo_log);
+        assert!(
+            utxo_set.get_utxo(&UtxoId::new(FUNDING_TX_ID, 0)).is_some()
+                && utxo_set.get_utxo(&UtxoId::new(TX_ID, 0)).is_none(),
+            "Checking if the tx is rolled back"
+        );
+        assert!(
+            utxo_set.apply_avm_tx(&base_tx(FUNDING_TX_ID)).is_ok(),
+            "Checking if the input can be spent again once rolled back"
+        );
+    }
+
+    /// Add the UTXOs spent by the txs of a P-chain block .
+    fn fund_block(_utxo_set: &mut UtxoSet, _block: &Block) {
+        for transaction in _block.block_data.transactions.iter().flatten() {
+            for input in &transaction.base_tx.transferable_inputs {
+                _utxo_set.insert(
+                    pvm_utxo(
+                        &UtxoId::from(input),
+                        input.asset_id.clone(),
+                        PvmOutput {
+                            type_id: 7,
+                            stakeable_locked_output: None,
+                            secp256k1_transfer_output: Some(SECP256KTransferOutput {
+                                type_id: 7,
+                                amount: None,
+                                locktime: 0,
+                                threshold: 1,
+                                addresses: vec![
+                                    "P-avax1ptek50w4urnujl8w8g63hhwzyacty0jjxcx4sh".to_string()
+                                ],
+                            }),
+                            secp256k1_owner_output: None,
+                        },
+                    )
+                    .unwrap(),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn apply_pvm_block_01() {
+        let proposal = add_validator_proposal_block();
+        let staker_tx_id = proposal.block_data.transactions[0]
+            .as_ref()
+            .unwrap()
+            .tx_id
+            .clone();
+        let mut utxo_set = UtxoSet::default();
+        fund_block(&mut utxo_set, &proposal);
+
+        utxo_set.apply_pvm_block(&proposal).unwrap();
+        assert_eq!(
+            utxo_set.utxos.len(),
+            11,
+            "Checking if the proposal waits for its commit"
+        );
+        let mut other_option = option_block(COMMIT_BLOCK_TYPE_ID, &proposal);
+        other_option.block_data.parent_block_id = "another_proposal".to_string();
+        assert!(
+            utxo_set.apply_pvm_block(&other_option).is_err(),
+            "Checking if an option block of another proposal is rejected"
+        );
+
+        utxo_set.apply_pvm_block(&proposal).unwrap();
+        utxo_set
+            .apply_pvm_block(&option_block(COMMIT_BLOCK_TYPE_ID, &proposal))
+            .unwrap();
+        assert_eq!(
+            utxo_set.utxos.keys().collect::<Vec<_>>(),
+            vec![&UtxoId::new(&staker_tx_id, 0)],
+            "Checking if the inputs are spent and only the change is produced"
+        );
+        assert_eq!(
+            utxo_set.staked.get(&staker_tx_id).map(|stake| stake.len()),
+            Some(4),
+            "Checking if the stake is locked until the reward"
+        );
+
+        let mut reward = reward_validator_proposal_block();
+        reward.block_data.transactions[0]
+            .as_mut()
+            .unwrap()
+            .reward_validator_tx
+            .as_mut()
+            .unwrap()
+            .tx_id = staker_tx_id.clone();
+        utxo_set.apply_pvm_block(&reward).unwrap();
+        let undo_log = utxo_set
+            .apply_pvm_block(&option_block(ABORT_BLOCK_TYPE_ID, &reward))
+            .unwrap();
+        assert!(
+            (0..5).all(|index| utxo_set
+                .get_utxo(&UtxoId::new(&staker_tx_id, index))
+                .is_some())
+                && utxo_set.staked.is_empty(),
+            "Checking if an aborted reward still returns the stake at the indices after the outputs"
+        );
+
+        utxo_set.rollback(undo_log);
+        assert!(
+            utxo_set.get_utxo(&UtxoId::new(&staker_tx_id, 1)).is_none()
+                && utxo_set.staked.contains_key(&staker_tx_id),
+            "Checking if the returned stake is locked again on rollback"
+        );
+    }
+
+    #[test]
+    fn apply_pvm_block_02() {
+        let proposal = add_validator_proposal_block();
+        let staker_tx_id = proposal.block_data.transactions[0]
+            .as_ref()
+            .unwrap()
+            .tx_id
+            .clone();
+        let mut utxo_set = UtxoSet::default();
+        fund_block(&mut utxo_set, &proposal);
+
+        utxo_set.apply_pvm_block(&proposal).unwrap();
+        utxo_set
+            .apply_pvm_block(&option_block(ABORT_BLOCK_TYPE_ID, &proposal))
+            .unwrap();
+        let mut utxo_ids = utxo_set.utxos.keys().cloned().collect::<Vec<_>>();
+        utxo_ids.sort();
+        assert_eq!(
+            utxo_ids,
+            (0..5)
+                .map(|index| UtxoId::new(&staker_tx_id, index))
+                .collect::<Vec<_>>(),
+            "Checking if an aborted staker tx spends its inputs and returns its stake at once"
+        );
+        assert!(
+            utxo_set.staked.is_empty(),
+            "Checking if no stake is locked for an aborted staker tx"
+        );
+        assert!(
+            utxo_set
+                .apply_pvm_block(&option_block(ABORT_BLOCK_TYPE_ID, &proposal))
+                .is_err(),
+            "Checking if a proposal is only decided once"
+        );
+    }
+
+    #[test]
+    fn apply_cvm_tx_01() {
+        let mut utxo_set = UtxoSet::default();
+        let export_tx = export_tx();
+        utxo_set.apply_cvm_tx(&export_tx).unwrap();
+        assert_eq!(
+            utxo_set
+                .get_utxo(&UtxoId::new(&export_tx.tx_id, 0))
+                .and_then(|utxo| utxo.avm_output.as_ref())
+                .and_then(|output| output.amount),
+            Some(Amount(999_000_000)),
+            "Checking if the exported output is put in the shared memory"
+        );
+
+        let import_tx = import_tx();
+        assert!(
+            utxo_set
+                .apply_cvm_tx(&import_tx)
+                .unwrap_err()
+                .to_string()
+                .contains("unknown"),
+            "Checking if importing a UTXO missing from the shared memory is rejected"
+        );
+
+        let imported = UtxoId::from(&import_tx.import_tx.as_ref().unwrap().transferable_inputs[0]);
+        utxo_set.insert(avm_utxo(&imported, AVAX.to_string(), output(500_000_000)).unwrap());
+        let undo_log = utxo_set.apply_cvm_tx(&import_tx).unwrap();
+        assert!(
+            utxo_set.get_utxo(&imported).is_none() && utxo_set.spent.contains(&imported),
+            "Checking if the imported UTXO is spent"
+        );
+        assert_eq!(
+            undo_log.produced.len(),
+            0,
+            "Checking if an import produces no UTXO , its outputs are EVM balances"
+        );
+    }
+}